@@ -0,0 +1,131 @@
+package osui
+
+import (
+	"math"
+	"time"
+
+	"github.com/orus-dev/osui/colors"
+)
+
+// Maps the elapsed share of an animation in [0, 1] to the share of the change
+type Easing func(t float64) float64
+
+var (
+	Linear    Easing = func(t float64) float64 { return t }
+	EaseIn    Easing = func(t float64) float64 { return t * t * t }
+	EaseOut   Easing = func(t float64) float64 { return 1 - math.Pow(1-t, 3) }
+	EaseInOut Easing = func(t float64) float64 {
+		if t < 0.5 {
+			return 4 * t * t * t
+		}
+		return 1 - math.Pow(-2*t+2, 3)/2
+	}
+	// Overshoots the end a little and settles back
+	EaseOutBack Easing = func(t float64) float64 {
+		return 1 + 2.70158*math.Pow(t-1, 3) + 1.70158*math.Pow(t-1, 2)
+	}
+)
+
+// A value going from From to To over the duration, stepped by the screen before every frame.
+// The screen renders continuously while it runs and with Settings.ReducedMotion it jumps to the end
+type Animation struct {
+	From     float64
+	To       float64
+	Duration time.Duration
+	Easing   Easing
+	// Called before every frame with the current value
+	OnUpdate func(value float64)
+	// Called once after the last value
+	OnComplete func()
+	start      time.Time
+	done       bool
+}
+
+func NewAnimation(from, to float64, duration time.Duration, easing Easing) *Animation {
+	if easing == nil {
+		easing = Linear
+	}
+	return &Animation{From: from, To: to, Duration: duration, Easing: easing}
+}
+
+// Get the elapsed share of the duration in [0, 1]
+func (a *Animation) Progress() float64 {
+	if a.done || a.Duration <= 0 || Settings.ReducedMotion {
+		return 1
+	}
+	if a.start.IsZero() {
+		return 0
+	}
+	return min(float64(time.Since(a.start))/float64(a.Duration), 1)
+}
+
+func (a *Animation) Value() float64 {
+	return a.From + (a.To-a.From)*a.Easing(a.Progress())
+}
+
+func (a *Animation) Done() bool {
+	return a.done
+}
+
+// Stop the animation where it is, OnComplete isn't called
+func (a *Animation) Stop() {
+	a.done = true
+}
+
+// Start the animation, it is stepped before every frame until it is done
+func (s *Screen) Animate(a *Animation) *Animation {
+	a.start, a.done = time.Now(), false
+	s.mu.Lock()
+	s.animations = append(s.animations, a)
+	s.mu.Unlock()
+	s.RequestContinuous(a.Duration)
+	return a
+}
+
+// Animate an int like the position or the size of a component
+func (s *Screen) AnimateInt(target *int, to int, duration time.Duration, easing Easing) *Animation {
+	a := NewAnimation(float64(*target), float64(to), duration, easing)
+	a.OnUpdate = func(v float64) {
+		*target = int(math.Round(v))
+	}
+	return s.Animate(a)
+}
+
+// Animate a color of a style, truecolor and basic colors are blended
+func (s *Screen) AnimateColor(target *string, to string, duration time.Duration, easing Easing) *Animation {
+	from := *target
+	a := NewAnimation(0, 1, duration, easing)
+	a.OnUpdate = func(v float64) {
+		*target = LogicValue(a.Progress() >= 1, to, colors.Mix(from, to, v))
+	}
+	return s.Animate(a)
+}
+
+// Step every running animation, the finished ones are removed after their last value
+func (s *Screen) stepAnimations() {
+	s.mu.Lock()
+	animations := s.animations
+	s.animations = nil
+	s.mu.Unlock()
+	running := []*Animation{}
+	for _, a := range animations {
+		if a.done {
+			continue
+		}
+		finished := a.Progress() >= 1
+		if a.OnUpdate != nil {
+			a.OnUpdate(a.Value())
+		}
+		if !finished {
+			running = append(running, a)
+			continue
+		}
+		a.done = true
+		if a.OnComplete != nil {
+			a.OnComplete()
+		}
+	}
+	s.mu.Lock()
+	s.animations = append(running, s.animations...)
+	s.mu.Unlock()
+}
@@ -0,0 +1,128 @@
+package osui
+
+import (
+	"fmt"
+	"os"
+	"strings"
+
+	"golang.org/x/term"
+)
+
+// Where a screen draws its frames and reads its input, the terminal by default.
+// Implement it to run a screen over ssh, in a browser or on an embedded display
+type Backend interface {
+	// Get ready for input, like raw mode and mouse reports, the returned function undoes it
+	Start() (stop func(), err error)
+	Size() (width, height int)
+	// Replace the shown frame with the new one
+	Draw(frame []string) error
+	// Block until the next input, keys, mouse reports and pastes are sent as escape sequences
+	ReadKey() (string, error)
+	// Show the cursor at x, y with the DECSCUSR style or hide it
+	SetCursor(visible bool, x, y int, style string)
+}
+
+// Backends that can put text on the clipboard of the user
+type ClipboardBackend interface {
+	SetClipboard(text string) error
+}
+
+// The backend of the terminal osui runs in
+type TerminalBackend struct{}
+
+func (TerminalBackend) Start() (func(), error) {
+	fd := int(os.Stdin.Fd())
+	oldState, err := term.MakeRaw(fd)
+	if err != nil {
+		return nil, err
+	}
+	mouse := Settings.Mouse
+	if mouse {
+		EnableMouse()
+	}
+	EnablePaste()
+	keyEvents := Settings.KeyEvents
+	if keyEvents {
+		fmt.Fprint(Terminal, keyEventsEnable+keyEventsQuery)
+	}
+	title := Settings.Title
+	if title != "" {
+		PushTitle()
+		SetTitle(title)
+	}
+	return func() {
+		if title != "" {
+			PopTitle()
+		}
+		if keyEvents {
+			fmt.Fprint(Terminal, keyEventsDisable)
+		}
+		DisablePaste()
+		if mouse {
+			DisableMouse()
+		}
+		ShowCursor()
+		term.Restore(fd, oldState)
+	}, nil
+}
+
+func (TerminalBackend) Size() (int, int) {
+	return GetTerminalSize()
+}
+
+func (TerminalBackend) Draw(frame []string) error {
+	Clear()
+	_, err := fmt.Fprint(Terminal, strings.Join(frame, ""))
+	return err
+}
+
+func (TerminalBackend) ReadKey() (string, error) {
+	return ReadKey()
+}
+
+// Backends whose reads can give up, the screen stops its reader with them once Run returns
+type stoppableReader interface {
+	readUntil(done <-chan struct{}) (string, error)
+	// Keep keys that were read but not handled for the next read
+	giveBack(keys []string)
+}
+
+func (TerminalBackend) readUntil(done <-chan struct{}) (string, error) {
+	return readKeyUntil(done)
+}
+
+func (TerminalBackend) giveBack(keys []string) {
+	unreadMu.Lock()
+	unread = append(keys, unread...)
+	unreadMu.Unlock()
+}
+
+func (TerminalBackend) SetClipboard(text string) error {
+	_, err := fmt.Fprint(Terminal, clipboardSequence(text))
+	return err
+}
+
+func (TerminalBackend) SetCursor(visible bool, x, y int, style string) {
+	if !visible {
+		HideCursor()
+		return
+	}
+	fmt.Fprintf(Terminal, "\x1b[%d;%dH%s", y+1, x+1, style)
+	ShowCursor()
+}
+
+// Run the screen on another backend than the terminal
+func NewScreenWith(c Component, backend Backend) *Screen {
+	s := newScreen(c)
+	s.backend = backend
+	return s
+}
+
+func (s *Screen) Backend() Backend {
+	return s.backend
+}
+
+// Get the size of the frames, the size of the terminal by default
+func (s *Screen) Size() (int, int) {
+	return s.backend.Size()
+}
@@ -0,0 +1,27 @@
+package osui
+
+// Run f without rendering in between, the screen renders once after it if anything asked for a render
+func (s *Screen) Batch(f func()) {
+	s.mu.Lock()
+	s.batching++
+	s.mu.Unlock()
+	defer func() {
+		s.mu.Lock()
+		s.batching--
+		render := s.batching == 0 && s.pendingRender
+		if render {
+			s.pendingRender = false
+		}
+		s.mu.Unlock()
+		if render {
+			s.RequestRender()
+		}
+	}()
+	f()
+}
+
+func (s *Screen) inBatch() bool {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	return s.batching > 0
+}
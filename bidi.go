@@ -0,0 +1,150 @@
+package osui
+
+import (
+	"fmt"
+	"strings"
+	"unicode"
+)
+
+// The base direction of text, right-to-left text is right aligned and its runs are laid out from the right
+type Direction int
+
+const (
+	Ltr Direction = iota
+	Rtl
+)
+
+func ParseDirection(s string) (Direction, error) {
+	switch strings.ToLower(s) {
+	case "ltr":
+		return Ltr, nil
+	case "rtl":
+		return Rtl, nil
+	}
+	return Ltr, fmt.Errorf("unknown direction %q, expected ltr or rtl", s)
+}
+
+// Whether the rune is a strong right-to-left character, like Hebrew or Arabic letters
+func IsRtl(r rune) bool {
+	return unicode.In(r, unicode.Hebrew, unicode.Arabic, unicode.Syriac, unicode.Thaana, unicode.Nko)
+}
+
+// Whether text in the component goes through the bidi pass, either for all text or for right-to-left components
+func BidiEnabled(d *ComponentData) bool {
+	return Settings.Bidi || d.Direction == Rtl
+}
+
+// The logical indices of the grapheme clusters in the order they are shown. This is a simplified bidi pass,
+// runs of right-to-left letters are reversed and neutrals between two letters of the same direction join them
+func BidiOrder(g []string, dir Direction) []int {
+	order, _ := bidiRuns(g, dir)
+	return order
+}
+
+// Reorder each line of plain text into visual order, brackets in right-to-left runs are mirrored
+func Bidi(s string, dir Direction) string {
+	lines := strings.Split(s, "\n")
+	for i, line := range lines {
+		g := Graphemes(line)
+		order, rtl := bidiRuns(g, dir)
+		var b strings.Builder
+		for _, j := range order {
+			if rtl[j] {
+				b.WriteString(mirror(g[j]))
+			} else {
+				b.WriteString(g[j])
+			}
+		}
+		lines[i] = b.String()
+	}
+	return strings.Join(lines, "\n")
+}
+
+// Right align each line to the width, lines that fill it are left as they are
+func AlignRight(s string, width int) string {
+	lines := strings.Split(s, "\n")
+	for i, line := range lines {
+		lines[i] = strings.Repeat(" ", max(width-VisibleWidth(line), 0)) + line
+	}
+	return strings.Join(lines, "\n")
+}
+
+// The visual order and the resolved direction of every grapheme cluster
+func bidiRuns(g []string, dir Direction) ([]int, []bool) {
+	// 1 is left-to-right, -1 is right-to-left and 0 is neutral
+	strong := make([]int, len(g))
+	for i, c := range g {
+		for _, r := range c {
+			if IsRtl(r) {
+				strong[i] = -1
+			} else if unicode.IsLetter(r) || unicode.IsDigit(r) {
+				strong[i] = 1
+			}
+			break
+		}
+	}
+	base := LogicValueInt(dir == Rtl, -1, 1)
+	rtl := make([]bool, len(g))
+	for i := 0; i < len(g); {
+		if strong[i] != 0 {
+			rtl[i] = strong[i] < 0
+			i++
+			continue
+		}
+		end := i
+		for end < len(g) && strong[end] == 0 {
+			end++
+		}
+		level := base
+		if i > 0 && end < len(g) && strong[i-1] == strong[end] {
+			level = strong[end]
+		}
+		for ; i < end; i++ {
+			rtl[i] = level < 0
+		}
+	}
+	var runs [][]int
+	for i := range g {
+		if i == 0 || rtl[i] != rtl[i-1] {
+			runs = append(runs, nil)
+		}
+		runs[len(runs)-1] = append(runs[len(runs)-1], i)
+	}
+	order := make([]int, 0, len(g))
+	for k := range runs {
+		run := runs[k]
+		if dir == Rtl {
+			run = runs[len(runs)-1-k]
+		}
+		if rtl[run[0]] {
+			for j := len(run) - 1; j >= 0; j-- {
+				order = append(order, run[j])
+			}
+		} else {
+			order = append(order, run...)
+		}
+	}
+	return order, rtl
+}
+
+func mirror(g string) string {
+	switch g {
+	case "(":
+		return ")"
+	case ")":
+		return "("
+	case "[":
+		return "]"
+	case "]":
+		return "["
+	case "{":
+		return "}"
+	case "}":
+		return "{"
+	case "<":
+		return ">"
+	case ">":
+		return "<"
+	}
+	return g
+}
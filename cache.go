@@ -0,0 +1,148 @@
+package osui
+
+import (
+	"hash"
+	"hash/fnv"
+	"reflect"
+	"sort"
+	"strings"
+	"sync/atomic"
+)
+
+// Components that report a version of what they draw. While the version and the sizes, states and styles of
+// the component and the descendants it drew stay the same, the output of its last render is drawn again
+// instead of calling Render, so big static parts of a ui cost nothing per frame. Change the version with
+// everything Render reads, for containers that includes the content of their children
+type Versioned interface {
+	Version() uint64
+}
+
+// What the output of a render depends on besides the content
+type renderKey struct {
+	version      uint64
+	width        int
+	height       int
+	states       string
+	defaultColor string
+	style        uint64
+}
+
+// The number of versioned components being rendered, their descendants remember their keys for the check
+var caching int
+
+// Render the component again on the next frame even if nothing of its key changed. The screen does it for the
+// components that get a key, a click or an event and for the tickers that ask for a render
+func (d *ComponentData) invalidateCache() {
+	atomic.StoreUint32(&d.invalid, 1)
+}
+
+func keyOf(c Component) renderKey {
+	data := c.GetComponentData()
+	key := renderKey{
+		width:        data.Width,
+		height:       data.Height,
+		states:       strings.Join(data.ActiveStates(), " "),
+		defaultColor: data.DefaultColor,
+		style:        styleHash(c),
+	}
+	if v, ok := c.(Versioned); ok {
+		key.version = v.Version()
+	}
+	return key
+}
+
+// Get the output of the last render of a versioned component if it is still fresh, the descendants it drew
+// then count as drawn in this frame too
+func cachedOutput(c Component) (string, bool) {
+	data := c.GetComponentData()
+	if !data.cached {
+		return "", false
+	}
+	drawn := []*ComponentData{}
+	var fresh func(c Component) bool
+	fresh = func(c Component) bool {
+		d := c.GetComponentData()
+		// Blending styles change without their key changing
+		if atomic.LoadUint32(&d.invalid) != 0 || d.transition != nil || keyOf(c) != d.cacheKey {
+			return false
+		}
+		drawn = append(drawn, d)
+		if container, ok := c.(Container); ok {
+			for _, child := range container.GetChildren() {
+				if child.GetComponentData().rendered == data.rendered && !fresh(child) {
+					return false
+				}
+			}
+		}
+		return true
+	}
+	if !fresh(c) {
+		return "", false
+	}
+	frame := data.Screen.frameNumber()
+	for _, d := range drawn {
+		d.frame, d.rendered, d.reused = frame, frame, true
+	}
+	return data.cache, true
+}
+
+// Remember what the output of the render depends on, for the cache of the versioned component or of the one
+// it is drawn in
+func (d *ComponentData) storeKey(c Component, output string, versioned bool) {
+	atomic.StoreUint32(&d.invalid, 0)
+	d.reused, d.rendered = false, d.Screen.frameNumber()
+	if versioned || caching > 0 {
+		d.cacheKey, d.cached = keyOf(c), true
+	}
+	if versioned {
+		d.cache = output
+	}
+}
+
+// A hash of the string fields of the style of the component and of its state styles
+func styleHash(c Component) uint64 {
+	h := fnv.New64a()
+	v := reflect.ValueOf(c)
+	for v.Kind() == reflect.Ptr {
+		v = v.Elem()
+	}
+	if v.Kind() == reflect.Struct {
+		hashStrings(h, v.FieldByName("Style"))
+	}
+	states := c.GetComponentData().States
+	names := make([]string, 0, len(states))
+	for name := range states {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	for _, name := range names {
+		h.Write([]byte(name))
+		hashStrings(h, reflect.ValueOf(states[name]))
+	}
+	return h.Sum64()
+}
+
+func hashStrings(h hash.Hash64, v reflect.Value) {
+	for v.IsValid() && v.Kind() == reflect.Ptr {
+		v = v.Elem()
+	}
+	if !v.IsValid() || v.Kind() != reflect.Struct {
+		return
+	}
+	for i := 0; i < v.NumField(); i++ {
+		if f := v.Field(i); f.Kind() == reflect.String {
+			h.Write([]byte(f.String()))
+			h.Write([]byte{0})
+		}
+	}
+}
+
+// Invalidate the focused components and the open modal
+func (s *Screen) invalidateFocus() {
+	for _, c := range s.FocusPath() {
+		c.GetComponentData().invalidateCache()
+	}
+	if m := s.Modal(); m != nil {
+		m.GetComponentData().invalidateCache()
+	}
+}
@@ -0,0 +1,39 @@
+package osui
+
+import (
+	"io"
+	"log"
+	"os"
+)
+
+// Where the frames are written, stays the terminal while the output is captured
+var Terminal io.Writer = os.Stdout
+
+// Redirect os.Stdout, os.Stderr and the log package into w, like a log view or a file, so prints from
+// the app and its dependencies don't corrupt the frame. The returned function restores them
+func CaptureOutput(w io.Writer) (restore func(), err error) {
+	r, pw, err := os.Pipe()
+	if err != nil {
+		return nil, err
+	}
+	stdout, stderr := os.Stdout, os.Stderr
+	os.Stdout, os.Stderr = pw, pw
+	logOutput := log.Writer()
+	if logOutput == stderr {
+		log.SetOutput(pw)
+	}
+	done := make(chan struct{})
+	go func() {
+		io.Copy(w, r)
+		close(done)
+	}()
+	return func() {
+		os.Stdout, os.Stderr = stdout, stderr
+		if log.Writer() == io.Writer(pw) {
+			log.SetOutput(logOutput)
+		}
+		pw.Close()
+		<-done
+		r.Close()
+	}, nil
+}
@@ -0,0 +1,107 @@
+package osui
+
+import (
+	"fmt"
+	"time"
+
+	"github.com/orus-dev/osui/colors"
+)
+
+type CaretShape int
+
+const (
+	CaretBlock CaretShape = iota
+	CaretUnderline
+	CaretBar
+)
+
+// How input components draw their caret
+type Caret struct {
+	Shape CaretShape
+	Blink bool
+	// Time the caret stays shown or hidden while blinking, 530ms when 0
+	Rate  time.Duration
+	Color string
+}
+
+var caretStart = time.Now()
+
+// Whether the caret is in its shown phase, always true when it doesn't blink
+func (c Caret) Visible() bool {
+	if !c.Blink {
+		return true
+	}
+	rate := c.Rate
+	if rate <= 0 {
+		rate = 530 * time.Millisecond
+	}
+	return (time.Since(caretStart)/rate)%2 == 0
+}
+
+// Draw the caret over char, restore is written after it to get back the style of the text
+func (c Caret) Render(char, restore string) string {
+	if !c.Visible() {
+		return char
+	}
+	switch c.Shape {
+	case CaretUnderline:
+		return c.Color + colors.Underline + char + colors.Reset + restore
+	case CaretBar:
+		if char == " " {
+			return c.Color + "▏" + colors.Reset + restore
+		}
+	}
+	return colors.Reverse + c.Color + char + colors.Reset + restore
+}
+
+// The escape sequence that gives the terminal cursor the shape of the caret
+func (c Caret) TerminalStyle() string {
+	n := 2 + int(c.Shape)*2
+	if c.Blink {
+		n--
+	}
+	return fmt.Sprintf("\x1b[%d q", n)
+}
+
+type cursorRequest struct {
+	component Component
+	x         int
+	y         int
+	caret     Caret
+}
+
+// Show the real terminal cursor at x, y inside the component once the frame is drawn,
+// only lasts for the current render
+func (s *Screen) SetCursor(c Component, x, y int, caret Caret) {
+	s.cursor = &cursorRequest{component: c, x: x, y: y, caret: caret}
+}
+
+func (s *Screen) placeCursor() {
+	if s.cursor != nil {
+		for _, root := range s.roots() {
+			if x, y, ok := positionOf(root, s.cursor.component, 0, 0); ok {
+				s.backend.SetCursor(true, x+s.cursor.x, y+s.cursor.y, s.cursor.caret.TerminalStyle())
+				return
+			}
+		}
+	}
+	s.backend.SetCursor(false, 0, 0, "")
+}
+
+// Get the screen position of target inside root
+func positionOf(root, target Component, ox, oy int) (int, int, bool) {
+	data := root.GetComponentData()
+	x, y := ox+data.X, oy+data.Y
+	if root == target {
+		return x, y, true
+	}
+	if container, ok := root.(Container); ok {
+		cx, cy := container.ChildOffset()
+		for _, child := range container.GetChildren() {
+			if px, py, ok := positionOf(child, target, x+cx, y+cy); ok {
+				return px, py, true
+			}
+		}
+	}
+	return 0, 0, false
+}
@@ -0,0 +1,206 @@
+package osui
+
+import (
+	"strings"
+	"sync"
+	"unicode/utf8"
+)
+
+// A character of a frame line with the escape sequences right before it
+type cell struct {
+	seq  string
+	char rune
+	// Where the sequences start in the line of the row
+	at int
+}
+
+// A frame line split into cells, drawing on it writes the cells in place
+type cellRow struct {
+	line  string
+	cells []cell
+	// The escape sequences after the last cell and where they start
+	tail   string
+	tailAt int
+}
+
+var (
+	// Guards the rows and the scratch cells, frames can be drawn outside of the renders of a screen
+	cellsMu sync.Mutex
+	// The rows of the frame lines drawn on so far by the address of the line, so drawing on a line again doesn't
+	// split it again. A row is only used while the line is still the one it was split from
+	rows = map[*string]*cellRow{}
+	// The rows of released lines, reused for the next ones
+	freeRows []*cellRow
+	// The cells of the line being drawn
+	scratch []cell
+)
+
+// Let go of the lines of the last frame, their rows are kept for the next one
+func releaseRows() {
+	cellsMu.Lock()
+	defer cellsMu.Unlock()
+	for key, row := range rows {
+		freeRows = append(freeRows, row)
+		delete(rows, key)
+	}
+}
+
+// The cells of a frame line, split again only when the line changed since it was drawn on. Called with cellsMu
+// held
+func rowOf(frame *[]string, y int) *cellRow {
+	key := &(*frame)[y]
+	row, ok := rows[key]
+	if ok && row.line == *key {
+		return row
+	}
+	if !ok {
+		// Frames drawn without a screen, like with RenderComponent alone, never release their rows
+		if len(rows) >= 1<<14 {
+			for key, row := range rows {
+				freeRows = append(freeRows, row)
+				delete(rows, key)
+			}
+		}
+		if n := len(freeRows); n > 0 {
+			row, freeRows = freeRows[n-1], freeRows[:n-1]
+		} else {
+			row = &cellRow{}
+		}
+		rows[key] = row
+	}
+	row.line = *key
+	row.cells, row.tail = appendCells(row.cells[:0], row.line)
+	row.tailAt = len(row.line) - len(row.tail)
+	return row
+}
+
+// Split the line into cells, the escape sequences are slices of it
+func appendCells(dst []cell, line string) ([]cell, string) {
+	seq, at := "", 0
+	for i := 0; i < len(line); {
+		if n := escapeLength(line[i:]); n > 0 {
+			seq, at = line[i:i+n], i
+			i += n
+			continue
+		}
+		if seq == "" {
+			at = i
+		}
+		r, size := utf8.DecodeRuneInString(line[i:])
+		dst = append(dst, cell{seq: seq, char: r, at: at})
+		seq = ""
+		i += size
+	}
+	return dst, seq
+}
+
+// The length of the escape sequences at the start of s, several in a row count as one. 0 if it doesn't start
+// with one
+func escapeLength(s string) int {
+	total := 0
+	for {
+		n := sequenceLength(s[total:])
+		if n == 0 {
+			return total
+		}
+		total += n
+	}
+}
+
+// The length of the CSI or OSC sequence at the start of s, OSC ones end with BEL or ST
+func sequenceLength(s string) int {
+	if len(s) < 2 || s[0] != 0x1b {
+		return 0
+	}
+	switch s[1] {
+	case '[':
+		for i := 2; i < len(s); i++ {
+			c := s[i]
+			switch {
+			case (c >= '0' && c <= '9') || c == ';':
+			case (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z'):
+				return i + 1
+			default:
+				return 0
+			}
+		}
+	case ']':
+		for i := 2; i < len(s); i++ {
+			switch s[i] {
+			case 0x07:
+				return i + 1
+			case 0x1b:
+				if i+1 < len(s) && s[i+1] == '\\' {
+					return i + 2
+				}
+				return 0
+			}
+		}
+	}
+	return 0
+}
+
+// Cut the cells starting at x to the columns [from, to), x may be negative. The escape sequences before the cut
+// are kept at its start and the ones after it at its end, so styles and resets aren't lost
+func clipCells(cells []cell, tail string, x, from, to int) ([]cell, string, int) {
+	start := min(max(from-x, 0), len(cells))
+	end := max(min(to-x, len(cells)), start)
+	if start == 0 && end == len(cells) {
+		return cells, tail, x
+	}
+	if start == end {
+		return nil, "", x + start
+	}
+	res := cells[start:end]
+	res[0].seq = joinSequences(cells[:start+1])
+	suffix := joinSequences(cells[end:]) + tail
+	return res, suffix, x + start
+}
+
+// The escape sequences of the cells one after the other
+func joinSequences(cells []cell) string {
+	var b strings.Builder
+	for _, c := range cells {
+		b.WriteString(c.seq)
+	}
+	return b.String()
+}
+
+// Write the cells over the row starting at x like RenderLine, the sequences of the cells they cover are applied
+// again after them so the rest of the row keeps its styles. The cells have to fit in the row. Only the covered
+// part of the line is written again, the rest is copied as it is
+func (row *cellRow) draw(cells []cell, tail string, x int) {
+	if len(cells) == 0 {
+		return
+	}
+	end := x + len(cells)
+	start, rest := row.cells[x].at, row.tailAt
+	if end < len(row.cells) {
+		rest = row.cells[end].at
+	}
+	after := tail + joinSequences(row.cells[x:end])
+	var b strings.Builder
+	b.Grow(len(row.line) + len(after) + len(cells))
+	b.WriteString(row.line[:start])
+	for i, c := range cells {
+		c.at = b.Len()
+		row.cells[x+i] = c
+		b.WriteString(c.seq)
+		b.WriteRune(c.char)
+	}
+	shift := b.Len() - rest
+	b.WriteString(after)
+	b.WriteString(row.line[rest:])
+	row.line = b.String()
+	if end == len(row.cells) {
+		row.tail = after + row.tail
+		row.tailAt += shift
+		return
+	}
+	row.cells[end].seq = after + row.cells[end].seq
+	row.cells[end].at += shift
+	for i := end + 1; i < len(row.cells); i++ {
+		row.cells[i].at += shift + len(after)
+	}
+	row.tailAt += shift + len(after)
+}
@@ -9,6 +9,7 @@ import (
 const (
 	Reset     = "\x1b[0m"
 	Bold      = "\x1b[1m"
+	Dim       = "\x1b[2m"
 	Underline = "\x1b[4m"
 	Italic    = "\x1b[3m"
 	Reverse   = "\x1b[7m"
@@ -0,0 +1,94 @@
+package colors
+
+import (
+	"fmt"
+	"regexp"
+	"strconv"
+	"strings"
+	"unicode/utf8"
+)
+
+var (
+	gradientRe = regexp.MustCompile(`\x1b\[([34])8;9;(\d+);(\d+);(\d+);(\d+);(\d+);(\d+)m`)
+	escapeRe   = regexp.MustCompile(`\x1b\[[0-9;]*[a-zA-Z]|\x1b\][^\x07\x1b]*(\x07|\x1b\\)`)
+)
+
+// A color fading from one color to the other across the cells after it, up to the next reset or the end of the
+// line. Both are truecolor or basic colors, anything else gives from. The gradient is a background if from is one
+func Gradient(from, to string) string {
+	a, bg, okA := ToRgb(from)
+	b, _, okB := ToRgb(to)
+	if !okA || !okB {
+		return from
+	}
+	kind := 3
+	if bg {
+		kind = 4
+	}
+	return fmt.Sprintf("\x1b[%d8;9;%d;%d;%d;%d;%d;%dm", kind, a[0], a[1], a[2], b[0], b[1], b[2])
+}
+
+// The color of the gradient at t, 0 is its start and 1 its end. Anything but a gradient is returned as it is
+func GradientAt(s string, t float64) string {
+	m := gradientRe.FindStringSubmatch(s)
+	if m == nil || len(m[0]) != len(s) {
+		return s
+	}
+	from, to := gradientEnds(m)
+	return mixRgb(from, to, t, m[1] == "4")
+}
+
+// Replace the gradients in the line with a color for every cell they cover, so the terminal can show them
+func ExpandGradients(line string) string {
+	// Most lines have none, the check is much cheaper than the search
+	if !strings.Contains(line, "8;9;") {
+		return line
+	}
+	loc := gradientRe.FindStringSubmatchIndex(line)
+	if loc == nil {
+		return line
+	}
+	m := make([]string, len(loc)/2)
+	for i := range m {
+		m[i] = line[loc[2*i]:loc[2*i+1]]
+	}
+	from, to := gradientEnds(m)
+	rest := line[loc[1]:]
+	end := len(rest)
+	if i := strings.Index(rest, Reset); i >= 0 {
+		end = i
+	}
+	if next := gradientRe.FindStringIndex(rest); next != nil && next[0] < end {
+		end = next[0]
+	}
+	segment := rest[:end]
+	cells := utf8.RuneCountInString(escapeRe.ReplaceAllString(segment, ""))
+	var b strings.Builder
+	b.WriteString(line[:loc[0]])
+	for i := 0; len(segment) > 0; {
+		if l := escapeRe.FindStringIndex(segment); l != nil && l[0] == 0 {
+			b.WriteString(segment[:l[1]])
+			segment = segment[l[1]:]
+			continue
+		}
+		t := 0.0
+		if cells > 1 {
+			t = float64(i) / float64(cells-1)
+		}
+		_, size := utf8.DecodeRuneInString(segment)
+		b.WriteString(mixRgb(from, to, t, m[1] == "4"))
+		b.WriteString(segment[:size])
+		segment = segment[size:]
+		i++
+	}
+	return b.String() + ExpandGradients(rest[end:])
+}
+
+func gradientEnds(m []string) (from, to [3]uint8) {
+	for i := 0; i < 3; i++ {
+		a, _ := strconv.Atoi(m[i+2])
+		b, _ := strconv.Atoi(m[i+5])
+		from[i], to[i] = uint8(min(a, 255)), uint8(min(b, 255))
+	}
+	return from, to
+}
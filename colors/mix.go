@@ -0,0 +1,63 @@
+package colors
+
+import (
+	"fmt"
+	"regexp"
+	"strconv"
+)
+
+var (
+	rgbRe   = regexp.MustCompile(`^\x1b\[([34])8;2;(\d+);(\d+);(\d+)m$`)
+	basicRe = regexp.MustCompile(`^\x1b\[([34])([0-7])m$`)
+)
+
+// The RGB values of the 8 basic colors, as most terminals show them
+var basic = [8][3]uint8{
+	{0, 0, 0}, {205, 49, 49}, {13, 188, 121}, {229, 229, 16},
+	{36, 114, 200}, {188, 63, 188}, {17, 168, 205}, {229, 229, 229},
+}
+
+// Get the red, green and blue of a single truecolor or basic color, bg is true for background colors
+func ToRgb(s string) (rgb [3]uint8, bg bool, ok bool) {
+	if m := rgbRe.FindStringSubmatch(s); m != nil {
+		for i := range rgb {
+			n, _ := strconv.Atoi(m[i+2])
+			rgb[i] = uint8(min(n, 255))
+		}
+		return rgb, m[1] == "4", true
+	}
+	if m := basicRe.FindStringSubmatch(s); m != nil {
+		return basic[m[2][0]-'0'], m[1] == "4", true
+	}
+	return rgb, false, false
+}
+
+func RgbBg(r, g, b uint8) string {
+	return fmt.Sprintf("\x1b[48;2;%d;%d;%dm", r, g, b)
+}
+
+// Blend two colors, t is 0 for from and 1 for to. The result is a background color if from is one.
+// Anything but a single truecolor or basic color, like bold text, switches at the middle instead
+func Mix(from, to string, t float64) string {
+	a, bg, okA := ToRgb(from)
+	b, _, okB := ToRgb(to)
+	if !okA || !okB {
+		if t < 0.5 {
+			return from
+		}
+		return to
+	}
+	return mixRgb(a, b, t, bg)
+}
+
+func mixRgb(a, b [3]uint8, t float64, bg bool) string {
+	t = min(max(t, 0), 1)
+	var c [3]uint8
+	for i := range c {
+		c[i] = uint8(float64(a[i]) + (float64(b[i])-float64(a[i]))*t + 0.5)
+	}
+	if bg {
+		return RgbBg(c[0], c[1], c[2])
+	}
+	return Rgb(c[0], c[1], c[2])
+}
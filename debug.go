@@ -0,0 +1,88 @@
+package osui
+
+import (
+	"encoding/json"
+	"os"
+	"reflect"
+)
+
+type DebugNode struct {
+	Type      string            `json:"type"`
+	Id        string            `json:"id,omitempty"`
+	Classes   []string          `json:"classes,omitempty"`
+	X         int               `json:"x"`
+	Y         int               `json:"y"`
+	AbsoluteX int               `json:"absolute_x"`
+	AbsoluteY int               `json:"absolute_y"`
+	Width     int               `json:"width"`
+	Height    int               `json:"height"`
+	Focused   bool              `json:"focused"`
+	Hovered   bool              `json:"hovered"`
+	Rendered  bool              `json:"rendered"`
+	Style     map[string]string `json:"style,omitempty"`
+	Children  []DebugNode       `json:"children,omitempty"`
+}
+
+// Describe the component tree with the layout and styles of the last frame
+func DebugTree(c Component) DebugNode {
+	return debugTree(c, 0, 0)
+}
+
+// Write the component tree as json, useful to attach to bug reports
+func (s *Screen) DumpDebug(path string) error {
+	data, err := json.MarshalIndent(DebugTree(s.component), "", "  ")
+	if err != nil {
+		return err
+	}
+	return os.WriteFile(path, data, 0644)
+}
+
+func debugTree(c Component, ox, oy int) DebugNode {
+	data := c.GetComponentData()
+	node := DebugNode{
+		Type:      TypeName(c),
+		Id:        data.Id,
+		Classes:   data.Classes,
+		X:         data.X,
+		Y:         data.Y,
+		AbsoluteX: ox + data.X,
+		AbsoluteY: oy + data.Y,
+		Width:     data.Width,
+		Height:    data.Height,
+		Focused:   data.IsActive,
+		Hovered:   data.Hovered,
+		Rendered:  data.drawn(),
+		Style:     debugStyle(c),
+	}
+	if container, ok := c.(Container); ok {
+		cx, cy := container.ChildOffset()
+		for _, child := range container.GetChildren() {
+			node.Children = append(node.Children, debugTree(child, node.AbsoluteX+cx, node.AbsoluteY+cy))
+		}
+	}
+	return node
+}
+
+func debugStyle(c Component) map[string]string {
+	v := reflect.ValueOf(c)
+	for v.Kind() == reflect.Ptr {
+		v = v.Elem()
+	}
+	if v.Kind() != reflect.Struct {
+		return nil
+	}
+	style := v.FieldByName("Style")
+	for style.IsValid() && style.Kind() == reflect.Ptr {
+		style = style.Elem()
+	}
+	if !style.IsValid() || style.Kind() != reflect.Struct {
+		return nil
+	}
+	res := map[string]string{}
+	for i := 0; i < style.NumField(); i++ {
+		if f := style.Field(i); f.Kind() == reflect.String {
+			res[style.Type().Field(i).Name] = f.String()
+		}
+	}
+	return res
+}
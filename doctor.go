@@ -0,0 +1,124 @@
+package osui
+
+import (
+	"fmt"
+	"os"
+	"regexp"
+	"runtime"
+	"strconv"
+	"strings"
+	"time"
+
+	"golang.org/x/term"
+)
+
+var (
+	cursorReportRe = regexp.MustCompile(`\x1b\[(\d+);(\d+)R`)
+	kittyReportRe  = regexp.MustCompile(`\x1b\[\?\d*u`)
+	deviceAttrRe   = regexp.MustCompile(`\x1b\[\?[0-9;]*c`)
+)
+
+// What the running terminal supports
+type Report struct {
+	Term             string
+	Width            int
+	Height           int
+	ColorDepth       int
+	Unicode          bool
+	WideCharWidth    int
+	Mouse            bool
+	KeyboardProtocol string
+	Responded        bool
+}
+
+// Probe the terminal, it must not be in use by a running screen
+func Doctor() Report {
+	r := Report{Term: os.Getenv("TERM"), ColorDepth: detectColorDepth(), KeyboardProtocol: "legacy"}
+	r.Width, r.Height = GetTerminalSize()
+	r.Unicode = DetectGlyphs() == GlyphsDefault
+	r.Mouse = r.Term != "dumb" && r.Term != "linux"
+
+	fd := int(os.Stdin.Fd())
+	oldState, err := term.MakeRaw(fd)
+	if err != nil {
+		return r
+	}
+	defer term.Restore(fd, oldState)
+
+	fmt.Fprint(Terminal, "\r界\x1b[6n\x1b[?u\x1b[c")
+	response := readResponse(500 * time.Millisecond)
+	fmt.Fprint(Terminal, "\r\x1b[K")
+
+	r.Responded = deviceAttrRe.MatchString(response)
+	if m := cursorReportRe.FindStringSubmatch(response); m != nil {
+		col, _ := strconv.Atoi(m[2])
+		r.WideCharWidth = col - 1
+		r.Unicode = r.Unicode && r.WideCharWidth == 2
+	}
+	if kittyReportRe.MatchString(response) {
+		r.KeyboardProtocol = "kitty"
+	}
+	return r
+}
+
+func (r Report) String() string {
+	var b strings.Builder
+	fmt.Fprintf(&b, "Terminal:          %s\n", orDefault(r.Term, "unknown"))
+	fmt.Fprintf(&b, "Size:              %dx%d\n", r.Width, r.Height)
+	fmt.Fprintf(&b, "Color depth:       %d bit\n", r.ColorDepth)
+	fmt.Fprintf(&b, "Unicode:           %t\n", r.Unicode)
+	fmt.Fprintf(&b, "Wide char width:   %s\n", orDefault(strconv.Itoa(r.WideCharWidth), "unknown"))
+	fmt.Fprintf(&b, "Mouse:             %t\n", r.Mouse)
+	fmt.Fprintf(&b, "Keyboard protocol: %s\n", r.KeyboardProtocol)
+	if !r.Responded {
+		b.WriteString("The terminal didn't answer the probes, the results are guesses\n")
+	}
+	return b.String()
+}
+
+func detectColorDepth() int {
+	colorTerm := strings.ToLower(os.Getenv("COLORTERM"))
+	t := os.Getenv("TERM")
+	switch {
+	case colorTerm == "truecolor" || colorTerm == "24bit" || runtime.GOOS == "windows":
+		return 24
+	case strings.Contains(t, "256color"):
+		return 8
+	case t == "dumb":
+		return 1
+	}
+	return 4
+}
+
+// Read what the terminal answers until the device attributes arrive or the timeout ends. Where stdin can be
+// polled the read stops at the timeout, elsewhere it is left waiting for the next key
+func readResponse(timeout time.Duration) string {
+	done := make(chan struct{})
+	timer := time.AfterFunc(timeout, func() { close(done) })
+	defer timer.Stop()
+	data := make(chan string, 1)
+	go func() {
+		var res string
+		for !deviceAttrRe.MatchString(res) {
+			k, err := readKeyUntil(done)
+			if err != nil {
+				break
+			}
+			res += k
+		}
+		data <- res
+	}()
+	select {
+	case res := <-data:
+		return res
+	case <-done:
+		return ""
+	}
+}
+
+func orDefault(s, fallback string) string {
+	if s == "" || s == "0" {
+		return fallback
+	}
+	return s
+}
@@ -0,0 +1,36 @@
+package osui
+
+type Event struct {
+	Name string
+	Data any
+}
+
+// Emit an event into the ui loop, safe to call from any goroutine. It never blocks, the handlers run once the
+// loop gets to it
+func (s *Screen) Emit(name string, data any) {
+	s.enqueue(func() {
+		s.dispatch(Event{Name: name, Data: data})
+	})
+}
+
+// Subscribe to events with the given name, handlers run on the ui loop
+func (s *Screen) On(name string, handler func(Event)) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	s.handlers[name] = append(s.handlers[name], handler)
+}
+
+func (s *Screen) dispatch(e Event) {
+	s.mu.Lock()
+	handlers := append([]func(Event){}, s.handlers[e.Name]...)
+	s.mu.Unlock()
+	for _, h := range handlers {
+		h(e)
+	}
+	for _, c := range s.unfocusedListeners() {
+		if l, ok := c.(EventListener); ok {
+			l.OnEvent(e)
+			c.GetComponentData().invalidateCache()
+		}
+	}
+}
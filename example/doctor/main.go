@@ -0,0 +1,11 @@
+package main
+
+import (
+	"fmt"
+
+	"github.com/orus-dev/osui"
+)
+
+func main() {
+	fmt.Print(osui.Doctor())
+}
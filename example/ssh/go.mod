@@ -0,0 +1,10 @@
+module github.com/orus-dev/osui/example/ssh
+
+go 1.23.1
+
+require (
+	github.com/orus-dev/osui v0.0.0
+	golang.org/x/crypto v0.27.0
+)
+
+replace github.com/orus-dev/osui => ../..
@@ -0,0 +1,158 @@
+// Serve an osui app over ssh, every connection gets its own screen.
+// It lives in its own module so osui itself doesn't depend on an ssh library,
+// go mod tidy fetches that library and writes the go.sum of the module.
+//
+//	go mod tidy
+//	OSUI_SSH_PASSWORD=secret go run . &
+//	ssh -p 2222 admin@localhost
+package main
+
+import (
+	"crypto/ed25519"
+	"crypto/rand"
+	"crypto/subtle"
+	"fmt"
+	"log"
+	"net"
+	"os"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/ui"
+	"golang.org/x/crypto/ssh"
+)
+
+func main() {
+	password := os.Getenv("OSUI_SSH_PASSWORD")
+	if password == "" {
+		log.Fatal("set OSUI_SSH_PASSWORD")
+	}
+	config := &ssh.ServerConfig{
+		PasswordCallback: func(conn ssh.ConnMetadata, pass []byte) (*ssh.Permissions, error) {
+			if subtle.ConstantTimeCompare(pass, []byte(password)) == 1 {
+				return nil, nil
+			}
+			return nil, fmt.Errorf("wrong password for %s", conn.User())
+		},
+	}
+	// A new host key on every start keeps the example short, load a fixed one in a real server
+	_, key, err := ed25519.GenerateKey(rand.Reader)
+	if err != nil {
+		log.Fatal(err)
+	}
+	signer, err := ssh.NewSignerFromKey(key)
+	if err != nil {
+		log.Fatal(err)
+	}
+	config.AddHostKey(signer)
+
+	listener, err := net.Listen("tcp", ":2222")
+	if err != nil {
+		log.Fatal(err)
+	}
+	for {
+		conn, err := listener.Accept()
+		if err != nil {
+			log.Fatal(err)
+		}
+		go serve(conn, config)
+	}
+}
+
+func serve(conn net.Conn, config *ssh.ServerConfig) {
+	sshConn, chans, reqs, err := ssh.NewServerConn(conn, config)
+	if err != nil {
+		conn.Close()
+		return
+	}
+	defer sshConn.Close()
+	go ssh.DiscardRequests(reqs)
+	for newChannel := range chans {
+		if newChannel.ChannelType() != "session" {
+			newChannel.Reject(ssh.UnknownChannelType, "only sessions are supported")
+			continue
+		}
+		channel, requests, err := newChannel.Accept()
+		if err != nil {
+			continue
+		}
+		go session(sshConn.User(), channel, requests)
+	}
+}
+
+type ptyRequest struct {
+	Term                    string
+	Columns, Rows           uint32
+	PixelWidth, PixelHeight uint32
+	Modes                   string
+}
+
+type windowChange struct {
+	Columns, Rows           uint32
+	PixelWidth, PixelHeight uint32
+}
+
+// Run a screen on the channel once the client asks for a shell, the window changes resize it
+func session(user string, channel ssh.Channel, requests <-chan *ssh.Request) {
+	defer channel.Close()
+	width, height := 80, 24
+	var screen *osui.Screen
+	var backend *osui.StreamBackend
+	// Gets true once the shell starts, false if the client leaves before
+	started := make(chan bool, 1)
+	go func() {
+		for req := range requests {
+			switch req.Type {
+			case "pty-req":
+				var p ptyRequest
+				if ssh.Unmarshal(req.Payload, &p) == nil {
+					width, height = int(p.Columns), int(p.Rows)
+				}
+				req.Reply(true, nil)
+			case "window-change":
+				var w windowChange
+				if ssh.Unmarshal(req.Payload, &w) == nil && backend != nil {
+					backend.Resize(int(w.Columns), int(w.Rows))
+					screen.RequestRender()
+				}
+			case "shell":
+				if screen != nil {
+					req.Reply(false, nil)
+					continue
+				}
+				screen, backend = osui.NewStreamScreen(App(user), channel, channel, width, height)
+				backend.Mouse = true
+				req.Reply(true, nil)
+				started <- true
+			default:
+				req.Reply(false, nil)
+			}
+		}
+		if screen == nil {
+			started <- false
+		}
+	}()
+	if !<-started {
+		return
+	}
+	screen.Run()
+	channel.SendRequest("exit-status", false, ssh.Marshal(struct{ Status uint32 }{0}))
+}
+
+// Ends the session on q or Ctrl+C
+type console struct {
+	*ui.DivComponent
+}
+
+func (c console) Update(key string) bool {
+	if key == "q" || key == "\x03" {
+		return true
+	}
+	return c.DivComponent.Update(key)
+}
+
+func App(user string) osui.Component {
+	return console{ui.Div(
+		ui.Text("Admin console"),
+		ui.Text("Signed in as "+user+", press q to quit"),
+	)}
+}
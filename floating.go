@@ -0,0 +1,61 @@
+package osui
+
+import "sort"
+
+type floatingComponent struct {
+	component Component
+	x         int
+	y         int
+}
+
+// Get the floating components drawn in the last frame with their screen position, from the lowest z-index up.
+// Components with the same z-index keep the order of the tree
+func floatingIn(root Component) []floatingComponent {
+	res := []floatingComponent{}
+	var walk func(c Component, ox, oy int)
+	walk = func(c Component, ox, oy int) {
+		data := c.GetComponentData()
+		if !data.drawn() {
+			return
+		}
+		x, y := ox+data.X, oy+data.Y
+		if data.Floating {
+			res = append(res, floatingComponent{component: c, x: x, y: y})
+		}
+		if container, ok := c.(Container); ok {
+			cx, cy := container.ChildOffset()
+			for _, child := range container.GetChildren() {
+				walk(child, x+cx, y+cy)
+			}
+		}
+	}
+	walk(root, 0, 0)
+	sort.SliceStable(res, func(i, j int) bool {
+		return res[i].component.GetComponentData().ZIndex < res[j].component.GetComponentData().ZIndex
+	})
+	return res
+}
+
+// Draw the floating components of the tree over everything else, they are only clipped by the edges of the screen
+func drawFloating(root Component, frame *[]string) {
+	saved := clips
+	clips = nil
+	for _, f := range floatingIn(root) {
+		drawElevation(f.component, f.component.GetComponentData().floatOutput, f.x, f.y, frame)
+		drawComponent(f.component, f.component.GetComponentData().floatOutput, f.x, f.y, frame)
+	}
+	clips = saved
+}
+
+// Get the components under the position inside the floating components of the tree, the highest first
+func floatingAt(root Component, x, y int) []Component {
+	floating := floatingIn(root)
+	for i := len(floating) - 1; i >= 0; i-- {
+		f := floating[i]
+		data := f.component.GetComponentData()
+		if path := componentsAt(f.component, x, y, f.x-data.X, f.y-data.Y); path != nil {
+			return path
+		}
+	}
+	return nil
+}
@@ -0,0 +1,71 @@
+package osui
+
+// Components that can keep the focus, BeforeBlur returns false to cancel the focus change
+type BlurGuard interface {
+	BeforeBlur() bool
+}
+
+// Check that c and its focused descendants allow the focus to move away
+func CanBlur(c Component) bool {
+	if g, ok := c.(BlurGuard); ok && !g.BeforeBlur() {
+		return false
+	}
+	if container, ok := c.(Container); ok {
+		for _, child := range container.GetChildren() {
+			if child.GetComponentData().IsActive && !CanBlur(child) {
+				return false
+			}
+		}
+	}
+	return true
+}
+
+// Whether the focus can move onto c, disabled components are skipped
+func Focusable(c Component) bool {
+	return !c.GetComponentData().Disabled
+}
+
+// Get the deepest focused component
+func (s *Screen) Focused() Component {
+	path := s.FocusPath()
+	return path[len(path)-1]
+}
+
+// Get the focused components from the root down to the deepest one
+func (s *Screen) FocusPath() []Component {
+	return ActivePath(s.component)
+}
+
+// The active components from c down to the deepest one, c first
+func ActivePath(c Component) []Component {
+	path := []Component{c}
+	for {
+		container, ok := c.(Container)
+		if !ok {
+			return path
+		}
+		var next Component
+		for _, child := range container.GetChildren() {
+			if child.GetComponentData().IsActive {
+				next = child
+				break
+			}
+		}
+		if next == nil {
+			return path
+		}
+		c = next
+		path = append(path, c)
+	}
+}
+
+// Stop or resume delivering keys and mouse events to the components, while stopped only the resume
+// binding is handled and nothing is shown as focused
+func (s *Screen) SetInteractive(interactive bool) {
+	s.frozen = !interactive
+	s.RequestRender()
+}
+
+func (s *Screen) Interactive() bool {
+	return !s.frozen
+}
@@ -0,0 +1,47 @@
+package osui
+
+// Run f once after the next frame is written, when the positions and sizes of that frame are known.
+// Safe to call from any goroutine, it asks for the frame
+func (s *Screen) AfterFrame(f func()) {
+	s.mu.Lock()
+	s.afterFrame = append(s.afterFrame, f)
+	s.mu.Unlock()
+	s.RequestRender()
+}
+
+func (s *Screen) runAfterFrame() {
+	s.mu.Lock()
+	callbacks := s.afterFrame
+	s.afterFrame = nil
+	s.mu.Unlock()
+	for _, f := range callbacks {
+		f()
+	}
+}
+
+// Whether the component was drawn in the last frame of its screen
+func (d *ComponentData) drawn() bool {
+	return d.Screen != nil && d.frame == d.Screen.frameCount
+}
+
+// The number of the frame the screen draws, 0 for components drawn without one
+func (s *Screen) frameNumber() uint64 {
+	if s == nil {
+		return 0
+	}
+	return s.frameCount
+}
+
+// Get where the component was drawn in the last frame, the size is the size of its output
+func (s *Screen) Bounds(c Component) (Rect, bool) {
+	data := c.GetComponentData()
+	if data.Screen != s || !data.drawn() {
+		return Rect{}, false
+	}
+	for _, root := range s.roots() {
+		if x, y, ok := positionOf(root, c, 0, 0); ok {
+			return Rect{X: x, Y: y, Width: data.drawnWidth, Height: data.drawnHeight}, true
+		}
+	}
+	return Rect{}, false
+}
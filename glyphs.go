@@ -0,0 +1,63 @@
+package osui
+
+import (
+	"os"
+	"runtime"
+	"strings"
+)
+
+type GlyphSet struct {
+	TopLeft     string
+	Top         string
+	TopRight    string
+	Left        string
+	Right       string
+	BottomLeft  string
+	Bottom      string
+	BottomRight string
+	Horizontal  string
+	Vertical    string
+	ScrollTrack string
+	ScrollThumb string
+	// The indicators of expanded and collapsed sections, like in trees and cards
+	Expanded  string
+	Collapsed string
+	// Between the items of a row, like tabs and the sections of a status bar
+	Separator string
+	// The axes of charts, the ticks on the left axis, the corner and the ticks on the bottom axis
+	AxisTick   string
+	AxisCorner string
+	AxisMark   string
+}
+
+var (
+	GlyphsDefault = GlyphSet{" ", "_", " ", "│", "│", " ", "‾", " ", "─", "│", "│", "█", "▾", "▸", "│", "┤", "└", "┬"}
+	GlyphsLight   = GlyphSet{"┌", "─", "┐", "│", "│", "└", "─", "┘", "─", "│", "│", "█", "▾", "▸", "│", "┤", "└", "┬"}
+	GlyphsHeavy   = GlyphSet{"┏", "━", "┓", "┃", "┃", "┗", "━", "┛", "━", "┃", "┃", "█", "▾", "▸", "┃", "┫", "┗", "┳"}
+	GlyphsRounded = GlyphSet{"╭", "─", "╮", "│", "│", "╰", "─", "╯", "─", "│", "│", "█", "▾", "▸", "│", "┤", "╰", "┬"}
+	GlyphsAscii   = GlyphSet{"+", "-", "+", "|", "|", "+", "-", "+", "-", "|", "|", "#", "v", ">", "|", "+", "+", "+"}
+)
+
+func (g GlyphSet) TopBorder(width int) string {
+	return g.TopLeft + strings.Repeat(g.Top, max(width-2, 0)) + g.TopRight
+}
+
+func (g GlyphSet) BottomBorder(width int) string {
+	return g.BottomLeft + strings.Repeat(g.Bottom, max(width-2, 0)) + g.BottomRight
+}
+
+// Pick the ascii glyphs when the locale says the terminal can't show unicode
+func DetectGlyphs() GlyphSet {
+	if runtime.GOOS == "windows" {
+		return GlyphsDefault
+	}
+	for _, env := range []string{"LC_ALL", "LC_CTYPE", "LANG"} {
+		if v := strings.ToLower(os.Getenv(env)); v != "" {
+			if strings.Contains(v, "utf-8") || strings.Contains(v, "utf8") {
+				return GlyphsDefault
+			}
+			return GlyphsAscii
+		}
+	}
+	return GlyphsDefault
+}
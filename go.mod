@@ -6,5 +6,5 @@ require golang.org/x/term v0.24.0 // direct
 
 require (
 	github.com/nathan-fiscaletti/consolesize-go v0.0.0-20220204101620-317176b6684d
-	golang.org/x/sys v0.25.0 // indirect
+	golang.org/x/sys v0.25.0
 )
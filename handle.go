@@ -0,0 +1,72 @@
+package osui
+
+// A handle to a screen for other goroutines, everything it does is safe to call from any of them.
+// It is a small value, copy it into every goroutine that needs it
+type Handle struct {
+	screen *Screen
+}
+
+func (s *Screen) Handle() Handle {
+	return Handle{screen: s}
+}
+
+func (h Handle) RequestRender() {
+	h.screen.RequestRender()
+}
+
+func (h Handle) Emit(name string, data any) {
+	h.screen.Emit(name, data)
+}
+
+// Run f on the ui loop, where it can change components like a key handler. It never blocks, calls from the ui
+// loop itself run after the current handler
+func (h Handle) Do(f func()) {
+	h.screen.enqueue(f)
+}
+
+// Stop Run like the root component finishing would, calling it again does nothing
+func (h Handle) Exit() {
+	h.screen.quitOnce.Do(func() {
+		close(h.screen.quit)
+	})
+}
+
+// Run f on the ui loop while the screen runs, right away while it doesn't
+func (s *Screen) runOnLoop(f func()) {
+	runningMu.Lock()
+	_, isRunning := running[s]
+	runningMu.Unlock()
+	if !isRunning {
+		f()
+		return
+	}
+	s.enqueue(f)
+}
+
+// Queue f for the ui loop and wake it up
+func (s *Screen) enqueue(f func()) {
+	s.mu.Lock()
+	s.queued = append(s.queued, f)
+	s.mu.Unlock()
+	select {
+	case s.wake <- struct{}{}:
+	default:
+	}
+}
+
+// Run the functions queued so far, the ones they queue wake the loop again. Returns whether there were any
+func (s *Screen) runQueued() bool {
+	s.mu.Lock()
+	queued := s.queued
+	s.queued = nil
+	s.mu.Unlock()
+	for _, f := range queued {
+		f()
+	}
+	return len(queued) > 0
+}
+
+// Get the channel closed once Exit was called
+func (h Handle) Exited() <-chan struct{} {
+	return h.screen.quit
+}
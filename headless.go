@@ -0,0 +1,154 @@
+package osui
+
+import (
+	"fmt"
+	"io"
+	"strings"
+)
+
+// Render the component on a width x height frame without a terminal, the lines are joined with new lines
+func RenderToString(c Component, width, height int) string {
+	return NewTestBackend(c, width, height).Render()
+}
+
+// Drives a screen without a terminal, for tests asserting on the rendered frames.
+// Keys, mouse reports, pastes and events are handled like in Run and every one of them renders a frame
+type TestBackend struct {
+	Screen *Screen
+	Width  int
+	Height int
+	// Every frame rendered so far, the lines joined with new lines
+	Frames []string
+	// The text copied last
+	Clipboard string
+	done      bool
+}
+
+func NewTestBackend(c Component, width, height int) *TestBackend {
+	t := &TestBackend{Width: width, Height: height}
+	t.Screen = NewScreenWith(c, t)
+	t.Screen.component.GetComponentData().Screen = t.Screen
+	return t
+}
+
+func (t *TestBackend) Start() (func(), error) {
+	return func() {}, nil
+}
+
+func (t *TestBackend) Size() (int, int) {
+	return t.Width, t.Height
+}
+
+func (t *TestBackend) Draw(frame []string) error {
+	t.Frames = append(t.Frames, strings.Join(frame, "\n"))
+	return nil
+}
+
+// The input comes from Press and the other methods, so Run stops right away
+func (t *TestBackend) ReadKey() (string, error) {
+	return "", io.EOF
+}
+
+func (t *TestBackend) SetCursor(visible bool, x, y int, style string) {}
+
+func (t *TestBackend) SetClipboard(text string) error {
+	t.Clipboard = text
+	return nil
+}
+
+// Render a frame and return it
+func (t *TestBackend) Render() string {
+	t.Screen.Render()
+	return t.Frame()
+}
+
+// Get the last frame, rendering one if there is none yet
+func (t *TestBackend) Frame() string {
+	if len(t.Frames) == 0 {
+		return t.Render()
+	}
+	return t.Frames[len(t.Frames)-1]
+}
+
+// Get the last frame without the escape sequences
+func (t *TestBackend) Text() string {
+	return StripAnsi(t.Frame())
+}
+
+// Send keys one after the other like the terminal would, returns false once the root component is done
+func (t *TestBackend) Press(keys ...string) bool {
+	for _, k := range keys {
+		if t.Done() {
+			return false
+		}
+		for _, key := range SplitKeys(k) {
+			_, t.done = t.Screen.handleKey(key)
+			t.Screen.drain()
+			t.Screen.Render()
+			if t.Done() {
+				break
+			}
+		}
+	}
+	return !t.Done()
+}
+
+// Type the text one character at a time
+func (t *TestBackend) Type(text string) bool {
+	keys := []string{}
+	for _, r := range text {
+		keys = append(keys, string(r))
+	}
+	return t.Press(keys...)
+}
+
+// Press and release the mouse button at x, y of the frame
+func (t *TestBackend) Click(x, y, button int) {
+	t.Press(fmt.Sprintf("\x1b[<%d;%d;%dM", button, x+1, y+1), fmt.Sprintf("\x1b[<%d;%d;%dm", button, x+1, y+1))
+}
+
+// Paste the text at once like a bracketed paste
+func (t *TestBackend) Paste(text string) {
+	if t.Done() {
+		return
+	}
+	_, t.done = t.Screen.handleKey(pasteStart + text + pasteEnd)
+	t.Screen.drain()
+	t.Screen.Render()
+}
+
+// Dispatch an event right away, the handlers run before it returns
+func (t *TestBackend) Emit(name string, data any) {
+	t.Screen.dispatch(Event{Name: name, Data: data})
+	t.Screen.drain()
+	t.Screen.Render()
+}
+
+// Run one tick of the screen, renders if a component asked for it
+func (t *TestBackend) Tick() {
+	if t.Screen.tick() {
+		t.Screen.Render()
+	}
+}
+
+// Resize the frame and render again
+func (t *TestBackend) Resize(width, height int) {
+	t.Width, t.Height = width, height
+	t.Screen.Render()
+}
+
+// Check whether a key made the root component finish or Exit was called on a handle
+func (t *TestBackend) Done() bool {
+	select {
+	case <-t.Screen.quit:
+		t.done = true
+	default:
+	}
+	return t.done
+}
+
+// Dispatch the events emitted and run the functions queued while handling the input, without blocking
+func (s *Screen) drain() {
+	for s.runQueued() {
+	}
+}
@@ -0,0 +1,100 @@
+package osui
+
+import (
+	"strings"
+	"testing"
+)
+
+// Draws a fixed text and remembers the keys and the pastes it got, q finishes it
+type testComponent struct {
+	Data   ComponentData
+	text   string
+	keys   []string
+	pasted string
+}
+
+func (c *testComponent) GetComponentData() *ComponentData {
+	return &c.Data
+}
+
+func (c *testComponent) Render() string {
+	return c.text
+}
+
+func (c *testComponent) Update(key string) bool {
+	c.keys = append(c.keys, key)
+	return key == "q"
+}
+
+func (c *testComponent) Paste(text string) {
+	c.pasted += text
+}
+
+// The frame without styles and without the spaces at the end of its lines
+func plain(frame string) string {
+	lines := strings.Split(StripAnsi(frame), "\n")
+	for i, line := range lines {
+		lines[i] = strings.TrimRight(line, " ")
+	}
+	return strings.Join(lines, "\n")
+}
+
+func TestRenderToString(t *testing.T) {
+	frame := RenderToString(&testComponent{text: "hello\nworld"}, 8, 3)
+	if got, want := plain(frame), "hello\nworld\n"; got != want {
+		t.Fatalf("got %q, want %q", got, want)
+	}
+	for _, line := range strings.Split(StripAnsi(frame), "\n") {
+		if VisibleWidth(line) != 8 {
+			t.Fatalf("line %q isn't 8 cells wide", line)
+		}
+	}
+}
+
+func TestClip(t *testing.T) {
+	frame := NewFrame(6, 2)
+	PushClip(Rect{X: 1, Y: 0, Width: 3, Height: 1})
+	DrawOutput(&frame, "abcdef\nabcdef", 0, 0)
+	PopClip()
+	if got, want := plain(strings.Join(frame, "\n")), " bcd\n"; got != want {
+		t.Fatalf("got %q, want %q", got, want)
+	}
+}
+
+func TestDrawKeepsStyles(t *testing.T) {
+	frame := []string{"ab\x1b[31mcdef\x1b[0m"}
+	DrawOutput(&frame, "\x1b[32mXY\x1b[0m", 2, 0)
+	if got, want := StripAnsi(frame[0]), "abXYef"; got != want {
+		t.Fatalf("got %q, want %q", got, want)
+	}
+	// The red of the covered cells comes back after the drawn ones
+	if !strings.Contains(frame[0], "XY\x1b[0m\x1b[31m") {
+		t.Fatalf("the style of the covered cells isn't applied again after the drawn ones: %q", frame[0])
+	}
+}
+
+func TestPressAndPaste(t *testing.T) {
+	c := &testComponent{text: "keys"}
+	b := NewTestBackend(c, 10, 2)
+	b.Render()
+	if !b.Press("a", "b\x1b[A") {
+		t.Fatal("the screen finished early")
+	}
+	if got := strings.Join(c.keys, ","); got != "a,b,\x1b[A" {
+		t.Fatalf("got the keys %q", got)
+	}
+	b.Paste("x\ny")
+	if c.pasted != "x\ny" {
+		t.Fatalf("got the paste %q", c.pasted)
+	}
+	// A paste isn't split into keys
+	if len(c.keys) != 3 {
+		t.Fatalf("the paste reached Update: %q", c.keys)
+	}
+	if b.Press("q") || !b.Done() {
+		t.Fatal("q didn't finish the screen")
+	}
+	if got := len(b.Frames); got != 6 {
+		t.Fatalf("got %d frames, want one per input and the first one", got)
+	}
+}
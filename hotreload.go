@@ -0,0 +1,73 @@
+package osui
+
+import (
+	"log"
+	"os"
+	"time"
+)
+
+// How often the watched files are checked for changes
+var WatchInterval = 250 * time.Millisecond
+
+// Reload the stylesheet at path whenever the file changes, for iterating on colors and spacing while the app runs.
+// A stylesheet that doesn't parse keeps the last good one applied and its error goes to onError, nil logs it.
+// Values that don't fit their property go there too, once per version of the file
+func (s *Screen) WatchStylesheet(path string, onError func(error)) (stop func(), err error) {
+	sheet, err := LoadStylesheet(path)
+	if err != nil {
+		return nil, err
+	}
+	s.mu.Lock()
+	s.sheetOnError = onError
+	s.mu.Unlock()
+	s.SetStylesheet(sheet)
+	return WatchFile(path, func() {
+		sheet, err := LoadStylesheet(path)
+		if err != nil {
+			reportWatchError(onError, err)
+			return
+		}
+		s.SetStylesheet(sheet)
+	}), nil
+}
+
+// Poll the file and call onChange on another goroutine whenever its modification time or size changes
+func WatchFile(path string, onChange func()) (stop func()) {
+	done := make(chan struct{})
+	last, _ := os.Stat(path)
+	go func() {
+		ticker := time.NewTicker(WatchInterval)
+		defer ticker.Stop()
+		for {
+			select {
+			case <-done:
+				return
+			case <-ticker.C:
+			}
+			info, err := os.Stat(path)
+			if err != nil {
+				// Editors often replace the file, it comes back on the next check
+				continue
+			}
+			if last == nil || !info.ModTime().Equal(last.ModTime()) || info.Size() != last.Size() {
+				last = info
+				onChange()
+			}
+		}
+	}()
+	closed := false
+	return func() {
+		if !closed {
+			closed = true
+			close(done)
+		}
+	}
+}
+
+func reportWatchError(onError func(error), err error) {
+	if onError != nil {
+		onError(err)
+	} else {
+		log.Printf("osui: %v", err)
+	}
+}
@@ -1,5 +1,10 @@
 package isKey
 
+import (
+	"unicode"
+	"unicode/utf8"
+)
+
 func Enter(s string) bool {
 	return s == "\r" || s == "\n"
 }
@@ -8,6 +13,10 @@ func Tab(s string) bool {
 	return s == "\t"
 }
 
+func Space(s string) bool {
+	return s == " "
+}
+
 func Backspace(s string) bool {
 	return s == "\x7f" || s == "\b"
 }
@@ -32,6 +41,19 @@ func Left(s string) bool {
 	return s == "\x1b[D"
 }
 
+// Text typed by the user, any printable characters including multi byte ones but no control keys or escape sequences
+func Printable(s string) bool {
+	if s == "" || !utf8.ValidString(s) {
+		return false
+	}
+	for _, r := range s {
+		if unicode.IsControl(r) {
+			return false
+		}
+	}
+	return true
+}
+
 func Char(s string, s1 string) bool {
 	return s == s1
 }
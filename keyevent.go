@@ -0,0 +1,133 @@
+package osui
+
+import (
+	"regexp"
+	"strconv"
+	"strings"
+)
+
+type KeyKind int
+
+const (
+	KeyPress KeyKind = iota
+	KeyRepeat
+	KeyRelease
+)
+
+// Sent as the data of the "key" event for every key, before the focused component gets it.
+// Key is in the legacy form the keymaps use, like "\x1b[A" or "\x03", whichever protocol the terminal spoke.
+// Repeats and releases are only told apart on terminals with the kitty keyboard protocol and Settings.KeyEvents on,
+// every key is a press everywhere else
+type KeyEvent struct {
+	Key  string
+	Kind KeyKind
+	// The sequence as the terminal sent it
+	Raw string
+}
+
+const (
+	// Disambiguate the keys and report the event types
+	keyEventsEnable  = "\x1b[>3u"
+	keyEventsDisable = "\x1b[<u"
+	keyEventsQuery   = "\x1b[?u"
+)
+
+var (
+	keyEventsReply = regexp.MustCompile(`^\x1b\[\?\d+u$`)
+	csiKey         = regexp.MustCompile(`^\x1b\[([0-9:;]*)([uABCDEFHPQRS~])$`)
+)
+
+// Parse a key into a key event, false for mouse reports, pastes and the reply to the protocol query
+func ParseKeyEvent(key string) (KeyEvent, bool) {
+	if _, isMouse := ParseMouse(key); isMouse {
+		return KeyEvent{}, false
+	}
+	if _, isPaste := ParsePaste(key); isPaste || keyEventsReply.MatchString(key) {
+		return KeyEvent{}, false
+	}
+	e := KeyEvent{Key: key, Kind: KeyPress, Raw: key}
+	m := csiKey.FindStringSubmatch(key)
+	if m == nil {
+		return e, true
+	}
+	params := strings.Split(m[1], ";")
+	code, alternate, _ := strings.Cut(params[0], ":")
+	mods := 1
+	if len(params) > 1 {
+		modText, kindText, _ := strings.Cut(params[1], ":")
+		if n, err := strconv.Atoi(modText); err == nil {
+			mods = n
+		}
+		switch kindText {
+		case "2":
+			e.Kind = KeyRepeat
+		case "3":
+			e.Kind = KeyRelease
+		}
+	}
+	// Caps lock and num lock don't change the key
+	mods = (mods-1)&^(64|128) + 1
+	if m[2] == "u" {
+		e.Key = legacyKey(code, alternate, mods)
+	} else if mods == 1 && strings.Contains("PQRS", m[2]) {
+		e.Key = "\x1bO" + m[2]
+	} else if mods == 1 {
+		e.Key = "\x1b[" + LogicValue(m[2] == "~", code, "") + m[2]
+	} else {
+		e.Key = "\x1b[" + LogicValue(code == "", "1", code) + ";" + strconv.Itoa(mods) + m[2]
+	}
+	return e, true
+}
+
+// Turn a kitty "CSI code;mods u" key back into what a terminal without the protocol sends
+func legacyKey(code, alternate string, mods int) string {
+	n, err := strconv.Atoi(code)
+	if err != nil {
+		return "\x1b[" + code + "u"
+	}
+	canonical := "\x1b[" + code + LogicValue(mods == 1, "", ";"+strconv.Itoa(mods)) + "u"
+	shift, alt, ctrl := (mods-1)&1 != 0, (mods-1)&2 != 0, (mods-1)&4 != 0
+	if (mods-1)&^7 != 0 {
+		return canonical
+	}
+	switch n {
+	case 27:
+		return LogicValue(alt, "\x1b\x1b", "\x1b")
+	case 13:
+		return LogicValue(alt, "\x1b\r", "\r")
+	case 9:
+		return LogicValue(shift, "\x1b[Z", "\t")
+	case 127:
+		return LogicValue(alt, "\x1b\x7f", "\x7f")
+	}
+	if n < 32 || n > 126 && n < 160 || n >= 57344 && n <= 63743 {
+		return canonical
+	}
+	r := rune(n)
+	if shift {
+		if a, err := strconv.Atoi(alternate); err == nil && a > 0 {
+			r = rune(a)
+		} else if r >= 'a' && r <= 'z' {
+			r -= 'a' - 'A'
+		}
+	}
+	key := string(r)
+	if ctrl {
+		switch {
+		case r >= 'a' && r <= 'z' || r >= 'A' && r <= 'Z':
+			key = string(r & 0x1f)
+		case r == ' ' || r == '@':
+			key = "\x00"
+		default:
+			return canonical
+		}
+	}
+	return LogicValue(alt, "\x1b", "") + key
+}
+
+// Whether the terminal answered the query for the kitty keyboard protocol, which needs Settings.KeyEvents
+func (s *Screen) KeyEventsSupported() bool {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	return s.keyEvents
+}
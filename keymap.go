@@ -0,0 +1,162 @@
+package osui
+
+import (
+	"sort"
+	"strings"
+)
+
+type Binding struct {
+	Keys        []string
+	Description string
+}
+
+// Actions of a component mapped to the keys that trigger them
+type Keymap map[string]Binding
+
+func (k Keymap) Is(action, key string) bool {
+	for _, v := range k[action].Keys {
+		if v == key {
+			return true
+		}
+	}
+	return false
+}
+
+// Get the action the key is bound to, the first one by name if there are several
+func (k Keymap) Action(key string) (string, bool) {
+	actions := make([]string, 0, len(k))
+	for action := range k {
+		actions = append(actions, action)
+	}
+	sort.Strings(actions)
+	for _, action := range actions {
+		if k.Is(action, key) {
+			return action, true
+		}
+	}
+	return "", false
+}
+
+// Copy the keymap, the bindings of o replace the ones with the same action
+func (k Keymap) Merge(o Keymap) Keymap {
+	res := Keymap{}
+	for action, b := range k {
+		res[action] = b
+	}
+	for action, b := range o {
+		res[action] = b
+	}
+	return res
+}
+
+// Bindings handled by the screen before the components get the key
+var ScreenKeymap = Keymap{
+	"resume": {Keys: []string{"\x1b[24~"}, Description: "Resume interaction"},
+	"undo":   {Keys: []string{"\x1a"}, Description: "Undo"},
+	"redo":   {Keys: []string{"\x1b[122;6u", "\x19"}, Description: "Redo"},
+	// Ctrl+Z is undo, so suspending has no key unless it is bound
+	"suspend": {Description: "Suspend to the shell"},
+	"select":  {Keys: []string{"\x1b[18~"}, Description: "Select text to copy"},
+}
+
+// Components that get every key while CapturesKeys is true, even the ones bound by the screen like undo
+type KeyCapturer interface {
+	CapturesKeys() bool
+}
+
+func (s *Screen) capturing() bool {
+	c, ok := s.Focused().(KeyCapturer)
+	return ok && c.CapturesKeys()
+}
+
+// Whether the active component inside c, or c itself, captures the keys. Containers with their own bindings
+// pass every key on while it does
+func Captures(c Component) bool {
+	path := ActivePath(c)
+	k, ok := path[len(path)-1].(KeyCapturer)
+	return ok && k.CapturesKeys()
+}
+
+// Whether c or one of the active components inside it binds the key in its keymap. Containers check it before
+// taking a key their content may need, like the arrows of a text input
+func Binds(c Component, key string) bool {
+	for _, c := range ActivePath(c) {
+		if o, ok := c.(KeymapOwner); ok {
+			if _, bound := o.GetKeymap().Action(key); bound {
+				return true
+			}
+		}
+	}
+	return false
+}
+
+// Components that expose their keymap, used to list the bindings of the focused components
+type KeymapOwner interface {
+	GetKeymap() Keymap
+}
+
+// Get the keymaps along the focus path, from the root to the focused component
+func (s *Screen) ActiveKeymaps() []Keymap {
+	res := []Keymap{}
+	for _, c := range s.FocusPath() {
+		if o, ok := c.(KeymapOwner); ok {
+			res = append(res, o.GetKeymap())
+		}
+	}
+	return res
+}
+
+var keyNames = map[string]string{
+	"\r":       "Enter",
+	"\n":       "Enter",
+	"\t":       "Tab",
+	" ":        "Space",
+	"\x7f":     "Backspace",
+	"\b":       "Backspace",
+	"\x1b":     "Esc",
+	"\x1b[A":   "↑",
+	"\x1b[B":   "↓",
+	"\x1b[C":   "→",
+	"\x1b[D":   "←",
+	"\x1b[Z":   "Shift+Tab",
+	"\x1b[H":   "Home",
+	"\x1b[F":   "End",
+	"\x1b[1~":  "Home",
+	"\x1b[4~":  "End",
+	"\x1b[2~":  "Insert",
+	"\x1b[3~":  "Delete",
+	"\x1b[5~":  "PgUp",
+	"\x1b[6~":  "PgDn",
+	"\x1bOP":   "F1",
+	"\x1bOQ":   "F2",
+	"\x1bOR":   "F3",
+	"\x1bOS":   "F4",
+	"\x1b[15~": "F5",
+	"\x1b[17~": "F6",
+	"\x1b[18~": "F7",
+	"\x1b[19~": "F8",
+	"\x1b[20~": "F9",
+	"\x1b[21~": "F10",
+	"\x1b[23~": "F11",
+	"\x1b[24~": "F12",
+}
+
+var keyModifiers = map[byte]string{'2': "Shift+", '3': "Alt+", '4': "Alt+Shift+", '5': "Ctrl+", '6': "Ctrl+Shift+"}
+
+// Get a readable name of a key like "Ctrl+X" or "Alt+←"
+func KeyName(key string) string {
+	if name, ok := keyNames[key]; ok {
+		return name
+	}
+	switch {
+	case len(key) == 1 && key[0] >= 1 && key[0] <= 26:
+		return "Ctrl+" + string(rune('A'+key[0]-1))
+	case len(key) == 6 && strings.HasPrefix(key, "\x1b[1;"):
+		if mod, ok := keyModifiers[key[4]]; ok {
+			return mod + KeyName("\x1b["+key[5:])
+		}
+	case len(key) == 2 && key[0] == 0x1b:
+		return "Alt+" + strings.ToUpper(key[1:])
+	}
+	return key
+}
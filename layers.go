@@ -0,0 +1,144 @@
+package osui
+
+import (
+	"sort"
+
+	"github.com/orus-dev/osui/colors"
+)
+
+// Layers are drawn from the lowest to the highest, custom layers can sit between the named ones
+type Layer int
+
+const (
+	LayerBase    Layer = 0
+	LayerPopup   Layer = 100
+	LayerOverlay Layer = 200
+	LayerDebug   Layer = 300
+)
+
+func (l Layer) String() string {
+	switch l {
+	case LayerBase:
+		return "base"
+	case LayerPopup:
+		return "popup"
+	case LayerOverlay:
+		return "overlay"
+	case LayerDebug:
+		return "debug"
+	}
+	return "layer"
+}
+
+// Draw the component on top of the layers below, components of the same layer are drawn in the order they were added
+func (s *Screen) AddToLayer(l Layer, c Component) {
+	s.layers[l] = append(s.layers[l], c)
+	s.RequestRender()
+}
+
+// Remove the component from the layer, returns false if it isn't on it
+func (s *Screen) RemoveFromLayer(l Layer, c Component) bool {
+	for i, v := range s.layers[l] {
+		if v == c {
+			s.layers[l] = append(s.layers[l][:i], s.layers[l][i+1:]...)
+			s.RequestRender()
+			return true
+		}
+	}
+	return false
+}
+
+func (s *Screen) LayerComponents(l Layer) []Component {
+	return s.layers[l]
+}
+
+// Show or hide a whole layer, hiding the base layer also hides the screen component
+func (s *Screen) SetLayerVisible(l Layer, visible bool) {
+	s.hiddenLayers[l] = !visible
+	s.RequestRender()
+}
+
+func (s *Screen) LayerVisible(l Layer) bool {
+	return !s.hiddenLayers[l]
+}
+
+func (s *Screen) ToggleLayer(l Layer) {
+	s.SetLayerVisible(l, !s.LayerVisible(l))
+}
+
+// The layers that have components, from the lowest to the highest
+func (s *Screen) layerOrder() []Layer {
+	order := []Layer{}
+	for l, components := range s.layers {
+		if len(components) > 0 {
+			order = append(order, l)
+		}
+	}
+	sort.Slice(order, func(i, j int) bool { return order[i] < order[j] })
+	return order
+}
+
+func (s *Screen) renderLayers(frame *[]string, width, height int) {
+	for _, l := range s.layerOrder() {
+		if s.hiddenLayers[l] {
+			continue
+		}
+		for _, c := range s.layers[l] {
+			data := c.GetComponentData()
+			data.Screen = s
+			data.DefaultColor = colors.Reset
+			if s.isModal(c) {
+				s.renderModal(c, frame, width, height)
+				drawFloating(c, frame)
+				continue
+			}
+			if data.Width == 0 {
+				data.Width = width
+			}
+			if data.Height == 0 {
+				data.Height = height
+			}
+			RenderOnFrame(c, frame)
+			drawFloating(c, frame)
+		}
+	}
+}
+
+// Get the components under the position on the highest visible layer that has one there
+func (s *Screen) layerComponentsAt(x, y int) []Component {
+	order := s.layerOrder()
+	for i := len(order) - 1; i >= 0; i-- {
+		if s.hiddenLayers[order[i]] {
+			continue
+		}
+		components := s.layers[order[i]]
+		for j := len(components) - 1; j >= 0; j-- {
+			if path := floatingAt(components[j], x, y); path != nil {
+				return path
+			}
+			if path := componentsAt(components[j], x, y, 0, 0); path != nil {
+				return path
+			}
+			// Nothing behind a modal can be clicked
+			if s.isModal(components[j]) {
+				return nil
+			}
+		}
+	}
+	if s.hiddenLayers[LayerBase] {
+		return nil
+	}
+	if path := floatingAt(s.component, x, y); path != nil {
+		return path
+	}
+	return componentsAt(s.component, x, y, 0, 0)
+}
+
+// The root component followed by the components of every layer, from the lowest layer up
+func (s *Screen) roots() []Component {
+	roots := []Component{s.component}
+	for _, l := range s.layerOrder() {
+		roots = append(roots, s.layers[l]...)
+	}
+	return roots
+}
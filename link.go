@@ -0,0 +1,16 @@
+package osui
+
+// Make the text a hyperlink that terminals open on click, with OSC 8. Without Settings.Hyperlinks the url is
+// shown after the text instead
+func Hyperlink(url, text string) string {
+	if url == "" {
+		return text
+	}
+	if !Settings.Hyperlinks {
+		if StripAnsi(text) == url {
+			return text
+		}
+		return text + " (" + url + ")"
+	}
+	return "\x1b]8;;" + url + "\x1b\\" + text + "\x1b]8;;\x1b\\"
+}
@@ -0,0 +1,37 @@
+package osui
+
+// Components with ComponentData.ListenUnfocused that only want some of the keys, the others aren't passed to Update
+type KeyFilter interface {
+	ListensTo(key string) bool
+}
+
+// Components with ComponentData.ListenUnfocused that get the events of the screen
+type EventListener interface {
+	OnEvent(e Event)
+}
+
+// Get the components that listen while they aren't on the focus path, like clocks or hint bars
+func (s *Screen) unfocusedListeners() []Component {
+	focused := s.FocusPath()
+	res := []Component{}
+	for _, root := range s.roots() {
+		Walk(root, func(c Component) bool {
+			if c.GetComponentData().ListenUnfocused && !containsComponent(focused, c) {
+				res = append(res, c)
+			}
+			return true
+		})
+	}
+	return res
+}
+
+// Pass a key to the unfocused listeners that want it, the result of their Update is ignored
+func (s *Screen) deliverUnfocused(key string) {
+	for _, c := range s.unfocusedListeners() {
+		if f, ok := c.(KeyFilter); ok && !f.ListensTo(key) {
+			continue
+		}
+		c.Update(key)
+		c.GetComponentData().invalidateCache()
+	}
+}
@@ -0,0 +1,57 @@
+package osui
+
+import "strings"
+
+// Show the component in the middle of the screen over everything else and give it every key until its Update
+// returns true, then it is closed. Modals opened while one is shown stack on top of it. Call it from the ui loop
+func (s *Screen) OpenModal(c Component) {
+	s.modals = append(s.modals, c)
+	s.AddToLayer(LayerPopup, c)
+}
+
+// Close the modal without waiting for it to be done, returns false if it isn't open
+func (s *Screen) CloseModal(c Component) bool {
+	for i, m := range s.modals {
+		if m == c {
+			s.modals = append(s.modals[:i], s.modals[i+1:]...)
+			s.RemoveFromLayer(LayerPopup, c)
+			return true
+		}
+	}
+	return false
+}
+
+// The modal on top that gets the keys, nil if none is open
+func (s *Screen) Modal() Component {
+	if len(s.modals) == 0 {
+		return nil
+	}
+	return s.modals[len(s.modals)-1]
+}
+
+func (s *Screen) isModal(c Component) bool {
+	for _, m := range s.modals {
+		if m == c {
+			return true
+		}
+	}
+	return false
+}
+
+// Render a modal in the middle of the frame, it takes the size of its output for clicks
+func (s *Screen) renderModal(c Component, frame *[]string, width, height int) {
+	data := c.GetComponentData()
+	data.IsActive = c == s.Modal()
+	output := RenderComponent(c)
+	w, lines := 0, strings.Split(output, "\n")
+	for _, line := range lines {
+		w = max(w, VisibleWidth(line))
+	}
+	if data.Width == 0 {
+		data.Width = w
+	}
+	data.Height = len(lines)
+	data.X, data.Y = max((width-w)/2, 0), max((height-len(lines))/2, 0)
+	drawElevation(c, output, data.X, data.Y, frame)
+	RenderOutputOnFrame(c, output, frame)
+}
@@ -0,0 +1,215 @@
+package osui
+
+import (
+	"strconv"
+	"strings"
+	"time"
+)
+
+const (
+	MouseLeft       = 0
+	MouseMiddle     = 1
+	MouseRight      = 2
+	MouseWheelUp    = 64
+	MouseWheelDown  = 65
+	MouseWheelLeft  = 66
+	MouseWheelRight = 67
+)
+
+type MouseEvent struct {
+	X       int
+	Y       int
+	Button  int
+	Motion  bool
+	Release bool
+}
+
+// Components that can be scrolled by the mouse wheel
+type Scrollable interface {
+	Scroll(dx, dy int) bool
+}
+
+// Components notified when the pointer enters or leaves them
+type Hoverable interface {
+	OnMouseEnter()
+	OnMouseLeave()
+}
+
+// Components that handle mouse presses, the position is relative to the component.
+// Returning true keeps the press from reaching the components below
+type Clickable interface {
+	OnClick(m MouseEvent) bool
+}
+
+// Parse a SGR mouse report like "\x1b[<64;10;5M"
+func ParseMouse(key string) (MouseEvent, bool) {
+	if !strings.HasPrefix(key, "\x1b[<") || len(key) < 9 {
+		return MouseEvent{}, false
+	}
+	final := key[len(key)-1]
+	if final != 'M' && final != 'm' {
+		return MouseEvent{}, false
+	}
+	parts := strings.Split(key[3:len(key)-1], ";")
+	if len(parts) != 3 {
+		return MouseEvent{}, false
+	}
+	b, err := strconv.Atoi(parts[0])
+	if err != nil {
+		return MouseEvent{}, false
+	}
+	x, err := strconv.Atoi(parts[1])
+	if err != nil {
+		return MouseEvent{}, false
+	}
+	y, err := strconv.Atoi(parts[2])
+	if err != nil {
+		return MouseEvent{}, false
+	}
+	return MouseEvent{
+		X:       x - 1,
+		Y:       y - 1,
+		Button:  b &^ (4 | 8 | 16 | 32),
+		Motion:  b&32 != 0,
+		Release: final == 'm',
+	}, true
+}
+
+// Get the rendered components under the given screen position, from the root down
+func (s *Screen) ComponentsAt(x, y int) []Component {
+	return s.layerComponentsAt(x, y)
+}
+
+func componentsAt(c Component, x, y, ox, oy int) []Component {
+	data := c.GetComponentData()
+	if !data.drawn() {
+		return nil
+	}
+	ax, ay := ox+data.X, oy+data.Y
+	if x < ax || y < ay || x >= ax+data.Width || y >= ay+data.Height {
+		return nil
+	}
+	if container, ok := c.(Container); ok {
+		cx, cy := container.ChildOffset()
+		children := container.GetChildren()
+		for i := len(children) - 1; i >= 0; i-- {
+			if path := componentsAt(children[i], x, y, ax+cx, ay+cy); path != nil {
+				return append([]Component{c}, path...)
+			}
+		}
+	}
+	return []Component{c}
+}
+
+// Give the press to the deepest clickable component under it first
+func (s *Screen) click(path []Component, m MouseEvent) bool {
+	for i := len(path) - 1; i >= 0; i-- {
+		c, ok := path[i].(Clickable)
+		if !ok || path[i].GetComponentData().Disabled {
+			continue
+		}
+		local := m
+		if r, ok := s.Bounds(path[i]); ok {
+			local.X, local.Y = m.X-r.X, m.Y-r.Y
+		}
+		if c.OnClick(local) {
+			return true
+		}
+	}
+	return false
+}
+
+func (s *Screen) updateHover(path []Component) bool {
+	changed := false
+	for _, c := range s.hovered {
+		if !containsComponent(path, c) {
+			c.GetComponentData().Hovered = false
+			if h, ok := c.(Hoverable); ok {
+				h.OnMouseLeave()
+			}
+			changed = true
+		}
+	}
+	for _, c := range path {
+		if !containsComponent(s.hovered, c) {
+			c.GetComponentData().Hovered = true
+			if h, ok := c.(Hoverable); ok {
+				h.OnMouseEnter()
+			}
+			changed = true
+		}
+	}
+	s.hovered = path
+	return changed
+}
+
+// Mark the components under a mouse press as pressed until it is released
+func (s *Screen) press(path []Component, pressed bool) {
+	for _, c := range s.pressed {
+		c.GetComponentData().Pressed = false
+	}
+	s.pressed = nil
+	if pressed {
+		for _, c := range path {
+			if !c.GetComponentData().Disabled {
+				c.GetComponentData().Pressed = true
+				s.pressed = append(s.pressed, c)
+			}
+		}
+	}
+}
+
+func containsComponent(a []Component, c Component) bool {
+	for _, v := range a {
+		if v == c {
+			return true
+		}
+	}
+	return false
+}
+
+// Handle a mouse event, returns whether the screen needs a render
+func (s *Screen) handleMouse(m MouseEvent) bool {
+	path := s.ComponentsAt(m.X, m.Y)
+	changed := s.updateHover(path)
+	if !m.Motion || m.Button >= MouseWheelUp {
+		for _, c := range path {
+			c.GetComponentData().invalidateCache()
+		}
+	}
+	dx, dy := 0, 0
+	switch m.Button {
+	case MouseWheelUp:
+		dy = -1
+	case MouseWheelDown:
+		dy = 1
+	case MouseWheelLeft:
+		dx = -1
+	case MouseWheelRight:
+		dx = 1
+	default:
+		if !m.Motion {
+			s.press(path, !m.Release)
+		}
+		if !m.Motion && !m.Release && s.click(path, m) {
+			return true
+		}
+		return changed || !m.Motion
+	}
+	step := max(Settings.ScrollStep, 1)
+	if Settings.ScrollMomentum {
+		if time.Since(s.lastScroll) < 80*time.Millisecond {
+			s.momentum = min(s.momentum+1, 4)
+		} else {
+			s.momentum = 1
+		}
+		s.lastScroll = time.Now()
+		step *= s.momentum
+	}
+	for i := len(path) - 1; i >= 0; i-- {
+		if sc, ok := path[i].(Scrollable); ok && sc.Scroll(dx*step, dy*step) {
+			return true
+		}
+	}
+	return changed
+}
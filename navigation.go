@@ -0,0 +1,51 @@
+package osui
+
+// Components that show different content for different paths
+type Navigator interface {
+	Navigate(path string) bool
+	NavigateWith(path string, arg any) bool
+}
+
+// Navigators that remember the visited paths
+type History interface {
+	Back() bool
+	Forward() bool
+}
+
+// Navigate the first router in the tree to the path
+func (s *Screen) Navigate(path string) bool {
+	return s.NavigateWith(path, nil)
+}
+
+// Navigate the first router in the tree to the path, passing arg to the screen being built
+func (s *Screen) NavigateWith(path string, arg any) bool {
+	ok := false
+	Walk(s.component, func(c Component) bool {
+		if n, isNavigator := c.(Navigator); isNavigator {
+			ok = n.NavigateWith(path, arg)
+			return false
+		}
+		return true
+	})
+	return ok
+}
+
+func (s *Screen) Back() bool {
+	return s.walkHistory(History.Back)
+}
+
+func (s *Screen) Forward() bool {
+	return s.walkHistory(History.Forward)
+}
+
+func (s *Screen) walkHistory(f func(History) bool) bool {
+	ok := false
+	Walk(s.component, func(c Component) bool {
+		if h, isHistory := c.(History); isHistory {
+			ok = f(h)
+			return false
+		}
+		return true
+	})
+	return ok
+}
@@ -1,22 +1,53 @@
 package osui
 
 import (
-	"fmt"
-	"os"
 	"strings"
+	"sync"
+	"time"
 
 	"github.com/orus-dev/osui/colors"
-	"golang.org/x/term"
 )
 
 type ComponentData struct {
-	X            int
-	Y            int
-	Width        int
-	Height       int
-	DefaultColor string
-	IsActive     bool
-	Screen       *Screen
+	Id              string
+	Key             string
+	Classes         []string
+	State           string
+	States          map[string]any
+	X               int
+	Y               int
+	Width           int
+	Height          int
+	DefaultColor    string
+	IsActive        bool
+	Hovered         bool
+	Pressed         bool
+	Disabled        bool
+	ListenUnfocused bool
+	Floating        bool
+	Shadow          bool // Floating components only
+	DimBehind       bool // Floating components only, dims everything drawn before them
+	ZIndex          int
+	Transition      time.Duration
+	Direction       Direction
+	Screen          *Screen
+	frame           uint64
+	renderTime      time.Duration
+	outputSize      int
+	drawnWidth      int
+	drawnHeight     int
+	floatOutput     string
+	transition      *styleTransition
+	// What the fields set by a stylesheet held before it, they go back to it once no rule sets them
+	sheetBase map[sheetField]any
+	// The output of the last render of versioned components and what it depends on
+	cache    string
+	cacheKey renderKey
+	cached   bool
+	invalid  uint32
+	// The frame of the last render, or of the last one its output was reused in
+	rendered uint64
+	reused   bool
 }
 
 type Component interface {
@@ -25,18 +56,136 @@ type Component interface {
 	Update(string) bool
 }
 
+// Reusable user components that build their content from their own props and state
+type View interface {
+	View() Component
+}
+
+// Components called on every tick of the screen while they are rendered or listen unfocused, returning true renders again
+type Ticker interface {
+	Tick() bool
+}
+
+// Components that hold other components
+type Container interface {
+	GetChildren() []Component
+	ChildOffset() (int, int)
+}
+
 type Screen struct {
 	component Component
+	handlers  map[string][]func(Event)
+	renders   chan struct{}
+	quit      chan struct{}
+	quitOnce  sync.Once
+	// Functions for the ui loop queued from any goroutine, wake tells the loop about them
+	queued []func()
+	wake   chan struct{}
+	mu     sync.Mutex
+	Keymap Keymap
+	frozen bool
+
+	lastScroll time.Time
+	momentum   int
+	hovered    []Component
+	pressed    []Component
+
+	layers       map[Layer][]Component
+	hiddenLayers map[Layer]bool
+	cursor       *cursorRequest
+
+	undo []UndoableAction
+	redo []UndoableAction
+
+	mode            RenderMode
+	continuousUntil time.Time
+
+	batching      int
+	pendingRender bool
+
+	frameTime  time.Duration
+	frameSize  int
+	watch      *watchdog
+	afterFrame []func()
+	backend    Backend
+	// Undoes the start of the backend while the screen runs
+	stopBackend func()
+	// The last frame drawn, partial renders draw over it
+	lastFrame []string
+	// The terminal speaks the kitty keyboard protocol
+	keyEvents  bool
+	animations []*Animation
+	stylesheet *Stylesheet
+	modals     []Component
+	selection  *selection
+	// The number of the frame drawn last, components remember the one they were drawn in
+	frameCount uint64
+	// The onError of WatchStylesheet and the errors of applying the stylesheet reported so far
+	sheetOnError func(error)
+	sheetErrors  map[string]bool
 }
 
+var (
+	running   = map[*Screen]struct{}{}
+	runningMu sync.Mutex
+	renderMu  sync.Mutex
+)
+
 func NewScreen(c Component) *Screen {
 	HideCursor()
-	s := &Screen{component: c}
+	return newScreen(c)
+}
+
+func newScreen(c Component) *Screen {
+	s := &Screen{
+		backend:   TerminalBackend{},
+		component: c,
+		handlers:  map[string][]func(Event){},
+		renders:   make(chan struct{}, 1),
+		wake:      make(chan struct{}, 1),
+		quit:      make(chan struct{}),
+		Keymap:    ScreenKeymap,
+
+		layers:       map[Layer][]Component{},
+		hiddenLayers: map[Layer]bool{},
+	}
 	return s
 }
 
+func (s *Screen) Component() Component {
+	return s.component
+}
+
+// Replace the root component, from the ui loop like a key handler or through Handle.Do
+func (s *Screen) SetComponent(c Component) {
+	s.component = c
+	c.GetComponentData().Screen = s
+	s.hovered, s.pressed = nil, nil
+	s.RequestRender()
+}
+
 func (s *Screen) Render() {
-	width, height := GetTerminalSize()
+	start := time.Now()
+	width, height := s.backend.Size()
+	s.stepAnimations()
+	// The clip stack and the styles are shared, so screens served side by side render one at a time
+	renderMu.Lock()
+	frame := s.renderFrame(width, height)
+	renderMu.Unlock()
+	s.lastFrame = frame
+	s.backend.Draw(frame)
+	s.placeCursor()
+	s.frameTime, s.frameSize = time.Since(start), 0
+	for _, line := range frame {
+		s.frameSize += len(line)
+	}
+	s.runAfterFrame()
+}
+
+// Draw the component tree and the layers on a new frame
+func (s *Screen) renderFrame(width, height int) []string {
+	s.frameCount++
+	releaseRows()
 	frame := NewFrame(width, height)
 	data := s.component.GetComponentData()
 	if data.Height == 0 {
@@ -46,27 +195,256 @@ func (s *Screen) Render() {
 		data.Width = width
 	}
 	data.Screen = s
-	data.IsActive = true
+	data.IsActive = !s.frozen
 	data.DefaultColor = colors.Reset
-	RenderOnFrame(s.component, &frame)
-	Clear()
-	fmt.Print(strings.Join(frame, ""))
+	s.cursor = nil
+	if sheet := s.Stylesheet(); sheet != nil {
+		for _, root := range s.roots() {
+			if err := sheet.Apply(root); err != nil {
+				s.reportStylesheetError(err)
+			}
+		}
+	}
+	if s.LayerVisible(LayerBase) {
+		RenderOnFrame(s.component, &frame)
+		drawFloating(s.component, &frame)
+	}
+	s.renderLayers(&frame, width, height)
+	if s.selection != nil {
+		s.drawSelection(&frame)
+	}
+	return frame
 }
 
 func (s *Screen) Run() {
-	oldState, err := term.MakeRaw(int(os.Stdin.Fd()))
+	stop, err := s.backend.Start()
 	if err != nil {
 		panic(err)
 	}
-	defer term.Restore(int(os.Stdin.Fd()), oldState)
+	// Suspend replaces it when it starts the backend again
+	s.stopBackend = stop
+	defer func() {
+		s.stopBackend()
+		s.stopBackend = nil
+	}()
+	tstp, stopSignals := suspendSignals()
+	defer stopSignals()
+	if Settings.Watchdog > 0 {
+		s.watch = &watchdog{}
+		done := make(chan struct{})
+		defer close(done)
+		go s.runWatchdog(Settings.Watchdog, done)
+	}
 	data := s.component.GetComponentData()
 	data.Screen = s
+	runningMu.Lock()
+	running[s] = struct{}{}
+	runningMu.Unlock()
+	defer func() {
+		runningMu.Lock()
+		delete(running, s)
+		runningMu.Unlock()
+	}()
+	keys := make(chan string)
+	stop := make(chan struct{})
+	defer close(stop)
+	go s.readKeys(keys, stop)
+	ticker := time.NewTicker(max(Settings.TickRate, 10*time.Millisecond))
+	defer ticker.Stop()
+	clock := &frameClock{}
+	defer clock.stop()
+	// Something changed since the last frame, throttle is set while waiting for the frame rate cap
+	pending := true
+	var last time.Time
+	var throttle <-chan time.Time
+	for {
+		if pending && throttle == nil && !s.inBatch() {
+			if wait := frameInterval() - time.Since(last); wait > 0 {
+				throttle = time.After(wait)
+			} else {
+				s.busy("render", s.component)
+				s.Render()
+				s.idle()
+				last, pending = time.Now(), false
+			}
+		}
+		dirty := true
+		select {
+		case <-throttle:
+			throttle, dirty = nil, false
+		case <-clock.next(s.continuous()):
+		case k, ok := <-keys:
+			if !ok {
+				return
+			}
+			s.busy("key", s.Focused())
+			var done bool
+			if dirty, done = s.handleKey(k); done {
+				return
+			}
+		case <-s.wake:
+			s.busy("queue", nil)
+			s.runQueued()
+		case <-s.renders:
+		case <-s.quit:
+			return
+		case <-tstp:
+			s.SuspendProcess()
+		case <-ticker.C:
+			s.busy("tick", nil)
+			dirty = s.tick()
+		}
+		s.idle()
+		pending = pending || dirty
+	}
+}
+
+// Read the input into keys until reading fails or stop is closed. Backends that can give up reading stop right
+// away and get back the keys read but not handled, so the next screen started gets them
+func (s *Screen) readKeys(keys chan<- string, stop <-chan struct{}) {
+	reader, stoppable := s.backend.(stoppableReader)
+	var paste strings.Builder
+	pasting := false
 	for {
-		s.Render()
-		k, _ := ReadKey()
-		if s.component.Update(k) {
-			ShowCursor()
+		var k string
+		var err error
+		if stoppable {
+			k, err = reader.readUntil(stop)
+		} else {
+			k, err = s.backend.ReadKey()
+		}
+		if err != nil {
+			close(keys)
 			return
 		}
+		split := SplitKeys(k)
+		for i, key := range split {
+			// A bracketed paste is sent as a single key so its characters don't trigger bindings
+			switch {
+			case key == pasteStart:
+				pasting = true
+				paste.Reset()
+				continue
+			case key == pasteEnd && pasting:
+				pasting = false
+				key = pasteStart + paste.String() + pasteEnd
+			case pasting:
+				paste.WriteString(key)
+				continue
+			}
+			select {
+			case keys <- key:
+			case <-stop:
+				if stoppable {
+					reader.giveBack(append([]string{key}, split[i+1:]...))
+				}
+				return
+			}
+		}
+	}
+}
+
+// Handle a key like mouse reports and pastes, returns whether it needs a render and whether the root component is done
+func (s *Screen) handleKey(k string) (bool, bool) {
+	if keyEventsReply.MatchString(k) {
+		s.mu.Lock()
+		s.keyEvents = true
+		s.mu.Unlock()
+		return false, false
+	}
+	if e, isKey := ParseKeyEvent(k); isKey {
+		if !s.frozen {
+			s.dispatch(Event{Name: "key", Data: e})
+		}
+		// Releases only reach the handlers of the "key" event
+		if e.Kind == KeyRelease {
+			return !s.frozen, false
+		}
+		k = e.Key
+	}
+	// The components that get the key may change without changing their version
+	if _, isMouse := ParseMouse(k); !isMouse && !s.frozen {
+		s.invalidateFocus()
+	}
+	if s.frozen {
+		if !s.Keymap.Is("resume", k) {
+			return false, false
+		}
+		s.SetInteractive(true)
+	} else if m, isMouse := ParseMouse(k); isMouse {
+		return s.handleMouse(m), false
+	} else if text, isPaste := ParsePaste(k); isPaste {
+		s.Paste(text)
+	} else if s.selection != nil {
+		s.selectKey(k)
+	} else if s.Keymap.Is("select", k) && !s.capturing() {
+		s.StartSelection()
+	} else if m := s.Modal(); m != nil {
+		m.GetComponentData().IsActive = true
+		if m.Update(k) {
+			s.CloseModal(m)
+		}
+	} else if s.Keymap.Is("undo", k) && !s.capturing() {
+		s.Undo()
+	} else if s.Keymap.Is("redo", k) && !s.capturing() {
+		s.Redo()
+	} else if s.Keymap.Is("suspend", k) && !s.capturing() {
+		s.SuspendProcess()
+	} else if s.component.Update(k) {
+		return true, true
+	} else {
+		s.deliverUnfocused(k)
+	}
+	return true, false
+}
+
+// Tick every rendered component, returns whether one of them needs a render
+func (s *Screen) tick() bool {
+	render := tickTree(s.component)
+	for _, l := range s.layerOrder() {
+		for _, c := range s.layers[l] {
+			render = tickTree(c) || render
+		}
+	}
+	return render
+}
+
+func tickTree(c Component) bool {
+	data := c.GetComponentData()
+	render := false
+	if t, ok := c.(Ticker); ok && (data.drawn() || data.ListenUnfocused) {
+		render = t.Tick()
+		if render {
+			data.invalidateCache()
+		}
+	}
+	if container, ok := c.(Container); ok {
+		for _, child := range container.GetChildren() {
+			render = tickTree(child) || render
+		}
+	}
+	return render
+}
+
+// Ask the ui loop to render again, safe to call from any goroutine
+func (s *Screen) RequestRender() {
+	s.mu.Lock()
+	if s.batching > 0 {
+		s.pendingRender = true
+		s.mu.Unlock()
+		return
+	}
+	s.mu.Unlock()
+	select {
+	case s.renders <- struct{}{}:
+	default:
+	}
+}
+
+func requestRenderAll() {
+	runningMu.Lock()
+	defer runningMu.Unlock()
+	for s := range running {
+		s.RequestRender()
 	}
 }
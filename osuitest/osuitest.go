@@ -0,0 +1,166 @@
+// Package osuitest runs osui screens without a terminal for tests, with scripted input,
+// plain text comparison of the frames and snapshot files
+package osuitest
+
+import (
+	"flag"
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+	"testing"
+
+	"github.com/orus-dev/osui"
+)
+
+var update = flag.Bool("update", false, "write the snapshot files instead of comparing with them")
+
+const (
+	KeyUp        = "\x1b[A"
+	KeyDown      = "\x1b[B"
+	KeyRight     = "\x1b[C"
+	KeyLeft      = "\x1b[D"
+	KeyEnter     = "\r"
+	KeyTab       = "\t"
+	KeyShiftTab  = "\x1b[Z"
+	KeyEscape    = "\x1b"
+	KeyBackspace = "\x7f"
+	KeySpace     = " "
+)
+
+// A chain of inputs and expectations on the frames they render, the first failed expectation fails the test
+type Script struct {
+	t       testing.TB
+	Backend *osui.TestBackend
+}
+
+// Render the component on a width x height frame and start a script on it
+func New(t testing.TB, c osui.Component, width, height int) *Script {
+	t.Helper()
+	s := &Script{t: t, Backend: osui.NewTestBackend(c, width, height)}
+	s.Backend.Render()
+	return s
+}
+
+func (s *Script) Press(keys ...string) *Script {
+	s.Backend.Press(keys...)
+	return s
+}
+
+func (s *Script) Type(text string) *Script {
+	s.Backend.Type(text)
+	return s
+}
+
+func (s *Script) Paste(text string) *Script {
+	s.Backend.Paste(text)
+	return s
+}
+
+func (s *Script) Click(x, y int) *Script {
+	s.Backend.Click(x, y, osui.MouseLeft)
+	return s
+}
+
+func (s *Script) Emit(name string, data any) *Script {
+	s.Backend.Emit(name, data)
+	return s
+}
+
+func (s *Script) Tick() *Script {
+	s.Backend.Tick()
+	return s
+}
+
+// Get the last frame as plain text
+func (s *Script) Frame() string {
+	return PlainText(s.Backend.Frame())
+}
+
+// Compare the last frame as plain text, spaces at the end of the lines and empty lines at the end don't count
+func (s *Script) ExpectFrame(want string) *Script {
+	s.t.Helper()
+	if diff := Diff(PlainText(want), s.Frame()); diff != "" {
+		s.t.Fatalf("unexpected frame:\n%s", diff)
+	}
+	return s
+}
+
+func (s *Script) ExpectContains(text string) *Script {
+	s.t.Helper()
+	if !strings.Contains(s.Frame(), text) {
+		s.t.Fatalf("the frame doesn't contain %q:\n%s", text, s.Frame())
+	}
+	return s
+}
+
+// Check that the root component finished, like after the last field of a form
+func (s *Script) ExpectDone() *Script {
+	s.t.Helper()
+	if !s.Backend.Done() {
+		s.t.Fatalf("the screen is still running:\n%s", s.Frame())
+	}
+	return s
+}
+
+// Compare the last frame as plain text with testdata/<test name>/<name>.snap, running the tests with -update writes the file
+func (s *Script) MatchSnapshot(name string) *Script {
+	s.t.Helper()
+	path := filepath.Join("testdata", sanitize(s.t.Name()), sanitize(name)+".snap")
+	got := s.Frame()
+	if *update {
+		if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+			s.t.Fatal(err)
+		}
+		if err := os.WriteFile(path, []byte(got+"\n"), 0644); err != nil {
+			s.t.Fatal(err)
+		}
+		return s
+	}
+	want, err := os.ReadFile(path)
+	if err != nil {
+		s.t.Fatalf("reading the snapshot, run the tests with -update to create it: %v", err)
+	}
+	if diff := Diff(PlainText(string(want)), got); diff != "" {
+		s.t.Fatalf("the frame doesn't match %s:\n%s", path, diff)
+	}
+	return s
+}
+
+// Strip the escape sequences of the frame, the spaces at the end of its lines and the empty lines at its end
+func PlainText(frame string) string {
+	lines := strings.Split(osui.StripAnsi(frame), "\n")
+	for i, line := range lines {
+		lines[i] = strings.TrimRight(line, " ")
+	}
+	return strings.TrimRight(strings.Join(lines, "\n"), "\n")
+}
+
+// Describe the lines that differ, empty if the texts are the same
+func Diff(want, got string) string {
+	if want == got {
+		return ""
+	}
+	w, g := strings.Split(want, "\n"), strings.Split(got, "\n")
+	var res strings.Builder
+	for i := 0; i < max(len(w), len(g)); i++ {
+		var wl, gl string
+		if i < len(w) {
+			wl = w[i]
+		}
+		if i < len(g) {
+			gl = g[i]
+		}
+		if wl != gl {
+			fmt.Fprintf(&res, "line %d:\n  want %q\n  got  %q\n", i+1, wl, gl)
+		}
+	}
+	return res.String()
+}
+
+var unsafePath = regexp.MustCompile(`[^A-Za-z0-9_.-]+`)
+
+func sanitize(name string) string {
+	return unsafePath.ReplaceAllString(name, "_")
+}
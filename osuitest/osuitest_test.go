@@ -0,0 +1,105 @@
+package osuitest
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/orus-dev/osui/ui"
+)
+
+// Keeps the first failure of a script instead of ending the test, to check that expectations fail
+type recorder struct {
+	testing.TB
+	failure string
+}
+
+func (r *recorder) Helper() {}
+
+func (r *recorder) Name() string {
+	return "Recorded"
+}
+
+func (r *recorder) Fatal(args ...any) {
+	r.Fatalf("%s", fmt.Sprint(args...))
+}
+
+func (r *recorder) Fatalf(format string, args ...any) {
+	if r.failure == "" {
+		r.failure = fmt.Sprintf(format, args...)
+	}
+}
+
+func TestPlainText(t *testing.T) {
+	got := PlainText("\x1b[31mab  \x1b[0m\ncd\x1b[1m \x1b[0m\n  \n\n")
+	if got != "ab\ncd" {
+		t.Fatalf("got %q", got)
+	}
+}
+
+func TestDiff(t *testing.T) {
+	if diff := Diff("a\nb", "a\nb"); diff != "" {
+		t.Fatalf("equal texts differ: %s", diff)
+	}
+	diff := Diff("a\nb", "a\nc\nd")
+	if !strings.Contains(diff, "line 2") || !strings.Contains(diff, "line 3") || strings.Contains(diff, "line 1") {
+		t.Fatalf("got the diff:\n%s", diff)
+	}
+}
+
+func TestExpectations(t *testing.T) {
+	r := &recorder{TB: t}
+	s := New(r, ui.Text("hello"), 10, 2)
+	s.ExpectFrame("hello").ExpectContains("ell")
+	if r.failure != "" {
+		t.Fatalf("a matching frame failed: %s", r.failure)
+	}
+	s.ExpectFrame("bye")
+	if !strings.Contains(r.failure, `want "bye"`) {
+		t.Fatalf("a different frame didn't fail with a diff: %q", r.failure)
+	}
+}
+
+func TestMatchSnapshot(t *testing.T) {
+	defer func(v bool) { *update = v }(*update)
+	*update = false
+	wd, err := os.Getwd()
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := os.Chdir(t.TempDir()); err != nil {
+		t.Fatal(err)
+	}
+	defer os.Chdir(wd)
+
+	r := &recorder{TB: t}
+	s := New(r, ui.Text("hello"), 10, 2)
+	s.MatchSnapshot("greeting")
+	if !strings.Contains(r.failure, "-update") {
+		t.Fatalf("a missing snapshot didn't fail: %q", r.failure)
+	}
+
+	r.failure = ""
+	*update = true
+	s.MatchSnapshot("greeting")
+	*update = false
+	path := filepath.Join("testdata", "Recorded", "greeting.snap")
+	data, err := os.ReadFile(path)
+	if err != nil || string(data) != "hello\n" || r.failure != "" {
+		t.Fatalf("-update wrote %q, %v, %q", data, err, r.failure)
+	}
+
+	s.MatchSnapshot("greeting")
+	if r.failure != "" {
+		t.Fatalf("the written snapshot doesn't match: %s", r.failure)
+	}
+	if err := os.WriteFile(path, []byte("bye\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	s.MatchSnapshot("greeting")
+	if !strings.Contains(r.failure, "doesn't match") {
+		t.Fatalf("a changed snapshot didn't fail: %q", r.failure)
+	}
+}
@@ -0,0 +1,55 @@
+package osui
+
+import "strings"
+
+// Render only the component with the id and draw it over its region of the last frame, instead of rendering
+// the whole tree. Meant for one fast updating panel of a big dashboard, call it where Render could be called.
+// The component keeps the position it had in the last frame, the whole screen is rendered instead when there
+// is no last frame, the size changed, layers are shown or text is being selected. Returns false if there is no
+// such component
+func (s *Screen) RenderElement(id string) bool {
+	var c Component
+	for _, root := range s.roots() {
+		if c = FindById(root, id); c != nil {
+			break
+		}
+	}
+	if c == nil {
+		return false
+	}
+	r, drawn := s.Bounds(c)
+	width, height := s.backend.Size()
+	if !drawn || len(s.lastFrame) != height || len(s.lastFrame) == 0 || VisibleWidth(s.lastFrame[0]) != width || s.layered() || s.selection != nil {
+		s.Render()
+		return true
+	}
+	data := c.GetComponentData()
+	frame := append([]string{}, s.lastFrame...)
+	renderMu.Lock()
+	output := RenderComponent(c)
+	// Blank the old region first, the new output may be smaller. Only that region is drawn on
+	PushClip(r)
+	DrawOutput(&frame, strings.TrimSuffix(strings.Repeat(data.DefaultColor+strings.Repeat(" ", r.Width)+"\n", r.Height), "\n"), r.X, r.Y)
+	if data.Floating {
+		data.floatOutput = output
+	} else {
+		drawComponent(c, output, r.X, r.Y, &frame)
+	}
+	PopClip()
+	drawFloating(s.component, &frame)
+	renderMu.Unlock()
+	s.lastFrame = frame
+	s.backend.Draw(frame)
+	s.placeCursor()
+	return true
+}
+
+// Whether layers are shown over the tree or the tree is hidden
+func (s *Screen) layered() bool {
+	for _, l := range s.layerOrder() {
+		if s.LayerVisible(l) {
+			return true
+		}
+	}
+	return !s.LayerVisible(LayerBase)
+}
@@ -0,0 +1,33 @@
+package osui
+
+import "strings"
+
+const (
+	pasteStart = "\x1b[200~"
+	pasteEnd   = "\x1b[201~"
+)
+
+// Components that take pasted text as a whole instead of as single keys
+type Paster interface {
+	Paste(text string)
+}
+
+// Get the text of a bracketed paste key
+func ParsePaste(key string) (string, bool) {
+	if !strings.HasPrefix(key, pasteStart) || !strings.HasSuffix(key, pasteEnd) || len(key) < len(pasteStart)+len(pasteEnd) {
+		return "", false
+	}
+	return key[len(pasteStart) : len(key)-len(pasteEnd)], true
+}
+
+// Give the text to the open modal or the focused component and dispatch a "paste" event with it
+func (s *Screen) Paste(text string) {
+	target := s.Focused()
+	if m := s.Modal(); m != nil {
+		target = m
+	}
+	if p, ok := target.(Paster); ok {
+		p.Paste(text)
+	}
+	s.dispatch(Event{Name: "paste", Data: text})
+}
@@ -0,0 +1,162 @@
+package osui
+
+import (
+	"reflect"
+	"regexp"
+	"strings"
+)
+
+var selectorRe = regexp.MustCompile(`([.#]?)([^.#]+)`)
+
+type simpleSelector struct {
+	typ     string
+	id      string
+	classes []string
+}
+
+// Visit c and all of its descendants, stops once f returns false
+func Walk(c Component, f func(Component) bool) bool {
+	if !f(c) {
+		return false
+	}
+	if container, ok := c.(Container); ok {
+		for _, child := range container.GetChildren() {
+			if !Walk(child, f) {
+				return false
+			}
+		}
+	}
+	return true
+}
+
+func FindById(root Component, id string) Component {
+	var found Component
+	Walk(root, func(c Component) bool {
+		if c.GetComponentData().Id == id {
+			found = c
+			return false
+		}
+		return true
+	})
+	return found
+}
+
+// Get the component with the id, ok is false if it doesn't exist or isn't a T
+func GetElementById[T Component](root Component, id string) (T, bool) {
+	t, ok := FindById(root, id).(T)
+	return t, ok
+}
+
+// Run f with the component with the id, returns whether it was found as a T
+func WithElement[T Component](root Component, id string, f func(T)) bool {
+	t, ok := GetElementById[T](root, id)
+	if ok {
+		f(t)
+	}
+	return ok
+}
+
+func GetElementsByClass(root Component, class string) []Component {
+	return QuerySelectorAll(root, "."+class)
+}
+
+// Get all components matching a selector like "div .todo" or "button#submit.primary"
+func QuerySelectorAll(root Component, selector string) []Component {
+	res := []Component{}
+	parts := parseSelector(selector)
+	if len(parts) > 0 {
+		querySelector(root, parts, 0, &res)
+	}
+	return res
+}
+
+func QuerySelector(root Component, selector string) Component {
+	if res := QuerySelectorAll(root, selector); len(res) > 0 {
+		return res[0]
+	}
+	return nil
+}
+
+// The lowercase type name of a component without the "Component" suffix, *ui.DivComponent is "div"
+func TypeName(c Component) string {
+	t := reflect.TypeOf(c)
+	for t.Kind() == reflect.Ptr {
+		t = t.Elem()
+	}
+	return strings.ToLower(strings.TrimSuffix(t.Name(), "Component"))
+}
+
+func (d *ComponentData) HasClass(class string) bool {
+	for _, c := range d.Classes {
+		if c == class {
+			return true
+		}
+	}
+	return false
+}
+
+func (d *ComponentData) AddClass(class string) {
+	if !d.HasClass(class) {
+		d.Classes = append(d.Classes, class)
+	}
+}
+
+func (d *ComponentData) RemoveClass(class string) {
+	for i, c := range d.Classes {
+		if c == class {
+			d.Classes = append(d.Classes[:i], d.Classes[i+1:]...)
+			return
+		}
+	}
+}
+
+func querySelector(c Component, parts []simpleSelector, i int, res *[]Component) {
+	next := i
+	if parts[i].matches(c) {
+		if i == len(parts)-1 {
+			*res = append(*res, c)
+		} else {
+			next = i + 1
+		}
+	}
+	if container, ok := c.(Container); ok {
+		for _, child := range container.GetChildren() {
+			querySelector(child, parts, next, res)
+		}
+	}
+}
+
+func parseSelector(selector string) []simpleSelector {
+	res := []simpleSelector{}
+	for _, part := range strings.Fields(selector) {
+		sel := simpleSelector{}
+		for _, m := range selectorRe.FindAllStringSubmatch(part, -1) {
+			switch m[1] {
+			case ".":
+				sel.classes = append(sel.classes, m[2])
+			case "#":
+				sel.id = m[2]
+			default:
+				sel.typ = strings.ToLower(m[2])
+			}
+		}
+		res = append(res, sel)
+	}
+	return res
+}
+
+func (s simpleSelector) matches(c Component) bool {
+	data := c.GetComponentData()
+	if s.typ != "" && s.typ != "*" && s.typ != TypeName(c) {
+		return false
+	}
+	if s.id != "" && s.id != data.Id {
+		return false
+	}
+	for _, class := range s.classes {
+		if !data.HasClass(class) {
+			return false
+		}
+	}
+	return true
+}
@@ -0,0 +1,89 @@
+package osui
+
+import "time"
+
+type RenderMode int
+
+const (
+	// Render only after input, events or a requested render
+	RenderOnDemand RenderMode = iota
+	// Render at Settings.FPS, for animations and games
+	RenderContinuous
+)
+
+// Switch the render mode, safe to call from any goroutine
+func (s *Screen) SetRenderMode(mode RenderMode) {
+	s.mu.Lock()
+	s.mode = mode
+	s.mu.Unlock()
+	s.RequestRender()
+}
+
+func (s *Screen) RenderMode() RenderMode {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	return s.mode
+}
+
+// Render continuously for the duration even in on demand mode, used by widgets while they animate
+func (s *Screen) RequestContinuous(d time.Duration) {
+	s.mu.Lock()
+	if until := time.Now().Add(d); until.After(s.continuousUntil) {
+		s.continuousUntil = until
+	}
+	s.mu.Unlock()
+	s.RequestRender()
+}
+
+func (s *Screen) continuous() bool {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	return s.mode == RenderContinuous || time.Now().Before(s.continuousUntil)
+}
+
+// Mark the component as changed so its screen renders it again, safe to call from any goroutine.
+// Without changes, input or events an idle screen doesn't render at all, and versioned components and the ones
+// drawn in them reuse their last output
+func (d *ComponentData) Invalidate() {
+	d.invalidateCache()
+	if d.Screen != nil {
+		d.Screen.RequestRender()
+	}
+}
+
+// The shortest time between two frames, 0 without a cap
+func frameInterval() time.Duration {
+	if Settings.MaxFPS <= 0 {
+		return 0
+	}
+	return time.Second / time.Duration(Settings.MaxFPS)
+}
+
+// Keeps a ticker running at Settings.FPS while the screen renders continuously
+type frameClock struct {
+	ticker *time.Ticker
+	fps    int
+}
+
+// Get the channel of the next frame, nil while rendering on demand
+func (f *frameClock) next(continuous bool) <-chan time.Time {
+	fps := max(Settings.FPS, 1)
+	if f.ticker != nil && (!continuous || fps != f.fps) {
+		f.ticker.Stop()
+		f.ticker = nil
+	}
+	if !continuous {
+		return nil
+	}
+	if f.ticker == nil {
+		f.ticker = time.NewTicker(time.Second / time.Duration(fps))
+		f.fps = fps
+	}
+	return f.ticker.C
+}
+
+func (f *frameClock) stop() {
+	if f.ticker != nil {
+		f.ticker.Stop()
+	}
+}
@@ -0,0 +1,167 @@
+package osui
+
+import (
+	"encoding/base64"
+	"errors"
+	"strings"
+)
+
+var errNoClipboard = errors.New("osui: the backend has no clipboard")
+
+// The keys of the selection mode, the screen gets them all while it is on
+var SelectionKeymap = Keymap{
+	"up":         {Keys: []string{"\x1b[A", "k"}, Description: "Move up"},
+	"down":       {Keys: []string{"\x1b[B", "j"}, Description: "Move down"},
+	"left":       {Keys: []string{"\x1b[D", "h"}, Description: "Move left"},
+	"right":      {Keys: []string{"\x1b[C", "l"}, Description: "Move right"},
+	"line_start": {Keys: []string{"\x1b[H", "\x1b[1~", "0"}, Description: "Start of the line"},
+	"line_end":   {Keys: []string{"\x1b[F", "\x1b[4~", "$"}, Description: "End of the line"},
+	"anchor":     {Keys: []string{" ", "v"}, Description: "Start or drop the selection at the cursor"},
+	"block":      {Keys: []string{"b"}, Description: "Select a rectangle instead of lines"},
+	"copy":       {Keys: []string{"\r", "y"}, Description: "Copy the selection and leave"},
+	"cancel":     {Keys: []string{"\x1b", "q"}, Description: "Leave without copying"},
+}
+
+// The cursor of the selection mode and where the selection started, in cells of the last frame
+type selection struct {
+	x, y     int
+	anchorX  int
+	anchorY  int
+	anchored bool
+	block    bool
+}
+
+// Move a cursor over the last frame with the keyboard and copy the text between it and the anchor. Raw mode
+// keeps the terminal from selecting text in many setups, this works in all of them. Starts on the focused
+// component
+func (s *Screen) StartSelection() {
+	sel := &selection{}
+	if r, ok := s.Bounds(s.Focused()); ok {
+		sel.x, sel.y = r.X, r.Y
+	}
+	s.selection = sel
+	s.RequestRender()
+}
+
+func (s *Screen) StopSelection() {
+	s.selection = nil
+	s.RequestRender()
+}
+
+func (s *Screen) Selecting() bool {
+	return s.selection != nil
+}
+
+// Handle a key of the selection mode
+func (s *Screen) selectKey(k string) {
+	sel := s.selection
+	action, _ := SelectionKeymap.Action(k)
+	switch action {
+	case "up":
+		sel.y--
+	case "down":
+		sel.y++
+	case "left":
+		sel.x--
+	case "right":
+		sel.x++
+	case "line_start":
+		sel.x = 0
+	case "line_end":
+		if sel.y >= 0 && sel.y < len(s.lastFrame) {
+			sel.x = len(frameCells(s.lastFrame[sel.y])) - 1
+		}
+	case "anchor":
+		sel.anchored = !sel.anchored
+		sel.anchorX, sel.anchorY = sel.x, sel.y
+	case "block":
+		sel.block = !sel.block
+	case "copy":
+		text := s.SelectedText()
+		s.StopSelection()
+		s.CopyToClipboard(text)
+		s.dispatch(Event{Name: "copy", Data: text})
+	case "cancel":
+		s.StopSelection()
+	}
+	width, height := s.backend.Size()
+	sel.x = min(max(sel.x, 0), max(width-1, 0))
+	sel.y = min(max(sel.y, 0), max(height-1, 0))
+}
+
+// The plain text of the selection in the last frame, the cell under the cursor without an anchor. Spaces at the
+// ends of the lines are dropped
+func (s *Screen) SelectedText() string {
+	if s.selection == nil {
+		return ""
+	}
+	x0, y0, x1, y1 := s.selection.bounds()
+	lines := []string{}
+	for y := y0; y <= y1 && y < len(s.lastFrame); y++ {
+		from, to := s.selection.columns(y, x0, y0, x1, y1)
+		cells := frameCells(s.lastFrame[y])
+		from, to = min(from, len(cells)), min(to, len(cells))
+		lines = append(lines, strings.TrimRight(string(cells[from:to]), " "))
+	}
+	return strings.Join(lines, "\n")
+}
+
+// The corners of the selection, from the top left to the bottom right in reading order
+func (sel *selection) bounds() (int, int, int, int) {
+	x0, y0, x1, y1 := sel.x, sel.y, sel.x, sel.y
+	if sel.anchored {
+		x0, y0 = sel.anchorX, sel.anchorY
+	}
+	if y0 > y1 || (y0 == y1 && x0 > x1) {
+		x0, y0, x1, y1 = x1, y1, x0, y0
+	}
+	if sel.block && x0 > x1 {
+		x0, x1 = x1, x0
+	}
+	return x0, y0, x1, y1
+}
+
+// The selected columns [from, to) of the row, whole lines between the first and the last row unless it is a block
+func (sel *selection) columns(y, x0, y0, x1, y1 int) (int, int) {
+	if sel.block {
+		return x0, x1 + 1
+	}
+	from, to := 0, int(^uint(0)>>1)
+	if y == y0 {
+		from = x0
+	}
+	if y == y1 {
+		to = x1 + 1
+	}
+	return from, to
+}
+
+// Draw the selection over the frame with the selection style
+func (s *Screen) drawSelection(frame *[]string) {
+	x0, y0, x1, y1 := s.selection.bounds()
+	for y := max(y0, 0); y <= y1 && y < len(*frame); y++ {
+		from, to := s.selection.columns(y, x0, y0, x1, y1)
+		(*frame)[y] = shadeLine((*frame)[y], from, to, Settings.SelectionStyle)
+	}
+}
+
+// The characters of a frame line without its styles, one per cell
+func frameCells(line string) []rune {
+	text, _ := CompressString(line)
+	return []rune(text)
+}
+
+// Put the text on the clipboard through the backend of the screen, the terminal and stream backends send it with
+// OSC 52 to the terminal they draw on. Terminals that don't allow it ignore it
+func (s *Screen) CopyToClipboard(text string) error {
+	b, ok := s.backend.(ClipboardBackend)
+	if !ok {
+		return errNoClipboard
+	}
+	return b.SetClipboard(text)
+}
+
+// The OSC 52 sequence setting the clipboard to the text
+func clipboardSequence(text string) string {
+	return "\x1b]52;c;" + base64.StdEncoding.EncodeToString([]byte(text)) + "\x07"
+}
@@ -0,0 +1,63 @@
+package osui
+
+import (
+	"time"
+
+	"github.com/orus-dev/osui/colors"
+)
+
+// Drawn on the focused component, Style wraps it and Marker is drawn left of it
+type FocusIndicator struct {
+	Style  string
+	Marker string
+}
+
+type Config struct {
+	Glyphs         GlyphSet
+	Focus          FocusIndicator
+	Mouse          bool
+	ScrollStep     int
+	ScrollMomentum bool
+	ReducedMotion  bool
+	TickRate       time.Duration
+	// Show the real terminal cursor for the caret of inputs instead of drawing it, so input methods show the
+	// text being composed at the caret
+	TerminalCursor bool
+	// Number of actions kept for undo, 0 keeps all of them
+	UndoDepth int
+	// Frames per second when rendering continuously
+	FPS int
+	// Cap on the frames per second in every render mode, changes coming in faster are drawn together. 0 turns it off
+	MaxFPS int
+	// Report when a handler or a render blocks the ui loop for longer than this, 0 turns it off
+	Watchdog time.Duration
+	// Called from the watchdog with each stall, nil logs it
+	OnStall func(Stall)
+	// Ask the terminal for the kitty keyboard protocol, so the "key" event tells presses, repeats and releases apart
+	KeyEvents bool
+	// Reorder right-to-left text of texts and inputs into visual order, components with an Rtl direction always are
+	Bidi bool
+	// Drawn over the cells under the shadow of floating components and behind the ones that dim what is behind them
+	ShadowStyle   string
+	BackdropStyle string
+	// Drawn over the text of the selection mode
+	SelectionStyle string
+	// Draw links as OSC 8 hyperlinks, turn it off for terminals that show the escape sequences
+	Hyperlinks bool
+	// The window title while a screen runs, the title from before comes back when it stops. Empty leaves it as it is
+	Title string
+}
+
+var Settings = Config{
+	Glyphs:     DetectGlyphs(),
+	ScrollStep: 1,
+	TickRate:   100 * time.Millisecond,
+	UndoDepth:  100,
+	FPS:        30,
+	MaxFPS:     60,
+	Hyperlinks: true,
+	// A dark background so the shadow shows on empty cells too
+	ShadowStyle:    colors.Dim + "\x1b[40m",
+	BackdropStyle:  colors.Dim,
+	SelectionStyle: colors.Reverse,
+}
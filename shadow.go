@@ -0,0 +1,58 @@
+package osui
+
+import (
+	"strings"
+
+	"github.com/orus-dev/osui/colors"
+)
+
+// Draw the shadow of a floating component or a modal one cell right of and below its output, and dim the whole
+// frame first for the ones that dim what is behind them
+func drawElevation(c Component, output string, x, y int, frame *[]string) {
+	data := c.GetComponentData()
+	if data.DimBehind {
+		for i, line := range *frame {
+			(*frame)[i] = shadeLine(line, 0, VisibleWidth(line), Settings.BackdropStyle)
+		}
+	}
+	if !data.Shadow {
+		return
+	}
+	lines := strings.Split(output, "\n")
+	width := 0
+	for _, line := range lines {
+		width = max(width, VisibleWidth(line))
+	}
+	for i := range lines {
+		if row := y + 1 + i; row >= 0 && row < len(*frame) {
+			(*frame)[row] = shadeLine((*frame)[row], x+1, x+1+width, Settings.ShadowStyle)
+		}
+	}
+}
+
+// Apply style over the columns [from, to) of a frame line. The characters stay, and so do the styles after it
+func shadeLine(line string, from, to int, style string) string {
+	text, m := CompressString(line)
+	r := []rune(text)
+	from, to = max(from, 0), min(to, len(r))
+	if from >= to {
+		return line
+	}
+	var b strings.Builder
+	active := ""
+	for i := 0; i <= len(r); i++ {
+		active += m[i]
+		b.WriteString(m[i])
+		// Styles inside of the range would end the shade, so it goes on again after them
+		if i == from || (i > from && i < to && m[i] != "") {
+			b.WriteString(style)
+		}
+		if i == to {
+			b.WriteString(colors.Reset + active)
+		}
+		if i < len(r) {
+			b.WriteRune(r[i])
+		}
+	}
+	return b.String()
+}
@@ -0,0 +1,34 @@
+package osui
+
+// Components that can show the progress of a long operation, percent is in [0, 1]
+type ProgressSetter interface {
+	SetProgress(percent float64)
+}
+
+// Show the splash while build constructs the real component on another goroutine, then run it
+func RunWithSplash(splash Component, build func(progress func(percent float64)) Component) {
+	s := NewScreen(splash)
+	done := make(chan Component)
+	progress := make(chan float64, 16)
+	go func() {
+		done <- build(func(percent float64) {
+			select {
+			case progress <- percent:
+			default:
+			}
+		})
+	}()
+	for {
+		s.Render()
+		select {
+		case c := <-done:
+			s.component = c
+			s.Run()
+			return
+		case p := <-progress:
+			if ps, ok := splash.(ProgressSetter); ok {
+				ps.SetProgress(p)
+			}
+		}
+	}
+}
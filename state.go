@@ -0,0 +1,58 @@
+package osui
+
+import "sync"
+
+type State[T any] struct {
+	mu          sync.Mutex
+	value       T
+	subscribers []func(T)
+}
+
+func NewState[T any](value T) *State[T] {
+	return &State[T]{value: value}
+}
+
+func (s *State[T]) Get() T {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	return s.value
+}
+
+// Set the value, notify the subscribers and re-render the running screens
+func (s *State[T]) Set(value T) {
+	s.mu.Lock()
+	s.value = value
+	subscribers := append([]func(T){}, s.subscribers...)
+	s.mu.Unlock()
+	for _, f := range subscribers {
+		f(value)
+	}
+	requestRenderAll()
+}
+
+func (s *State[T]) Update(f func(T) T) {
+	s.Set(f(s.Get()))
+}
+
+// Subscribe to changes, the function runs on the goroutine that called Set
+func (s *State[T]) Subscribe(f func(T)) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	s.subscribers = append(s.subscribers, f)
+}
+
+// Subscribe with a function that changes the component, it runs on the ui loop of the running screen the
+// component is on so it doesn't race with its render. It runs right away while the component isn't on one
+func (s *State[T]) SubscribeFor(c Component, f func(T)) {
+	s.Subscribe(func(v T) {
+		onLoop(c, func() { f(v) })
+	})
+}
+
+func onLoop(c Component, f func()) {
+	if screen := c.GetComponentData().Screen; screen != nil {
+		screen.runOnLoop(f)
+		return
+	}
+	f()
+}
@@ -0,0 +1,90 @@
+package osui
+
+import (
+	"reflect"
+
+	"github.com/orus-dev/osui/colors"
+)
+
+// Switch the custom state of the component like "loading" or "error", "" goes back to its normal style
+func (d *ComponentData) SetState(state string) {
+	d.State = state
+	if d.Screen != nil {
+		d.Screen.RequestRender()
+	}
+}
+
+// Set the style used while the component is in the state, style is a pointer to the style struct of the component
+func (d *ComponentData) SetStateStyle(state string, style any) {
+	if d.States == nil {
+		d.States = map[string]any{}
+	}
+	d.States[state] = style
+}
+
+// The states StateStyle applies, from the lowest precedence up: "hover" while the pointer is over the component,
+// "focus" while it or one of its descendants has the focus, "active" while it is pressed, then the custom State
+// and "disabled" over everything
+func (d *ComponentData) ActiveStates() []string {
+	states := []string{}
+	if d.Hovered {
+		states = append(states, "hover")
+	}
+	if d.IsActive {
+		states = append(states, "focus")
+	}
+	if d.Pressed {
+		states = append(states, "active")
+	}
+	if d.State != "" {
+		states = append(states, d.State)
+	}
+	if d.Disabled {
+		states = append(states, "disabled")
+	}
+	return states
+}
+
+// Get the style of the active states, the fields a state leaves empty come from the ones below it and base.
+// Disabled components without a "disabled" style get their foregrounds dimmed.
+// With a Transition the colors blend into the new style
+func StateStyle[T any](d *ComponentData, base *T) *T {
+	UseStyle(base)
+	merged := *base
+	applied := false
+	mv := reflect.ValueOf(&merged).Elem()
+	for _, state := range d.ActiveStates() {
+		s, ok := d.States[state].(*T)
+		if !ok {
+			continue
+		}
+		sv := reflect.ValueOf(s).Elem()
+		for i := 0; i < sv.NumField(); i++ {
+			if f := sv.Field(i); f.Kind() == reflect.String && f.String() != "" && mv.Field(i).CanSet() {
+				mv.Field(i).SetString(f.String())
+			}
+		}
+		applied = true
+	}
+	if _, styled := d.States["disabled"].(*T); d.Disabled && !styled {
+		for i := 0; i < mv.NumField(); i++ {
+			if mv.Type().Field(i).Tag.Get("type") == "fg" && mv.Field(i).CanSet() {
+				mv.Field(i).SetString(colors.Dim + mv.Field(i).String())
+			}
+		}
+		applied = true
+	}
+	if !applied {
+		return transitionStyle(d, base)
+	}
+	UseStyle(&merged)
+	return transitionStyle(d, &merged)
+}
+
+// Enable or disable the component, disabled components are skipped by the focus and ignore input
+func (d *ComponentData) SetDisabled(disabled bool) {
+	d.Disabled = disabled
+	if d.Screen != nil {
+		d.Screen.RequestRender()
+	}
+}
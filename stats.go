@@ -0,0 +1,84 @@
+package osui
+
+import (
+	"reflect"
+	"sort"
+	"time"
+)
+
+type RenderTime struct {
+	Component Component
+	Id        string
+	Type      string
+	// The time the component took to render, including its children
+	Duration time.Duration
+}
+
+type Stats struct {
+	Components int
+	// Components that were drawn in the last frame
+	Rendered int
+	// Components of the last frame whose output was reused from the frame before, see Versioned
+	Cached int
+	// The time of the whole last frame, from the first render to writing it out
+	Frame time.Duration
+	// The components of the last frame, the slowest first
+	RenderTimes []RenderTime
+	// Approximate bytes held by the last frame, the outputs of the components and their styles
+	Memory int
+}
+
+// Measure the component tree and the last frame, to find the expensive parts of large apps
+func (s *Screen) Stats() Stats {
+	stats := Stats{Frame: s.frameTime, Memory: s.frameSize}
+	for _, root := range s.roots() {
+		Walk(root, func(c Component) bool {
+			data := c.GetComponentData()
+			stats.Components++
+			stats.Memory += styleSize(c) + data.outputSize
+			for _, style := range data.States {
+				stats.Memory += stringsSize(reflect.ValueOf(style))
+			}
+			if data.drawn() {
+				stats.Rendered++
+				if data.reused {
+					stats.Cached++
+				}
+				stats.RenderTimes = append(stats.RenderTimes, RenderTime{Component: c, Id: data.Id, Type: TypeName(c), Duration: data.renderTime})
+			}
+			return true
+		})
+	}
+	sort.SliceStable(stats.RenderTimes, func(i, j int) bool {
+		return stats.RenderTimes[i].Duration > stats.RenderTimes[j].Duration
+	})
+	return stats
+}
+
+func styleSize(c Component) int {
+	v := reflect.ValueOf(c)
+	for v.Kind() == reflect.Ptr {
+		v = v.Elem()
+	}
+	if v.Kind() != reflect.Struct {
+		return 0
+	}
+	return stringsSize(v.FieldByName("Style"))
+}
+
+// The bytes of the string fields of a style struct
+func stringsSize(v reflect.Value) int {
+	for v.IsValid() && v.Kind() == reflect.Ptr {
+		v = v.Elem()
+	}
+	if !v.IsValid() || v.Kind() != reflect.Struct {
+		return 0
+	}
+	size := 0
+	for i := 0; i < v.NumField(); i++ {
+		if f := v.Field(i); f.Kind() == reflect.String {
+			size += f.Len()
+		}
+	}
+	return size
+}
@@ -0,0 +1,83 @@
+package osui
+
+import (
+	"fmt"
+	"io"
+	"strings"
+	"sync"
+)
+
+// A backend reading the input from a stream and drawing on another one, like the channel of an ssh session.
+// The other side is expected to be a terminal in raw mode, the size has to be set with Resize whenever it changes
+type StreamBackend struct {
+	In  io.Reader
+	Out io.Writer
+	// Ask the other side for mouse reports
+	Mouse  bool
+	mu     sync.Mutex
+	width  int
+	height int
+}
+
+func NewStreamBackend(in io.Reader, out io.Writer, width, height int) *StreamBackend {
+	return &StreamBackend{In: in, Out: out, width: width, height: height}
+}
+
+// Serve the component on the streams, the screen draws at the given size until Resize changes it
+func NewStreamScreen(c Component, in io.Reader, out io.Writer, width, height int) (*Screen, *StreamBackend) {
+	b := NewStreamBackend(in, out, width, height)
+	return NewScreenWith(c, b), b
+}
+
+func (b *StreamBackend) Start() (func(), error) {
+	mouse, keyEvents, title := b.Mouse, Settings.KeyEvents, Settings.Title
+	b.write("\x1b[?25l\x1b[?2004h" + LogicValue(mouse, "\x1b[?1000h\x1b[?1003h\x1b[?1006h", "") + LogicValue(keyEvents, keyEventsEnable+keyEventsQuery, "") + LogicValue(title != "", pushTitle+titleSequence(title), ""))
+	return func() {
+		b.write(LogicValue(title != "", popTitle, "") + LogicValue(keyEvents, keyEventsDisable, "") + LogicValue(mouse, "\x1b[?1000l\x1b[?1003l\x1b[?1006l", "") + "\x1b[?2004l\x1b[0m\x1b[H\x1b[2J\x1b[?25h")
+	}, nil
+}
+
+func (b *StreamBackend) Size() (int, int) {
+	b.mu.Lock()
+	defer b.mu.Unlock()
+	return b.width, b.height
+}
+
+// Change the size of the frames, call RequestRender on the screen to draw at the new size right away
+func (b *StreamBackend) Resize(width, height int) {
+	b.mu.Lock()
+	b.width, b.height = width, height
+	b.mu.Unlock()
+}
+
+// Home the cursor and clear instead of running clear, the frame is sent in one write
+func (b *StreamBackend) Draw(frame []string) error {
+	return b.write("\x1b[H\x1b[2J" + strings.Join(frame, ""))
+}
+
+func (b *StreamBackend) ReadKey() (string, error) {
+	var buf [256]byte
+	n, err := b.In.Read(buf[:])
+	if n == 0 {
+		return "", err
+	}
+	return string(buf[:n]), nil
+}
+
+func (b *StreamBackend) SetCursor(visible bool, x, y int, style string) {
+	if !visible {
+		b.write("\x1b[?25l")
+		return
+	}
+	b.write(fmt.Sprintf("\x1b[%d;%dH%s\x1b[?25h", y+1, x+1, style))
+}
+
+// Sent to the other side, so over ssh the text lands on the clipboard of the client
+func (b *StreamBackend) SetClipboard(text string) error {
+	return b.write(clipboardSequence(text))
+}
+
+func (b *StreamBackend) write(s string) error {
+	_, err := io.WriteString(b.Out, s)
+	return err
+}
@@ -0,0 +1,541 @@
+package osui
+
+import (
+	"fmt"
+	"os"
+	"reflect"
+	"strconv"
+	"strings"
+	"time"
+	"unicode"
+
+	"github.com/orus-dev/osui/colors"
+)
+
+// Styles for the components matching selectors, parsed from text like
+//
+//	/* the sidebar */
+//	div#sidebar { outline: blue; background: #1e1e2e; }
+//	button:focus, button:hover { active-foreground: bold yellow; transition: 150ms; }
+//
+// Selectors are the ones of QuerySelectorAll with an optional state after a colon, see ActiveStates.
+// Properties are the fields of the Style of a component written in lowercase with dashes, and x, y, width,
+// height, z-index, transition and direction (ltr or rtl) of its ComponentData. Colors are names, #rgb or
+// #rrggbb, bold, dim, italic, underline, reverse, strike, none, gradient(from, to) or a quoted escape sequence.
+// Later rules win over earlier ones
+type Stylesheet struct {
+	Rules []StyleRule
+}
+
+type StyleRule struct {
+	Selectors    []string
+	Declarations []StyleDeclaration
+	Line         int
+}
+
+type StyleDeclaration struct {
+	Property string
+	Value    string
+	Line     int
+	Col      int
+}
+
+// An error in a stylesheet with the position it was found at, lines and columns start at 1
+type StylesheetError struct {
+	Line int
+	Col  int
+	Msg  string
+}
+
+func (e *StylesheetError) Error() string {
+	return fmt.Sprintf("%d:%d: %s", e.Line, e.Col, e.Msg)
+}
+
+var colorNames = map[string]string{
+	"black":   colors.Black,
+	"red":     colors.Red,
+	"green":   colors.Green,
+	"yellow":  colors.Yellow,
+	"blue":    colors.Blue,
+	"magenta": colors.Magenta,
+	"cyan":    colors.Cyan,
+	"white":   colors.White,
+
+	"bold":      colors.Bold,
+	"dim":       colors.Dim,
+	"italic":    colors.Italic,
+	"underline": colors.Underline,
+	"reverse":   colors.Reverse,
+	"strike":    colors.Strike,
+	"none":      "",
+}
+
+func LoadStylesheet(path string) (*Stylesheet, error) {
+	b, err := os.ReadFile(path)
+	if err != nil {
+		return nil, err
+	}
+	sheet, err := ParseStylesheet(string(b))
+	if err != nil {
+		return nil, fmt.Errorf("%s:%w", path, err)
+	}
+	return sheet, nil
+}
+
+func ParseStylesheet(src string) (*Stylesheet, error) {
+	p := &sheetParser{src: []rune(src), line: 1, col: 1}
+	sheet := &Stylesheet{}
+	for {
+		if err := p.skipSpace(); err != nil {
+			return nil, err
+		}
+		if p.done() {
+			return sheet, nil
+		}
+		rule, err := p.rule()
+		if err != nil {
+			return nil, err
+		}
+		sheet.Rules = append(sheet.Rules, rule)
+	}
+}
+
+type sheetParser struct {
+	src  []rune
+	pos  int
+	line int
+	col  int
+}
+
+func (p *sheetParser) done() bool {
+	return p.pos >= len(p.src)
+}
+
+func (p *sheetParser) peek() rune {
+	if p.done() {
+		return 0
+	}
+	return p.src[p.pos]
+}
+
+func (p *sheetParser) next() rune {
+	r := p.peek()
+	p.pos++
+	if r == '\n' {
+		p.line, p.col = p.line+1, 1
+	} else {
+		p.col++
+	}
+	return r
+}
+
+func (p *sheetParser) errorf(line, col int, format string, args ...any) error {
+	return &StylesheetError{Line: line, Col: col, Msg: fmt.Sprintf(format, args...)}
+}
+
+// Skip white space and comments
+func (p *sheetParser) skipSpace() error {
+	for !p.done() {
+		if unicode.IsSpace(p.peek()) {
+			p.next()
+		} else if p.peek() == '/' && p.pos+1 < len(p.src) && p.src[p.pos+1] == '*' {
+			line, col := p.line, p.col
+			p.next()
+			p.next()
+			for !p.done() && !(p.peek() == '*' && p.pos+1 < len(p.src) && p.src[p.pos+1] == '/') {
+				p.next()
+			}
+			if p.done() {
+				return p.errorf(line, col, "unclosed comment")
+			}
+			p.next()
+			p.next()
+		} else {
+			return nil
+		}
+	}
+	return nil
+}
+
+// Read until one of the stop runes, quoted strings may contain them
+func (p *sheetParser) until(stops string) (string, error) {
+	var b strings.Builder
+	for !p.done() && !strings.ContainsRune(stops, p.peek()) {
+		if p.peek() == '"' {
+			line, col := p.line, p.col
+			b.WriteRune(p.next())
+			for !p.done() && p.peek() != '"' && p.peek() != '\n' {
+				if p.peek() == '\\' {
+					b.WriteRune(p.next())
+				}
+				b.WriteRune(p.next())
+			}
+			if p.peek() != '"' {
+				return "", p.errorf(line, col, "unclosed string")
+			}
+		}
+		b.WriteRune(p.next())
+	}
+	return b.String(), nil
+}
+
+func (p *sheetParser) rule() (StyleRule, error) {
+	rule := StyleRule{Line: p.line}
+	line, col := p.line, p.col
+	selectors, err := p.until("{}")
+	if err != nil {
+		return rule, err
+	}
+	if p.peek() != '{' {
+		return rule, p.errorf(p.line, p.col, "expected { after the selector")
+	}
+	for _, sel := range strings.Split(selectors, ",") {
+		sel = strings.TrimSpace(sel)
+		if sel == "" {
+			return rule, p.errorf(line, col, "empty selector")
+		}
+		rule.Selectors = append(rule.Selectors, sel)
+	}
+	p.next()
+	rule.Declarations, err = p.declarations(line, col, true)
+	return rule, err
+}
+
+// Parse the declarations of a rule up to its }, or up to the end without braced
+func (p *sheetParser) declarations(line, col int, braced bool) ([]StyleDeclaration, error) {
+	res := []StyleDeclaration{}
+	for {
+		if err := p.skipSpace(); err != nil {
+			return nil, err
+		}
+		if p.done() {
+			if braced {
+				return nil, p.errorf(line, col, "unclosed rule")
+			}
+			return res, nil
+		}
+		if p.peek() == '}' {
+			if !braced {
+				return nil, p.errorf(p.line, p.col, "unexpected }")
+			}
+			p.next()
+			return res, nil
+		}
+		d := StyleDeclaration{Line: p.line, Col: p.col}
+		property, err := p.until(":;{}")
+		if err != nil {
+			return nil, err
+		}
+		if p.peek() != ':' {
+			return nil, p.errorf(p.line, p.col, "expected : after %q", strings.TrimSpace(property))
+		}
+		p.next()
+		value, err := p.until(";}")
+		if err != nil {
+			return nil, err
+		}
+		if p.peek() == ';' {
+			p.next()
+		}
+		d.Property, d.Value = strings.ToLower(strings.TrimSpace(property)), strings.TrimSpace(value)
+		if d.Property == "" {
+			return nil, p.errorf(d.Line, d.Col, "missing property")
+		}
+		if _, err := parseStyleValue(d.Value, false); err != nil && !layoutProperty(d.Property) {
+			return nil, p.errorf(d.Line, d.Col, "%s: %s", d.Property, err)
+		}
+		res = append(res, d)
+	}
+}
+
+// The fields of ComponentData set by the layout properties
+var layoutFields = map[string]string{
+	"x":          "X",
+	"y":          "Y",
+	"width":      "Width",
+	"height":     "Height",
+	"z-index":    "ZIndex",
+	"transition": "Transition",
+	"direction":  "Direction",
+}
+
+func layoutProperty(property string) bool {
+	_, ok := layoutFields[property]
+	return ok
+}
+
+// Turn a value into escape sequences, colors become backgrounds for bg fields
+func parseStyleValue(value string, bg bool) (string, error) {
+	var b strings.Builder
+	for _, word := range splitValue(value) {
+		var s string
+		switch {
+		case strings.HasPrefix(word, `"`):
+			unquoted, err := strconv.Unquote(word)
+			if err != nil {
+				return "", fmt.Errorf("invalid string %s", word)
+			}
+			s = unquoted
+		case strings.HasPrefix(strings.ToLower(word), "gradient(") && strings.HasSuffix(word, ")"):
+			ends := strings.Split(word[len("gradient("):len(word)-1], ",")
+			if len(ends) != 2 {
+				return "", fmt.Errorf("a gradient needs 2 colors")
+			}
+			from, err := parseStyleValue(ends[0], false)
+			if err != nil {
+				return "", err
+			}
+			to, err := parseStyleValue(ends[1], false)
+			if err != nil {
+				return "", err
+			}
+			s = colors.Gradient(from, to)
+		case strings.HasPrefix(word, "#"):
+			rgb, err := parseHex(word)
+			if err != nil {
+				return "", err
+			}
+			s = colors.Rgb(rgb[0], rgb[1], rgb[2])
+		default:
+			name, ok := colorNames[strings.ToLower(word)]
+			if !ok {
+				return "", fmt.Errorf("unknown color %q", word)
+			}
+			s = name
+		}
+		if bg {
+			s = colors.AsBg(s)
+		}
+		b.WriteString(s)
+	}
+	return b.String(), nil
+}
+
+// Split on white space outside of quotes and parentheses
+func splitValue(value string) []string {
+	words := []string{}
+	var b strings.Builder
+	quoted, depth := false, 0
+	for i := 0; i < len(value); i++ {
+		c := value[i]
+		switch {
+		case c == '\\' && quoted && i+1 < len(value):
+			b.WriteByte(c)
+			i++
+			c = value[i]
+		case c == '"':
+			quoted = !quoted
+		case !quoted && c == '(':
+			depth++
+		case !quoted && c == ')':
+			depth = max(depth-1, 0)
+		case !quoted && depth == 0 && (c == ' ' || c == '\t' || c == '\n' || c == '\r'):
+			if b.Len() > 0 {
+				words = append(words, b.String())
+				b.Reset()
+			}
+			continue
+		}
+		b.WriteByte(c)
+	}
+	if b.Len() > 0 {
+		words = append(words, b.String())
+	}
+	return words
+}
+
+func parseHex(s string) ([3]uint8, error) {
+	hex := strings.TrimPrefix(s, "#")
+	if len(hex) == 3 {
+		hex = string([]byte{hex[0], hex[0], hex[1], hex[1], hex[2], hex[2]})
+	}
+	n, err := strconv.ParseUint(hex, 16, 32)
+	if err != nil || len(hex) != 6 {
+		return [3]uint8{}, fmt.Errorf("invalid color %s", s)
+	}
+	return [3]uint8{uint8(n >> 16), uint8(n >> 8), uint8(n)}, nil
+}
+
+// A field of a component set by a stylesheet, the state is empty for the style of the component and the layout
+type sheetField struct {
+	state    string
+	property string
+}
+
+// Set the properties of every rule on the matching components of the tree. Properties a component
+// doesn't have are skipped, returns the first value that doesn't fit its property. The fields set by an
+// earlier Apply that no rule sets anymore, like after removing a declaration, go back to what they held before
+func (sheet *Stylesheet) Apply(root Component) error {
+	var first error
+	set := map[*ComponentData]map[sheetField]bool{}
+	for _, rule := range sheet.Rules {
+		for _, sel := range rule.Selectors {
+			query, state, _ := strings.Cut(sel, ":")
+			state = strings.TrimSpace(state)
+			for _, c := range QuerySelectorAll(root, query) {
+				for _, d := range rule.Declarations {
+					field, bg, ok := propertyField(c, state, d.Property)
+					if !ok {
+						continue
+					}
+					value, err := parseProperty(field, d, bg)
+					if err != nil {
+						if first == nil {
+							first = &StylesheetError{Line: d.Line, Col: d.Col, Msg: fmt.Sprintf("%s: %s", d.Property, err)}
+						}
+						continue
+					}
+					data, key := c.GetComponentData(), sheetField{state: state, property: d.Property}
+					if data.sheetBase == nil {
+						data.sheetBase = map[sheetField]any{}
+					}
+					if _, saved := data.sheetBase[key]; !saved {
+						data.sheetBase[key] = field.Interface()
+					}
+					if set[data] == nil {
+						set[data] = map[sheetField]bool{}
+					}
+					set[data][key] = true
+					field.Set(value)
+				}
+			}
+		}
+	}
+	Walk(root, func(c Component) bool {
+		data := c.GetComponentData()
+		for key, base := range data.sheetBase {
+			if set[data][key] {
+				continue
+			}
+			if field, _, ok := propertyField(c, key.state, key.property); ok {
+				field.Set(reflect.ValueOf(base))
+			}
+			delete(data.sheetBase, key)
+		}
+		return true
+	})
+	return first
+}
+
+// Set declarations like "outline: blue; width: 30" on the component alone, like an inline style
+func ApplyStyle(c Component, declarations string) error {
+	p := &sheetParser{src: []rune(declarations), line: 1, col: 1}
+	decls, err := p.declarations(1, 1, false)
+	if err != nil {
+		return err
+	}
+	for _, d := range decls {
+		if err := applyDeclaration(c, "", d); err != nil {
+			return &StylesheetError{Line: d.Line, Col: d.Col, Msg: fmt.Sprintf("%s: %s", d.Property, err)}
+		}
+	}
+	return nil
+}
+
+// Apply the stylesheet to the tree and the layers before every frame, safe to call from any goroutine.
+// nil stops applying it, the styles it already set stay. Values that don't fit their property are skipped,
+// their errors are logged once
+func (s *Screen) SetStylesheet(sheet *Stylesheet) {
+	s.mu.Lock()
+	s.stylesheet = sheet
+	s.sheetErrors = nil
+	s.mu.Unlock()
+	s.RequestRender()
+}
+
+// Report an error of applying the stylesheet once, to the onError of WatchStylesheet or to the log
+func (s *Screen) reportStylesheetError(err error) {
+	s.mu.Lock()
+	reported := s.sheetErrors[err.Error()]
+	if !reported {
+		if s.sheetErrors == nil {
+			s.sheetErrors = map[string]bool{}
+		}
+		s.sheetErrors[err.Error()] = true
+	}
+	onError := s.sheetOnError
+	s.mu.Unlock()
+	if !reported {
+		reportWatchError(onError, err)
+	}
+}
+
+func (s *Screen) Stylesheet() *Stylesheet {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	return s.stylesheet
+}
+
+func applyDeclaration(c Component, state string, d StyleDeclaration) error {
+	field, bg, ok := propertyField(c, state, d.Property)
+	if !ok {
+		return nil
+	}
+	value, err := parseProperty(field, d, bg)
+	if err != nil {
+		return err
+	}
+	field.Set(value)
+	return nil
+}
+
+// Get the field a property sets on the component, in its state style for a state, and whether colors in it are
+// backgrounds. Not ok when the component has no such field, layout properties have no states
+func propertyField(c Component, state, property string) (reflect.Value, bool, bool) {
+	data := c.GetComponentData()
+	if name, ok := layoutFields[property]; ok {
+		return reflect.ValueOf(data).Elem().FieldByName(name), false, state == ""
+	}
+	style := reflect.ValueOf(c)
+	for style.Kind() == reflect.Ptr || style.Kind() == reflect.Interface {
+		style = style.Elem()
+	}
+	if style.Kind() != reflect.Struct {
+		return reflect.Value{}, false, false
+	}
+	style = style.FieldByName("Style")
+	if !style.IsValid() || style.Kind() != reflect.Ptr || style.IsNil() || style.Elem().Kind() != reflect.Struct {
+		return reflect.Value{}, false, false
+	}
+	if state != "" {
+		// The state style only holds the fields set for it, the rest comes from the component style
+		stateStyle, ok := data.States[state]
+		if v := reflect.ValueOf(stateStyle); !ok || !v.IsValid() || v.Type() != style.Type() {
+			stateStyle = reflect.New(style.Elem().Type()).Interface()
+			data.SetStateStyle(state, stateStyle)
+		}
+		style = reflect.ValueOf(stateStyle)
+	}
+	style = style.Elem()
+	name := strings.ReplaceAll(property, "-", "")
+	for i := 0; i < style.NumField(); i++ {
+		field := style.Type().Field(i)
+		if strings.ToLower(field.Name) != name || !style.Field(i).CanSet() {
+			continue
+		}
+		switch style.Field(i).Kind() {
+		case reflect.String, reflect.Int, reflect.Int8, reflect.Int16, reflect.Int32, reflect.Int64:
+			return style.Field(i), field.Tag.Get("type") == "bg", true
+		}
+	}
+	return reflect.Value{}, false, false
+}
+
+// Parse the value of the declaration for the field
+func parseProperty(field reflect.Value, d StyleDeclaration, bg bool) (reflect.Value, error) {
+	switch {
+	case d.Property == "transition":
+		duration, err := time.ParseDuration(d.Value)
+		return reflect.ValueOf(duration), err
+	case d.Property == "direction":
+		dir, err := ParseDirection(d.Value)
+		return reflect.ValueOf(dir), err
+	case field.Kind() == reflect.String:
+		value, err := parseStyleValue(d.Value, bg)
+		return reflect.ValueOf(value).Convert(field.Type()), err
+	}
+	n, err := strconv.Atoi(d.Value)
+	value := reflect.New(field.Type()).Elem()
+	value.SetInt(int64(n))
+	return value, err
+}
@@ -0,0 +1,30 @@
+package osui
+
+// Hand the terminal to f for a while, like to open an editor. The backend is stopped so the terminal is back in
+// its normal mode, then f runs and the backend starts again with a full redraw. Call it from the ui loop, like in
+// a key handler or with Handle().Do, a screen that isn't running just calls f
+func (s *Screen) Suspend(f func()) error {
+	if s.stopBackend == nil {
+		f()
+		return nil
+	}
+	pauseInput(true)
+	defer pauseInput(false)
+	s.stopBackend()
+	f()
+	stop, err := s.backend.Start()
+	if err != nil {
+		s.stopBackend = func() {}
+		return err
+	}
+	s.stopBackend = stop
+	s.lastFrame = nil
+	s.RequestRender()
+	return nil
+}
+
+// Stop the process like Ctrl+Z does in a shell, the screen comes back when the shell brings it to the foreground.
+// Raw mode keeps Ctrl+Z from stopping it, bind the "suspend" action of the screen keymap to a key for it
+func (s *Screen) SuspendProcess() error {
+	return s.Suspend(stopProcess)
+}
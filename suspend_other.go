@@ -0,0 +1,19 @@
+//go:build !unix
+
+package osui
+
+import "os"
+
+func pauseInput(bool) {}
+
+// Without poll the read that follows blocks until there is input, done can't stop it
+func waitForInput(done <-chan struct{}) bool {
+	return true
+}
+
+// Processes can't be stopped from the inside here
+func stopProcess() {}
+
+func suspendSignals() (<-chan os.Signal, func()) {
+	return nil, func() {}
+}
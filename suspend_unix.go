@@ -0,0 +1,55 @@
+//go:build unix
+
+package osui
+
+import (
+	"os"
+	"os/signal"
+	"sync/atomic"
+	"syscall"
+	"time"
+
+	"golang.org/x/sys/unix"
+)
+
+var inputPaused atomic.Bool
+
+func pauseInput(paused bool) {
+	inputPaused.Store(paused)
+}
+
+// Wait until there is input on stdin while the input isn't paused, so a suspended screen leaves the keys to the
+// program that has the terminal. Returns false once done is closed, a nil done waits for good
+func waitForInput(done <-chan struct{}) bool {
+	fds := []unix.PollFd{{Fd: int32(os.Stdin.Fd()), Events: unix.POLLIN}}
+	for {
+		select {
+		case <-done:
+			return false
+		default:
+		}
+		if inputPaused.Load() {
+			time.Sleep(50 * time.Millisecond)
+			continue
+		}
+		n, err := unix.Poll(fds, 50)
+		if err == unix.EINTR {
+			continue
+		}
+		// Without poll the read blocks as before
+		if err != nil || (n > 0 && !inputPaused.Load()) {
+			return true
+		}
+	}
+}
+
+func stopProcess() {
+	syscall.Kill(os.Getpid(), syscall.SIGSTOP)
+}
+
+// SIGTSTP sent from outside, like with kill -TSTP
+func suspendSignals() (<-chan os.Signal, func()) {
+	c := make(chan os.Signal, 1)
+	signal.Notify(c, syscall.SIGTSTP)
+	return c, func() { signal.Stop(c) }
+}
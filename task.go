@@ -0,0 +1,141 @@
+package osui
+
+import (
+	"fmt"
+	"sync"
+)
+
+// Sent as the data of the "task" event whenever a task reports progress or finishes
+type TaskUpdate struct {
+	Task    *Task
+	Value   float64
+	Message string
+	Done    bool
+	Err     error
+}
+
+// A function running on its own goroutine, its progress is kept in states so widgets like Gauge can bind to them
+type Task struct {
+	Name string
+	// How far the task got, in [0, 1]
+	Progress *State[float64]
+	Message  *State[string]
+	screen   *Screen
+	done     chan struct{}
+	err      error
+	// The last report, the states get it on the ui loop
+	mu       sync.Mutex
+	value    float64
+	message  string
+	handlers []func(TaskUpdate)
+}
+
+// Handed to the function of a task to report how far it got, safe to use from its goroutine
+type TaskProgress struct {
+	task *Task
+}
+
+// Run f on a new goroutine, the progress it reports updates the states of the task on the ui loop and is
+// emitted as a "task" event there. A panic in f ends the task with an error
+func (s *Screen) SpawnTask(name string, f func(p *TaskProgress) error) *Task {
+	t := &Task{
+		Name:     name,
+		Progress: NewState(0.0),
+		Message:  NewState(""),
+		screen:   s,
+		done:     make(chan struct{}),
+	}
+	go func() {
+		var err error
+		defer func() {
+			if r := recover(); r != nil {
+				err = fmt.Errorf("task %s panicked: %v", name, r)
+			}
+			t.err = err
+			close(t.done)
+			if err == nil {
+				t.mu.Lock()
+				t.value = 1
+				t.mu.Unlock()
+			}
+			t.update(true)
+		}()
+		err = f(&TaskProgress{task: t})
+	}()
+	return t
+}
+
+// Set the value in [0, 1] and the message
+func (p *TaskProgress) Report(value float64, message string) {
+	p.task.mu.Lock()
+	p.task.value, p.task.message = min(max(value, 0), 1), message
+	p.task.mu.Unlock()
+	p.task.update(false)
+}
+
+func (p *TaskProgress) SetValue(value float64) {
+	p.task.mu.Lock()
+	message := p.task.message
+	p.task.mu.Unlock()
+	p.Report(value, message)
+}
+
+func (p *TaskProgress) SetMessage(message string) {
+	p.task.mu.Lock()
+	value := p.task.value
+	p.task.mu.Unlock()
+	p.Report(value, message)
+}
+
+// Get the channel closed once the task finished
+func (t *Task) Done() <-chan struct{} {
+	return t.done
+}
+
+// Block until the task finished and get the error it returned
+func (t *Task) Wait() error {
+	<-t.done
+	return t.err
+}
+
+// Get the error of a finished task, nil while it runs
+func (t *Task) Err() error {
+	select {
+	case <-t.done:
+		return t.err
+	default:
+		return nil
+	}
+}
+
+// Set the states to the last report and tell the handlers about it on the ui loop
+func (t *Task) update(done bool) {
+	t.mu.Lock()
+	u := TaskUpdate{Task: t, Value: t.value, Message: t.message, Done: done}
+	t.mu.Unlock()
+	if done {
+		u.Err = t.err
+	}
+	t.screen.runOnLoop(func() {
+		t.Progress.Set(u.Value)
+		t.Message.Set(u.Message)
+		t.mu.Lock()
+		handlers := t.handlers
+		if done {
+			t.handlers = nil
+		}
+		t.mu.Unlock()
+		for _, f := range handlers {
+			f(u)
+		}
+		t.screen.dispatch(Event{Name: "task", Data: u})
+	})
+}
+
+// Run f on the ui loop for every update of the task, register it right after SpawnTask to get all of them.
+// It is dropped once the task finished
+func (t *Task) OnUpdate(f func(TaskUpdate)) {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+	t.handlers = append(t.handlers, f)
+}
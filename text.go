@@ -0,0 +1,128 @@
+package osui
+
+import (
+	"strings"
+	"unicode"
+)
+
+// Number of terminal cells a rune takes
+func RuneWidth(r rune) int {
+	switch {
+	case r < 32 || (r >= 0x7f && r < 0xa0):
+		return 0
+	case extendsGrapheme(r) || unicode.Is(unicode.Cf, r):
+		return 0
+	case isWide(r):
+		return 2
+	}
+	return 1
+}
+
+// Number of terminal cells a grapheme cluster takes, decided by its first rune
+func GraphemeWidth(g string) int {
+	for _, r := range g {
+		return RuneWidth(r)
+	}
+	return 0
+}
+
+// Number of terminal cells the string takes, escape sequences are ignored
+func VisibleWidth(s string) int {
+	w := 0
+	for _, g := range Graphemes(StripAnsi(s)) {
+		w += GraphemeWidth(g)
+	}
+	return w
+}
+
+func StripAnsi(s string) string {
+	return re.ReplaceAllString(s, "")
+}
+
+// Split a string into grapheme clusters, combining marks, variation selectors,
+// zero width joiner sequences and flag pairs stay with the rune before them
+func Graphemes(s string) []string {
+	res := []string{}
+	start, regional := 0, 0
+	var prev rune
+	for i, r := range s {
+		isRegional := r >= 0x1f1e6 && r <= 0x1f1ff
+		join := extendsGrapheme(r) || prev == 0x200d || (prev == '\r' && r == '\n') || (isRegional && regional%2 == 1)
+		if i > 0 && !join {
+			res = append(res, s[start:i])
+			start, regional = i, 0
+		}
+		if isRegional {
+			regional++
+		}
+		prev = r
+	}
+	if start < len(s) {
+		res = append(res, s[start:])
+	}
+	return res
+}
+
+// Cut the string to the display width without splitting escape sequences or grapheme clusters,
+// the escape sequences after the cut are kept so styles still get reset
+func TruncateVisible(s string, width int) string {
+	var b strings.Builder
+	used, full := 0, false
+	for len(s) > 0 {
+		if s[0] == 0x1b {
+			if loc := re.FindStringIndex(s); loc != nil && loc[0] == 0 {
+				b.WriteString(s[:loc[1]])
+				s = s[loc[1]:]
+				continue
+			}
+		}
+		end := strings.IndexByte(s[1:], 0x1b) + 1
+		if end == 0 {
+			end = len(s)
+		}
+		for _, g := range Graphemes(s[:end]) {
+			w := GraphemeWidth(g)
+			if full || used+w > width {
+				full = true
+				continue
+			}
+			b.WriteString(g)
+			used += w
+		}
+		s = s[end:]
+	}
+	return b.String()
+}
+
+func extendsGrapheme(r rune) bool {
+	return unicode.In(r, unicode.Mn, unicode.Me, unicode.Mc) ||
+		r == 0x200d ||
+		(r >= 0xfe00 && r <= 0xfe0f) ||
+		(r >= 0xe0100 && r <= 0xe01ef) ||
+		(r >= 0x1f3fb && r <= 0x1f3ff)
+}
+
+func isWide(r rune) bool {
+	return r >= 0x1100 && (r <= 0x115f ||
+		r == 0x2329 || r == 0x232a ||
+		(r >= 0x2e80 && r <= 0xa4cf && r != 0x303f) ||
+		(r >= 0xac00 && r <= 0xd7a3) ||
+		(r >= 0xf900 && r <= 0xfaff) ||
+		(r >= 0xfe30 && r <= 0xfe6f) ||
+		(r >= 0xff00 && r <= 0xff60) ||
+		(r >= 0xffe0 && r <= 0xffe6) ||
+		(r >= 0x1f300 && r <= 0x1f64f) ||
+		(r >= 0x1f900 && r <= 0x1f9ff) ||
+		(r >= 0x20000 && r <= 0x3fffd))
+}
+
+// Cut the string to the display width, ending it with an ellipsis if anything was cut
+func TruncateEllipsis(s string, width int) string {
+	if VisibleWidth(s) <= width {
+		return s
+	}
+	if width < 1 {
+		return ""
+	}
+	return TruncateVisible(s, width-1) + "…"
+}
@@ -0,0 +1,51 @@
+package osui
+
+import (
+	"fmt"
+	"net/url"
+	"os"
+	"path/filepath"
+	"strings"
+)
+
+const (
+	pushTitle = "\x1b[22;0t"
+	popTitle  = "\x1b[23;0t"
+)
+
+// Set the title of the terminal window or tab
+func SetTitle(title string) {
+	fmt.Fprint(Terminal, titleSequence(title))
+}
+
+// Save the title on the title stack of the terminal for PopTitle, terminals without the stack ignore it
+func PushTitle() {
+	fmt.Fprint(Terminal, pushTitle)
+}
+
+func PopTitle() {
+	fmt.Fprint(Terminal, popTitle)
+}
+
+// Tell the terminal the working directory with OSC 7, so new tabs and windows open in it
+func SetWorkingDirectory(path string) error {
+	abs, err := filepath.Abs(path)
+	if err != nil {
+		return err
+	}
+	host, _ := os.Hostname()
+	u := url.URL{Scheme: "file", Host: host, Path: filepath.ToSlash(abs)}
+	_, err = fmt.Fprint(Terminal, "\x1b]7;"+u.String()+"\x1b\\")
+	return err
+}
+
+// OSC 2 with the control characters of the title dropped, so it can't end the sequence early
+func titleSequence(title string) string {
+	title = strings.Map(func(r rune) rune {
+		if r < 32 || r == 0x7f {
+			return -1
+		}
+		return r
+	}, title)
+	return "\x1b]2;" + title + "\x1b\\"
+}
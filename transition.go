@@ -0,0 +1,58 @@
+package osui
+
+import (
+	"reflect"
+	"time"
+
+	"github.com/orus-dev/osui/colors"
+)
+
+// The style a component is blending from and the one it is blending to, both are values of its style struct
+type styleTransition struct {
+	from  any
+	to    any
+	start time.Time
+}
+
+// Blend from the style shown so far to the target when the target changes, for components with a Transition.
+// The screen renders continuously until the blend is done
+func transitionStyle[T any](d *ComponentData, target *T) *T {
+	if d.Transition <= 0 || d.Screen == nil || Settings.ReducedMotion {
+		d.transition = nil
+		return target
+	}
+	t := d.transition
+	if t == nil {
+		d.transition = &styleTransition{from: *target, to: *target}
+		return target
+	}
+	if !reflect.DeepEqual(t.to, *target) {
+		// Start from what is shown right now, so a change in the middle of a blend doesn't jump
+		t.from = *blendStyle[T](d, t)
+		t.to = *target
+		t.start = time.Now()
+		d.Screen.RequestContinuous(d.Transition)
+	}
+	return blendStyle[T](d, t)
+}
+
+func blendStyle[T any](d *ComponentData, t *styleTransition) *T {
+	from, fromOk := t.from.(T)
+	to, toOk := t.to.(T)
+	if !toOk {
+		return new(T)
+	}
+	progress := min(float64(time.Since(t.start))/float64(d.Transition), 1)
+	if !fromOk || progress >= 1 {
+		return &to
+	}
+	progress = EaseInOut(progress)
+	res := to
+	rv, fv, tv := reflect.ValueOf(&res).Elem(), reflect.ValueOf(from), reflect.ValueOf(to)
+	for i := 0; i < rv.NumField(); i++ {
+		if f := rv.Field(i); f.Kind() == reflect.String && f.CanSet() {
+			f.SetString(colors.Mix(fv.Field(i).String(), tv.Field(i).String(), progress))
+		}
+	}
+	return &res
+}
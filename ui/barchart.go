@@ -0,0 +1,150 @@
+package ui
+
+import (
+	"math"
+	"strconv"
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+type BarChartParams struct {
+	Style      BarChartStyle
+	Labels     []string
+	BarWidth   int
+	Gap        int
+	Max        float64
+	ShowValues bool
+	Bind       *osui.State[[]float64]
+}
+
+type BarChartStyle struct {
+	Bar   string `default:"" type:"fg"`
+	Label string `default:"" type:"fg"`
+	Value string `default:"" type:"fg"`
+}
+
+type BarChartComponent struct {
+	Data       osui.ComponentData
+	Style      *BarChartStyle
+	Values     []float64
+	Labels     []string
+	BarWidth   int
+	Gap        int
+	Max        float64
+	ShowValues bool
+}
+
+func (b *BarChartComponent) GetComponentData() *osui.ComponentData {
+	return &b.Data
+}
+
+func (b *BarChartComponent) Render() string {
+	osui.UseStyle(b.Style)
+	barWidth := max(b.BarWidth, 1)
+	height := b.Data.Height
+	if len(b.Labels) > 0 {
+		height--
+	}
+	if b.ShowValues {
+		height--
+	}
+	height = max(height, 1)
+	top := b.Max
+	if top == 0 {
+		top = maxValue(b.Values)
+	}
+
+	rows := make([]strings.Builder, height)
+	values, labels := strings.Builder{}, strings.Builder{}
+	for i, v := range b.Values {
+		gap := osui.LogicValue(i > 0, strings.Repeat(" ", b.Gap), "")
+		for j, r := range barCells(v, top, height) {
+			rows[j].WriteString(gap + b.Style.Bar + strings.Repeat(string(r), barWidth) + colors.Reset)
+		}
+		values.WriteString(gap + b.Style.Value + fitText(compactValue(v, barWidth), barWidth) + colors.Reset)
+		label := ""
+		if i < len(b.Labels) {
+			label = b.Labels[i]
+		}
+		labels.WriteString(gap + b.Style.Label + fitText(label, barWidth) + colors.Reset)
+	}
+
+	lines := []string{}
+	if b.ShowValues {
+		lines = append(lines, values.String())
+	}
+	for i := range rows {
+		lines = append(lines, rows[i].String())
+	}
+	if len(b.Labels) > 0 {
+		lines = append(lines, labels.String())
+	}
+	return strings.Join(lines, "\n")
+}
+
+func (b *BarChartComponent) Update(key string) bool {
+	return false
+}
+
+// Show the values of the state, updated whenever it changes
+func (b *BarChartComponent) Bind(state *osui.State[[]float64]) *BarChartComponent {
+	b.Values = state.Get()
+	state.SubscribeFor(b, func(v []float64) {
+		b.Values = v
+	})
+	return b
+}
+
+func (b *BarChartComponent) Params(param BarChartParams) *BarChartComponent {
+	b.Style = osui.SetDefaults(&param.Style).(*BarChartStyle)
+	b.Labels = param.Labels
+	b.BarWidth = osui.LogicValueInt(param.BarWidth == 0, 3, param.BarWidth)
+	b.Gap = param.Gap
+	b.Max = param.Max
+	b.ShowValues = param.ShowValues
+	if param.Bind != nil {
+		b.Bind(param.Bind)
+	}
+	return b
+}
+
+// Cut or pad the text to the width in cells, wide characters take two
+func fitText(text string, width int) string {
+	text = osui.TruncateVisible(text, width)
+	return text + strings.Repeat(" ", max(width-osui.VisibleWidth(text), 0))
+}
+
+// The value in at most width cells, with fewer decimals and a k, M, G or T suffix when it doesn't fit. Digits
+// are rounded, never cut, a value that doesn't fit at all is shown as an ellipsis
+func compactValue(v float64, width int) string {
+	if text := formatTick(v); len(text) <= width {
+		return text
+	}
+	scaled := v
+	for i, unit := range []string{"", "k", "M", "G", "T"} {
+		if i > 0 {
+			scaled /= 1000
+			// 0k says nothing about the value
+			if math.Abs(scaled) < 0.5 {
+				break
+			}
+		}
+		for prec := 3; prec >= 0; prec-- {
+			if text := strconv.FormatFloat(scaled, 'f', prec, 64) + unit; len(text) <= width {
+				return text
+			}
+		}
+	}
+	return osui.LogicValue(width > 0, "…", "")
+}
+
+func BarChart(values ...float64) *BarChartComponent {
+	return &BarChartComponent{
+		Values:   values,
+		Style:    osui.SetDefaults(&BarChartStyle{}).(*BarChartStyle),
+		BarWidth: 3,
+		Gap:      1,
+	}
+}
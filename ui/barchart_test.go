@@ -0,0 +1,24 @@
+package ui
+
+import "testing"
+
+func TestBarChartCompactValue(t *testing.T) {
+	tests := []struct {
+		value float64
+		width int
+		want  string
+	}{
+		{7, 3, "7"},
+		{1234, 4, "1234"},
+		{1234, 3, "1k"},
+		{15000, 4, "15k"},
+		{3.4e6, 4, "3.4M"},
+		{-1600, 4, "-2k"},
+		{12345678, 1, "…"},
+	}
+	for _, test := range tests {
+		if got := compactValue(test.value, test.width); got != test.want {
+			t.Errorf("compactValue(%v, %d) = %q, want %q", test.value, test.width, got, test.want)
+		}
+	}
+}
@@ -0,0 +1,46 @@
+package ui
+
+import (
+	"fmt"
+	"testing"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+// Measure the frames of a big dashboard, with and without wrapping its panels in Static
+func BenchmarkRender(b *testing.B) {
+	for _, static := range []bool{false, true} {
+		b.Run(fmt.Sprintf("static=%v", static), func(b *testing.B) {
+			screen := osui.NewTestBackend(dashboard(200, 60, 12, static), 200, 60)
+			b.ReportAllocs()
+			for i := 0; i < b.N; i++ {
+				screen.Screen.Render()
+				screen.Frames = screen.Frames[:0]
+			}
+		})
+	}
+}
+
+// A grid of outlined panels full of colored text
+func dashboard(width, height, panels int, static bool) osui.Component {
+	columns := 4
+	rows := (panels + columns - 1) / columns
+	w, h := width/columns, height/max(rows, 1)
+	children := []osui.Component{}
+	for p := 0; p < panels; p++ {
+		lines := []osui.Component{}
+		for l := 0; l < h-2; l++ {
+			lines = append(lines, Text(fmt.Sprintf("%s%d%s panel %d line %d %s", colors.Blue, l, colors.Reset, p, l, colors.Green+"ok"+colors.Reset)))
+		}
+		var panel osui.Component = Div(lines...).Params(DivParams{Width: w, Height: h, Stack: true, Style: DivStyle{Outline: colors.Blue}})
+		if static {
+			panel = Static(panel)
+		}
+		data := panel.GetComponentData()
+		data.X, data.Y = p%columns*w, p/columns*h
+		data.Width, data.Height = w, h
+		children = append(children, panel)
+	}
+	return Div(children...)
+}
@@ -0,0 +1,273 @@
+package ui
+
+import (
+	"bufio"
+	"bytes"
+	"fmt"
+	"os"
+	"strconv"
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+// Glyphs drawn a row at a time, all of them are Height rows high
+type Font struct {
+	Height int
+	glyphs map[rune][]string
+}
+
+// The rows of the text in the font, characters the font doesn't have are drawn as ?
+func (f *Font) Render(text string) []string {
+	rows := make([]string, f.Height)
+	for _, r := range text {
+		glyph, ok := f.glyphs[r]
+		if !ok {
+			glyph = f.glyphs['?']
+		}
+		for i := range rows {
+			if i < len(glyph) {
+				rows[i] += glyph[i]
+			}
+		}
+	}
+	return rows
+}
+
+// The width of the text in the font, without the space after the last character
+func (f *Font) Width(text string) int {
+	w := 0
+	for _, row := range f.Render(text) {
+		w = max(w, osui.VisibleWidth(strings.TrimRight(row, " ")))
+	}
+	return w
+}
+
+// Read a figlet font (.flf), the printable ASCII characters, the German ones and the code tagged ones are loaded
+func ParseFont(data []byte) (*Font, error) {
+	scanner := bufio.NewScanner(bytes.NewReader(data))
+	if !scanner.Scan() {
+		return nil, fmt.Errorf("empty font")
+	}
+	header := strings.Fields(scanner.Text())
+	if len(header) < 6 || !strings.HasPrefix(header[0], "flf2a") || len(header[0]) < 6 {
+		return nil, fmt.Errorf("not a figlet font")
+	}
+	hardblank := header[0][5:6]
+	height, err := strconv.Atoi(header[1])
+	if err != nil || height < 1 {
+		return nil, fmt.Errorf("invalid height %q", header[1])
+	}
+	comments, err := strconv.Atoi(header[5])
+	if err != nil {
+		return nil, fmt.Errorf("invalid comment line count %q", header[5])
+	}
+	line := 1
+	for ; comments > 0 && scanner.Scan(); comments-- {
+		line++
+	}
+	f := &Font{Height: height, glyphs: map[rune][]string{}}
+	glyph := func() ([]string, bool) {
+		rows := make([]string, height)
+		for i := range rows {
+			if !scanner.Scan() {
+				return nil, false
+			}
+			line++
+			row := strings.TrimRight(scanner.Text(), "\r ")
+			if row != "" {
+				row = strings.TrimRight(row, row[len(row)-1:])
+			}
+			rows[i] = strings.ReplaceAll(row, hardblank, " ")
+		}
+		return rows, true
+	}
+	for r := rune(32); r < 127; r++ {
+		rows, ok := glyph()
+		if !ok {
+			return nil, fmt.Errorf("line %d: the font ends before %q", line, r)
+		}
+		f.glyphs[r] = rows
+	}
+	for _, r := range []rune{'Ä', 'Ö', 'Ü', 'ä', 'ö', 'ü', 'ß'} {
+		rows, ok := glyph()
+		if !ok {
+			return f, nil
+		}
+		f.glyphs[r] = rows
+	}
+	for scanner.Scan() {
+		line++
+		fields := strings.Fields(scanner.Text())
+		if len(fields) == 0 {
+			continue
+		}
+		code, err := strconv.ParseInt(fields[0], 0, 32)
+		if err != nil {
+			return nil, fmt.Errorf("line %d: invalid character code %q", line, fields[0])
+		}
+		rows, ok := glyph()
+		if !ok {
+			return nil, fmt.Errorf("line %d: the font ends in character %d", line, code)
+		}
+		f.glyphs[rune(code)] = rows
+	}
+	return f, scanner.Err()
+}
+
+func LoadFont(path string) (*Font, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, err
+	}
+	f, err := ParseFont(data)
+	if err != nil {
+		return nil, fmt.Errorf("%s: %w", path, err)
+	}
+	return f, nil
+}
+
+// 3x5 pixel glyphs, rows are separated by spaces
+var bitmapGlyphs = map[rune]string{
+	'A': ".#. #.# ### #.# #.#", 'B': "##. #.# ##. #.# ##.", 'C': ".## #.. #.. #.. .##",
+	'D': "##. #.# #.# #.# ##.", 'E': "### #.. ##. #.. ###", 'F': "### #.. ##. #.. #..",
+	'G': ".## #.. #.# #.# .##", 'H': "#.# #.# ### #.# #.#", 'I': "### .#. .#. .#. ###",
+	'J': "..# ..# ..# #.# .#.", 'K': "#.# #.# ##. #.# #.#", 'L': "#.. #.. #.. #.. ###",
+	'M': "#.# ### ### #.# #.#", 'N': "##. #.# #.# #.# #.#", 'O': ".#. #.# #.# #.# .#.",
+	'P': "##. #.# ##. #.. #..", 'Q': ".#. #.# #.# ##. .##", 'R': "##. #.# ##. #.# #.#",
+	'S': ".## #.. .#. ..# ##.", 'T': "### .#. .#. .#. .#.", 'U': "#.# #.# #.# #.# ###",
+	'V': "#.# #.# #.# #.# .#.", 'W': "#.# #.# ### ### #.#", 'X': "#.# #.# .#. #.# #.#",
+	'Y': "#.# #.# .#. .#. .#.", 'Z': "### ..# .#. #.. ###",
+	'0': "### #.# #.# #.# ###", '1': ".#. ##. .#. .#. ###", '2': "##. ..# .#. #.. ###",
+	'3': "##. ..# .#. ..# ##.", '4': "#.# #.# ### ..# ..#", '5': "### #.. ##. ..# ##.",
+	'6': ".## #.. ### #.# ###", '7': "### ..# .#. .#. .#.", '8': "### #.# ### #.# ###",
+	'9': "### #.# ### ..# ##.",
+	' ': "... ... ... ... ...", '.': "... ... ... ... .#.", ',': "... ... ... .#. #..",
+	'!': ".#. .#. .#. ... .#.", '?': "##. ..# .#. ... .#.", '-': "... ... ### ... ...",
+	':': "... .#. ... .#. ...", '\'': ".#. .#. ... ... ...", '/': "..# ..# .#. #.. #..",
+	'+': "... .#. ### .#. ...", '=': "... ### ... ### ...", '_': "... ... ... ... ###",
+	'(': "..# .#. .#. .#. ..#", ')': "#.. .#. .#. .#. #..",
+}
+
+// The built-in fonts from the largest to the smallest, lower case letters are drawn in upper case. BigFont draws
+// a pixel with two cells and BlockFont with one, both are 5 rows high. SmallFont puts two pixels in a cell with
+// half blocks and is 3 rows high
+var (
+	BigFont   = bitmapFont(2, false)
+	BlockFont = bitmapFont(1, false)
+	SmallFont = bitmapFont(1, true)
+)
+
+func bitmapFont(scale int, half bool) *Font {
+	f := &Font{Height: osui.LogicValueInt(half, 3, 5), glyphs: map[rune][]string{}}
+	for r, bitmap := range bitmapGlyphs {
+		pixels := strings.Fields(bitmap)
+		pixels = append(pixels, "...")
+		rows := make([]string, f.Height)
+		for i := range rows {
+			var b strings.Builder
+			for x := 0; x < 3; x++ {
+				if half {
+					b.WriteString(halfBlock(pixels[2*i][x] == '#', pixels[2*i+1][x] == '#'))
+				} else {
+					b.WriteString(strings.Repeat(osui.LogicValue(pixels[i][x] == '#', "█", " "), scale))
+				}
+			}
+			// The space between the characters
+			b.WriteString(strings.Repeat(" ", scale))
+			rows[i] = b.String()
+		}
+		f.glyphs[r] = rows
+		if lower := []rune(strings.ToLower(string(r)))[0]; lower != r {
+			f.glyphs[lower] = rows
+		}
+	}
+	return f
+}
+
+func halfBlock(top, bottom bool) string {
+	switch {
+	case top && bottom:
+		return "█"
+	case top:
+		return "▀"
+	case bottom:
+		return "▄"
+	}
+	return " "
+}
+
+type BigTextParams struct {
+	Style  BigTextStyle
+	Width  int
+	Fonts  []*Font
+	Center bool
+}
+
+type BigTextStyle struct {
+	Foreground string `default:"" type:"fg"`
+	Background string `default:"" type:"bg"`
+}
+
+type BigTextComponent struct {
+	Data  osui.ComponentData
+	Style *BigTextStyle
+	Text  string
+	// Tried in order, the first one the text fits in with the width is used. Plain text if none of them fits
+	Fonts []*Font
+	// Center the text in the width
+	Center bool
+}
+
+func (t *BigTextComponent) GetComponentData() *osui.ComponentData {
+	return &t.Data
+}
+
+func (t *BigTextComponent) Render() string {
+	style := osui.StateStyle(&t.Data, t.Style)
+	rows := []string{osui.TruncateEllipsis(t.Text, osui.LogicValueInt(t.Data.Width == 0, len(t.Text), t.Data.Width))}
+	for _, f := range t.Fonts {
+		if t.Data.Width == 0 || f.Width(t.Text) <= t.Data.Width {
+			rows = f.Render(t.Text)
+			break
+		}
+	}
+	width := 0
+	for i, row := range rows {
+		rows[i] = strings.TrimRight(row, " ")
+		width = max(width, osui.VisibleWidth(rows[i]))
+	}
+	pad := ""
+	if t.Center {
+		pad = strings.Repeat(" ", max((t.Data.Width-width)/2, 0))
+	}
+	body := colors.Combine(style.Foreground, style.Background)
+	for i, row := range rows {
+		rows[i] = pad + body + row + colors.Reset + t.Data.DefaultColor
+	}
+	return strings.Join(rows, "\n")
+}
+
+func (t *BigTextComponent) Update(string) bool {
+	return false
+}
+
+func (t *BigTextComponent) Params(param BigTextParams) *BigTextComponent {
+	t.Style = osui.SetDefaults(&param.Style).(*BigTextStyle)
+	t.Data.Width = param.Width
+	if param.Fonts != nil {
+		t.Fonts = param.Fonts
+	}
+	t.Center = param.Center
+	return t
+}
+
+// Text drawn in large letters, like for splash screens and headers. It picks the largest built-in font that fits
+func BigText(text string) *BigTextComponent {
+	return &BigTextComponent{
+		Text:  text,
+		Style: osui.SetDefaults(&BigTextStyle{}).(*BigTextStyle),
+		Fonts: []*Font{BigFont, BlockFont, SmallFont},
+	}
+}
@@ -2,19 +2,24 @@ package ui
 
 import (
 	"fmt"
-	"strings"
 	"time"
 
 	"github.com/orus-dev/osui"
 	"github.com/orus-dev/osui/colors"
-	"github.com/orus-dev/osui/isKey"
 )
 
+var ButtonKeymap = osui.Keymap{
+	"activate": {Keys: []string{"\r", "\n", " "}, Description: "Activate button"},
+}
+
 type ButtonParams struct {
-	Style   ButtonStyle
-	Toggle  bool
-	OnClick func(*ButtonComponent) bool
-	Width   int
+	Style        ButtonStyle
+	Toggle       bool
+	OnClick      func(*ButtonComponent) bool
+	OnMouseEnter func(*ButtonComponent)
+	OnMouseLeave func(*ButtonComponent)
+	Keymap       osui.Keymap
+	Width        int
 }
 
 type ButtonStyle struct {
@@ -34,76 +39,97 @@ type ButtonStyle struct {
 type ButtonComponent struct {
 	Data     osui.ComponentData
 	Style    *ButtonStyle
+	Keymap   osui.Keymap
 	Text     string
 	Toggle   bool
 	Clicked  bool
 	on_click func(*ButtonComponent) bool
+
+	on_mouse_enter func(*ButtonComponent)
+	on_mouse_leave func(*ButtonComponent)
 }
 
 func (b *ButtonComponent) Render() string {
-	osui.UseStyle(b.Style)
+	style := osui.StateStyle(&b.Data, b.Style)
 
 	if b.Clicked {
-		return fmt.Sprintf(" %s\n%s│%s│%s\n %s",
-			colors.Reset+b.Style.ClickedOutline+strings.Repeat("_", b.Data.Width-2)+colors.Reset+b.Data.DefaultColor,
-			colors.Reset+b.Style.ClickedOutline,
-			colors.Reset+colors.Combine(b.Style.ClickedBackground, b.Style.ClickedForeground)+centerText(b.Text, b.Data.Width-2)+colors.Reset+b.Style.ClickedOutline,
-			colors.Reset+b.Data.DefaultColor,
-			colors.Reset+b.Style.ClickedOutline+strings.Repeat("‾", b.Data.Width-2)+colors.Reset+b.Data.DefaultColor,
-		)
+		return b.render(style.ClickedOutline, style.ClickedBackground, style.ClickedForeground)
 	}
 
-	if b.Data.IsActive {
-		return fmt.Sprintf(" %s\n%s│%s│%s\n %s",
-			colors.Reset+b.Style.ActiveOutline+strings.Repeat("_", b.Data.Width-2)+colors.Reset+b.Data.DefaultColor,
-			colors.Reset+b.Style.ActiveOutline,
-			colors.Reset+colors.Combine(b.Style.ActiveBackground, b.Style.ActiveForeground)+centerText(b.Text, b.Data.Width-2)+colors.Reset+b.Style.ActiveOutline,
-			colors.Reset+b.Data.DefaultColor,
-			colors.Reset+b.Style.ActiveOutline+strings.Repeat("‾", b.Data.Width-2)+colors.Reset+b.Data.DefaultColor,
-		)
+	if (b.Data.IsActive || b.Data.Hovered) && !b.Data.Disabled {
+		return b.render(style.ActiveOutline, style.ActiveBackground, style.ActiveForeground)
 	}
 
-	return fmt.Sprintf(" %s\n%s│%s│%s\n %s",
-		colors.Reset+b.Style.Outline+strings.Repeat("_", b.Data.Width-2)+colors.Reset+b.Data.DefaultColor,
-		colors.Reset+b.Style.Outline,
-		colors.Reset+colors.Combine(b.Style.Background, b.Style.Foreground)+centerText(b.Text, b.Data.Width-2)+colors.Reset+b.Style.Outline,
+	return b.render(style.Outline, style.Background, style.Foreground)
+}
+
+func (b *ButtonComponent) render(outline, bg, fg string) string {
+	g := osui.Settings.Glyphs
+	return fmt.Sprintf("%s\n%s%s%s%s\n%s",
+		colors.Reset+outline+g.TopBorder(b.Data.Width)+colors.Reset+b.Data.DefaultColor,
+		colors.Reset+outline+g.Left,
+		colors.Reset+colors.Combine(bg, fg)+centerText(b.Text, b.Data.Width-2)+colors.Reset+outline,
+		g.Right,
 		colors.Reset+b.Data.DefaultColor,
-		colors.Reset+b.Style.Outline+strings.Repeat("‾", b.Data.Width-2)+colors.Reset+b.Data.DefaultColor,
+		colors.Reset+outline+g.BottomBorder(b.Data.Width)+colors.Reset+b.Data.DefaultColor,
 	)
 }
 
 func (b *ButtonComponent) Update(key string) bool {
-	if isKey.Enter(key) {
+	if b.Data.Disabled {
+		return false
+	}
+	if b.Keymap.Is("activate", key) {
 		if !b.on_click(b) {
 			if b.Toggle {
 				b.Clicked = !b.Clicked
 				return false
 			}
-			b.Clicked = true
+			b.Clicked, b.Data.Pressed = true, true
 			b.Data.Screen.Render()
 			time.Sleep(time.Millisecond * 120)
-			b.Clicked = false
+			b.Clicked, b.Data.Pressed = false, false
 		}
 	}
 	return false
 }
 
+func (b *ButtonComponent) OnMouseEnter() {
+	if b.on_mouse_enter != nil {
+		b.on_mouse_enter(b)
+	}
+}
+
+func (b *ButtonComponent) OnMouseLeave() {
+	if b.on_mouse_leave != nil {
+		b.on_mouse_leave(b)
+	}
+}
+
 func (b *ButtonComponent) GetComponentData() *osui.ComponentData {
 	return &b.Data
 }
 
+func (b *ButtonComponent) GetKeymap() osui.Keymap {
+	return b.Keymap
+}
+
 func (b *ButtonComponent) Params(param ButtonParams) *ButtonComponent {
 	b.Style = osui.SetDefaults(&param.Style).(*ButtonStyle)
 	if param.OnClick != nil {
 		b.on_click = param.OnClick
 	}
+	b.on_mouse_enter = param.OnMouseEnter
+	b.on_mouse_leave = param.OnMouseLeave
+	b.Keymap = ButtonKeymap.Merge(param.Keymap)
 	b.Data.Width = osui.LogicValueInt(param.Width == 0, 20, param.Width)
 	return b
 }
 
 func Button(text string) *ButtonComponent {
 	return &ButtonComponent{Text: text,
-		Style: osui.SetDefaults(&ButtonStyle{}).(*ButtonStyle),
+		Style:  osui.SetDefaults(&ButtonStyle{}).(*ButtonStyle),
+		Keymap: ButtonKeymap,
 		Data: osui.ComponentData{
 			Width:  20,
 			Height: 1,
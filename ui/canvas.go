@@ -0,0 +1,135 @@
+package ui
+
+import (
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+type canvasCell struct {
+	char  rune
+	style string
+}
+
+type CanvasComponent struct {
+	Data  osui.ComponentData
+	cells [][]canvasCell
+}
+
+func (c *CanvasComponent) GetComponentData() *osui.ComponentData {
+	return &c.Data
+}
+
+func (c *CanvasComponent) Render() string {
+	c.resize()
+	lines := make([]string, len(c.cells))
+	for y, row := range c.cells {
+		var line strings.Builder
+		style := ""
+		for _, cell := range row {
+			if cell.style != style {
+				line.WriteString(colors.Reset + cell.style)
+				style = cell.style
+			}
+			if cell.char == 0 {
+				line.WriteRune(' ')
+			} else {
+				line.WriteRune(cell.char)
+			}
+		}
+		if style != "" {
+			line.WriteString(colors.Reset)
+		}
+		lines[y] = line.String()
+	}
+	return strings.Join(lines, "\n")
+}
+
+func (c *CanvasComponent) Update(key string) bool {
+	return false
+}
+
+func (c *CanvasComponent) Set(x, y int, char rune, style string) {
+	c.resize()
+	if y >= 0 && y < len(c.cells) && x >= 0 && x < len(c.cells[y]) {
+		c.cells[y][x] = canvasCell{char: char, style: style}
+	}
+}
+
+func (c *CanvasComponent) Get(x, y int) (rune, string) {
+	c.resize()
+	if y >= 0 && y < len(c.cells) && x >= 0 && x < len(c.cells[y]) {
+		return c.cells[y][x].char, c.cells[y][x].style
+	}
+	return 0, ""
+}
+
+func (c *CanvasComponent) Clear() {
+	c.cells = nil
+	c.resize()
+}
+
+func (c *CanvasComponent) Line(x0, y0, x1, y1 int, char rune, style string) {
+	osui.Bresenham(x0, y0, x1, y1, func(x, y int) {
+		c.Set(x, y, char, style)
+	})
+}
+
+func (c *CanvasComponent) Rect(x, y, width, height int, char rune, style string) {
+	c.Line(x, y, x+width-1, y, char, style)
+	c.Line(x, y+height-1, x+width-1, y+height-1, char, style)
+	c.Line(x, y, x, y+height-1, char, style)
+	c.Line(x+width-1, y, x+width-1, y+height-1, char, style)
+}
+
+func (c *CanvasComponent) FillRect(x, y, width, height int, char rune, style string) {
+	for i := y; i < y+height; i++ {
+		for j := x; j < x+width; j++ {
+			c.Set(j, i, char, style)
+		}
+	}
+}
+
+func (c *CanvasComponent) Text(x, y int, text, style string) {
+	for i, r := range []rune(text) {
+		c.Set(x+i, y, r, style)
+	}
+}
+
+// Plot points as braille dots over the whole canvas, merging with braille already drawn
+func (c *CanvasComponent) Points(points []osui.Point, minX, maxX, minY, maxY float64, style string) {
+	c.resize()
+	b := osui.NewBraille(c.Data.Width, c.Data.Height)
+	b.Plot(points, minX, maxX, minY, maxY)
+	for y := 0; y < b.Height; y++ {
+		for x := 0; x < b.Width; x++ {
+			r := b.Cell(x, y)
+			if r == 0 {
+				continue
+			}
+			if old, _ := c.Get(x, y); old >= 0x2800 && old <= 0x28ff {
+				r |= old
+			}
+			c.Set(x, y, r, style)
+		}
+	}
+}
+
+func (c *CanvasComponent) resize() {
+	for len(c.cells) < c.Data.Height {
+		c.cells = append(c.cells, nil)
+	}
+	for y := range c.cells {
+		for len(c.cells[y]) < c.Data.Width {
+			c.cells[y] = append(c.cells[y], canvasCell{})
+		}
+	}
+}
+
+// Create a canvas, a size of 0 takes the size of the parent
+func Canvas(width, height int) *CanvasComponent {
+	c := &CanvasComponent{Data: osui.ComponentData{Width: width, Height: height}}
+	c.resize()
+	return c
+}
@@ -0,0 +1,194 @@
+package ui
+
+import (
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+var CardKeymap = osui.Keymap{
+	"toggle": {Keys: []string{"\x1bc"}, Description: "Collapse or expand"},
+}
+
+type CardParams struct {
+	Style       CardStyle
+	Width       int
+	Height      int
+	Padding     int
+	Collapsible bool
+	Collapsed   bool
+	Keymap      osui.Keymap
+	OnToggle    func(*CardComponent)
+}
+
+type CardStyle struct {
+	Outline          string `default:"" type:"fg"`
+	Title            string `default:"\x1b[1m" type:"fg"`
+	HeaderBackground string `default:"" type:"bg"`
+	Background       string `default:"" type:"bg"`
+	Foreground       string `default:"" type:"fg"`
+	// Drawn in the cells the content doesn't cover, spaces by default
+	Fill string `default:""`
+}
+
+type CardComponent struct {
+	Data    osui.ComponentData
+	Style   *CardStyle
+	Title   string
+	Content *LayoutComponent
+	// Empty rows and columns between the border and the content
+	Padding int
+	// Only collapsible cards react to the toggle key
+	Collapsible bool
+	Collapsed   bool
+	Keymap      osui.Keymap
+	OnToggle    func(*CardComponent)
+	expanded    int
+}
+
+func (c *CardComponent) GetComponentData() *osui.ComponentData {
+	return &c.Data
+}
+
+func (c *CardComponent) GetKeymap() osui.Keymap {
+	return c.Keymap
+}
+
+func (c *CardComponent) GetChildren() []osui.Component {
+	if c.Collapsed {
+		return nil
+	}
+	return []osui.Component{c.Content}
+}
+
+func (c *CardComponent) ChildOffset() (int, int) {
+	return 1 + c.Padding, 1 + c.Padding
+}
+
+// A collapsed card only shows its title bar
+func (c *CardComponent) Render() string {
+	style := osui.StateStyle(&c.Data, c.Style)
+	g := osui.Settings.Glyphs
+	header := c.header(style)
+	if c.Collapsed {
+		return header + "\n" + colors.Reset + style.Outline + g.BottomBorder(c.Data.Width) + colors.Reset + c.Data.DefaultColor
+	}
+	body := colors.Combine(style.Foreground, style.Background)
+	width := max(c.Data.Width-2-2*c.Padding, 0)
+	frame := osui.NewFilledFrame(width, max(c.Data.Height-2-2*c.Padding, 0), style.Fill)
+	data := c.Content.GetComponentData()
+	data.X, data.Y = 0, 0
+	data.Width, data.Height = width, len(frame)
+	data.IsActive = c.Data.IsActive
+	data.DefaultColor = body
+	data.Screen = c.Data.Screen
+	osui.RenderOnFrame(c.Content, &frame)
+
+	pad := strings.Repeat(" ", c.Padding)
+	empty := body + strings.Repeat(" ", max(c.Data.Width-2, 0)) + colors.Reset
+	lines := []string{header}
+	for i := 0; i < c.Padding; i++ {
+		lines = append(lines, style.Outline+g.Left+colors.Reset+empty+style.Outline+g.Right+colors.Reset)
+	}
+	for _, f := range frame {
+		lines = append(lines, style.Outline+g.Left+colors.Reset+osui.WithStyle(pad+f+pad, body)+style.Outline+g.Right+colors.Reset)
+	}
+	for i := 0; i < c.Padding; i++ {
+		lines = append(lines, style.Outline+g.Left+colors.Reset+empty+style.Outline+g.Right+colors.Reset)
+	}
+	lines = append(lines, style.Outline+g.BottomBorder(c.Data.Width)+colors.Reset+c.Data.DefaultColor)
+	return strings.Join(lines, "\n")
+}
+
+// The top border with the title and the collapse indicator in it
+func (c *CardComponent) header(style *CardStyle) string {
+	g := osui.Settings.Glyphs
+	title := c.Title
+	if c.Collapsible {
+		title = osui.LogicValue(c.Collapsed, g.Collapsed, g.Expanded) + " " + title
+	}
+	if title == "" {
+		return colors.Reset + style.Outline + g.TopBorder(c.Data.Width) + colors.Reset + c.Data.DefaultColor
+	}
+	title = osui.TruncateEllipsis(" "+title+" ", max(c.Data.Width-4, 0))
+	rest := max(c.Data.Width-3-osui.VisibleWidth(title), 0)
+	return colors.Reset + style.Outline + g.TopLeft + g.Top + colors.Reset +
+		colors.Combine(style.HeaderBackground, style.Title) + title + colors.Reset +
+		style.Outline + strings.Repeat(g.Top, rest) + g.TopRight + colors.Reset + c.Data.DefaultColor
+}
+
+func (c *CardComponent) Update(key string) bool {
+	if c.Collapsible && c.Keymap.Is("toggle", key) {
+		c.Toggle()
+		return false
+	}
+	if c.Collapsed {
+		return false
+	}
+	c.Content.GetComponentData().IsActive = c.Data.IsActive
+	return c.Content.Update(key)
+}
+
+// Collapse an expanded card or expand a collapsed one
+func (c *CardComponent) Toggle() {
+	c.SetCollapsed(!c.Collapsed)
+}
+
+func (c *CardComponent) SetCollapsed(collapsed bool) {
+	if c.Collapsed == collapsed {
+		return
+	}
+	c.Collapsed = collapsed
+	// A collapsed card is two rows high so the components below it move up
+	if collapsed {
+		c.expanded, c.Data.Height = c.Data.Height, 2
+		c.Data.AddClass("collapsed")
+	} else {
+		c.Data.Height = c.expanded
+		c.Data.RemoveClass("collapsed")
+	}
+	if c.OnToggle != nil {
+		c.OnToggle(c)
+	}
+}
+
+func (c *CardComponent) Params(param CardParams) *CardComponent {
+	c.Style = osui.SetDefaults(&param.Style).(*CardStyle)
+	c.SetCollapsed(false)
+	c.Data.Width = osui.LogicValueInt(param.Width == 0, 30, param.Width)
+	c.Data.Height = osui.LogicValueInt(param.Height == 0, 10, param.Height)
+	c.Padding = param.Padding
+	c.Collapsible = param.Collapsible
+	c.Keymap = CardKeymap.Merge(param.Keymap)
+	c.OnToggle = param.OnToggle
+	c.SetCollapsed(param.Collapsed)
+	return c
+}
+
+// A bordered box with the title in its top border around the body
+func Card(title string, body osui.Component) *CardComponent {
+	return CardSlots(title, Slots{SlotBody: body})
+}
+
+// A card with a header, body and footer inside of its border
+func CardSlots(title string, slots Slots) *CardComponent {
+	return &CardComponent{
+		Title:   title,
+		Content: Layout(slots),
+		Style:   osui.SetDefaults(&CardStyle{}).(*CardStyle),
+		Keymap:  CardKeymap,
+		Data: osui.ComponentData{
+			Width:  30,
+			Height: 10,
+		},
+	}
+}
+
+// A collapsible card with a padded body
+func Panel(title string, body osui.Component) *CardComponent {
+	c := Card(title, body)
+	c.Collapsible = true
+	c.Padding = 1
+	return c
+}
@@ -0,0 +1,257 @@
+package ui
+
+import (
+	"math"
+	"strconv"
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+type Series struct {
+	Name   string
+	Points []osui.Point
+	Style  string
+}
+
+type ChartParams struct {
+	Style  ChartStyle
+	Series []Series
+	// Bounds of the axes, an axis where min and max are equal is fitted to the data
+	MinX, MaxX, MinY, MaxY float64
+	XTicks                 int
+	YTicks                 int
+	XLabel                 string
+	YLabel                 string
+	Legend                 bool
+	Format                 func(v float64) string
+}
+
+type ChartStyle struct {
+	Axis   string `default:"" type:"fg"`
+	Ticks  string `default:"\x1b[2m" type:"fg"`
+	Label  string `default:"" type:"fg"`
+	Legend string `default:"" type:"fg"`
+}
+
+type ChartComponent struct {
+	Data                   osui.ComponentData
+	Style                  *ChartStyle
+	Series                 []Series
+	MinX, MaxX, MinY, MaxY float64
+	XTicks                 int
+	YTicks                 int
+	XLabel                 string
+	YLabel                 string
+	Legend                 bool
+	Format                 func(v float64) string
+}
+
+func (c *ChartComponent) GetComponentData() *osui.ComponentData {
+	return &c.Data
+}
+
+func (c *ChartComponent) Render() string {
+	osui.UseStyle(c.Style)
+	g := osui.Settings.Glyphs
+	minX, maxX, minY, maxY := c.Bounds()
+	format := c.Format
+	if format == nil {
+		format = formatTick
+	}
+
+	yTicks := make([]string, max(c.YTicks, 0))
+	labelWidth := 0
+	for i := range yTicks {
+		yTicks[i] = format(tickValue(minY, maxY, i, len(yTicks)))
+		labelWidth = max(labelWidth, len([]rune(yTicks[i])))
+	}
+
+	lines := []string{}
+	if c.YLabel != "" || c.Legend {
+		lines = append(lines, c.header())
+	}
+	plotWidth := max(c.Data.Width-labelWidth-1, 1)
+	plotHeight := max(c.Data.Height-len(lines)-2-osui.LogicValueInt(c.XLabel != "", 1, 0), 1)
+
+	chars, styles := c.plot(plotWidth, plotHeight, minX, maxX, minY, maxY)
+	tickRows := map[int]string{}
+	for i, t := range yTicks {
+		tickRows[tickPosition(i, len(yTicks), plotHeight-1, true)] = t
+	}
+	for y := 0; y < plotHeight; y++ {
+		var line strings.Builder
+		t, isTick := tickRows[y]
+		line.WriteString(c.Style.Ticks + strings.Repeat(" ", labelWidth-len([]rune(t))) + t + colors.Reset)
+		line.WriteString(c.Style.Axis + osui.LogicValue(isTick, g.AxisTick, g.Vertical) + colors.Reset)
+		style := ""
+		for x := 0; x < plotWidth; x++ {
+			if styles[y][x] != style {
+				line.WriteString(colors.Reset + styles[y][x])
+				style = styles[y][x]
+			}
+			if chars[y][x] == 0 {
+				line.WriteRune(' ')
+			} else {
+				line.WriteRune(chars[y][x])
+			}
+		}
+		line.WriteString(colors.Reset)
+		lines = append(lines, line.String())
+	}
+
+	axis := make([]string, plotWidth)
+	for x := range axis {
+		axis[x] = g.Horizontal
+	}
+	labels := []rune(strings.Repeat(" ", labelWidth+1+plotWidth))
+	xTicks := max(c.XTicks, 0)
+	for i := 0; i < xTicks; i++ {
+		x := tickPosition(i, xTicks, plotWidth-1, false)
+		axis[x] = g.AxisMark
+		text := []rune(format(tickValue(minX, maxX, i, xTicks)))
+		start := min(max(labelWidth+1+x-len(text)/2, 0), len(labels)-len(text))
+		for j, r := range text {
+			if start+j >= 0 {
+				labels[start+j] = r
+			}
+		}
+	}
+	lines = append(lines,
+		c.Style.Axis+strings.Repeat(" ", labelWidth)+g.AxisCorner+strings.Join(axis, "")+colors.Reset,
+		c.Style.Ticks+string(labels)+colors.Reset,
+	)
+	if c.XLabel != "" {
+		lines = append(lines, c.Style.Label+centerText(c.XLabel, labelWidth+1+plotWidth)+colors.Reset)
+	}
+	return strings.Join(lines, "\n")
+}
+
+func (c *ChartComponent) Update(key string) bool {
+	return false
+}
+
+// The bounds used for plotting, fitted to the data where they are not set
+func (c *ChartComponent) Bounds() (minX, maxX, minY, maxY float64) {
+	points := []osui.Point{}
+	for _, s := range c.Series {
+		points = append(points, s.Points...)
+	}
+	minX, maxX, minY, maxY = osui.PointBounds(points)
+	if c.MinX != c.MaxX {
+		minX, maxX = c.MinX, c.MaxX
+	}
+	if c.MinY != c.MaxY {
+		minY, maxY = c.MinY, c.MaxY
+	}
+	if minY == maxY {
+		minY, maxY = minY-1, maxY+1
+	}
+	return minX, maxX, minY, maxY
+}
+
+// Add a series or replace the one with the same name
+func (c *ChartComponent) SetSeries(s Series) {
+	for i := range c.Series {
+		if c.Series[i].Name == s.Name {
+			c.Series[i] = s
+			return
+		}
+	}
+	c.Series = append(c.Series, s)
+}
+
+func (c *ChartComponent) header() string {
+	var legend strings.Builder
+	width := 0
+	if c.Legend {
+		for i, s := range c.Series {
+			if i > 0 {
+				legend.WriteString("  ")
+				width += 2
+			}
+			legend.WriteString(s.Style + "●" + colors.Reset + " " + c.Style.Legend + s.Name + colors.Reset)
+			width += 2 + len([]rune(s.Name))
+		}
+	}
+	label := []rune(c.YLabel)
+	gap := max(c.Data.Width-len(label)-width, 1)
+	return c.Style.Label + string(label) + colors.Reset + strings.Repeat(" ", gap) + legend.String()
+}
+
+// Plot every series on its own braille grid so each cell keeps the style of the last series drawn on it
+func (c *ChartComponent) plot(width, height int, minX, maxX, minY, maxY float64) ([][]rune, [][]string) {
+	chars := make([][]rune, height)
+	styles := make([][]string, height)
+	for y := range chars {
+		chars[y] = make([]rune, width)
+		styles[y] = make([]string, width)
+	}
+	for _, s := range c.Series {
+		b := osui.NewBraille(width, height)
+		points := []osui.Point{}
+		for _, p := range s.Points {
+			if p.X >= minX && p.X <= maxX && p.Y >= minY && p.Y <= maxY {
+				points = append(points, p)
+			}
+		}
+		b.Plot(points, minX, maxX, minY, maxY)
+		for y := 0; y < height; y++ {
+			for x := 0; x < width; x++ {
+				if r := b.Cell(x, y); r != 0 {
+					if chars[y][x] != 0 {
+						r |= chars[y][x]
+					}
+					chars[y][x] = r
+					styles[y][x] = s.Style
+				}
+			}
+		}
+	}
+	return chars, styles
+}
+
+func (c *ChartComponent) Params(param ChartParams) *ChartComponent {
+	c.Style = osui.SetDefaults(&param.Style).(*ChartStyle)
+	if param.Series != nil {
+		c.Series = param.Series
+	}
+	c.MinX, c.MaxX, c.MinY, c.MaxY = param.MinX, param.MaxX, param.MinY, param.MaxY
+	c.XTicks = osui.LogicValueInt(param.XTicks == 0, 5, param.XTicks)
+	c.YTicks = osui.LogicValueInt(param.YTicks == 0, 5, param.YTicks)
+	c.XLabel = param.XLabel
+	c.YLabel = param.YLabel
+	c.Legend = param.Legend
+	c.Format = param.Format
+	return c
+}
+
+func tickValue(from, to float64, i, count int) float64 {
+	if count < 2 {
+		return from
+	}
+	return from + (to-from)*float64(i)/float64(count-1)
+}
+
+// The cell of the i-th tick, counted from the bottom when inverted
+func tickPosition(i, count, size int, inverted bool) int {
+	pos := 0
+	if count > 1 {
+		pos = int(math.Round(float64(i) * float64(size) / float64(count-1)))
+	}
+	return osui.LogicValueInt(inverted, size-pos, pos)
+}
+
+func formatTick(v float64) string {
+	return strconv.FormatFloat(v, 'g', 4, 64)
+}
+
+func Chart(series ...Series) *ChartComponent {
+	return &ChartComponent{
+		Series: series,
+		Style:  osui.SetDefaults(&ChartStyle{}).(*ChartStyle),
+		XTicks: 5,
+		YTicks: 5,
+	}
+}
@@ -0,0 +1,258 @@
+package ui
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+var CodeViewKeymap = osui.Keymap{
+	"up":        {Keys: []string{"\x1b[A"}, Description: "Previous line"},
+	"down":      {Keys: []string{"\x1b[B"}, Description: "Next line"},
+	"left":      {Keys: []string{"\x1b[D"}, Description: "Scroll left"},
+	"right":     {Keys: []string{"\x1b[C"}, Description: "Scroll right"},
+	"page_up":   {Keys: []string{"\x1b[5~"}, Description: "A page up"},
+	"page_down": {Keys: []string{"\x1b[6~"}, Description: "A page down"},
+	"top":       {Keys: []string{"\x1b[H", "\x1b[1~"}, Description: "First line"},
+	"bottom":    {Keys: []string{"\x1b[F", "\x1b[4~"}, Description: "Last line"},
+}
+
+type CodeViewParams struct {
+	Style         CodeViewStyle
+	Width         int
+	Height        int
+	Syntax        *Syntax
+	Highlighter   Highlighter
+	LineNumbers   bool
+	HighlightLine bool
+	Keymap        osui.Keymap
+}
+
+type CodeViewStyle struct {
+	Foreground  string `default:"" type:"fg"`
+	Background  string `default:"" type:"bg"`
+	Keyword     string `default:"\x1b[35m" type:"fg"`
+	Type        string `default:"\x1b[36m" type:"fg"`
+	String      string `default:"\x1b[32m" type:"fg"`
+	Number      string `default:"\x1b[33m" type:"fg"`
+	Comment     string `default:"\x1b[2m" type:"fg"`
+	LineNumber  string `default:"\x1b[2m" type:"fg"`
+	Marker      string `default:"\x1b[31m" type:"fg"`
+	CurrentLine string `default:"\x1b[48;5;236m" type:"bg"`
+}
+
+// Source code with syntax highlighting, line numbers and a gutter for markers like breakpoints
+type CodeViewComponent struct {
+	Data  osui.ComponentData
+	Style *CodeViewStyle
+	Code  string
+	// The built-in highlighter of the language, nil shows plain text
+	Syntax *Syntax
+	// Used instead of the syntax, like to plug in a full highlighter
+	Highlighter Highlighter
+	LineNumbers bool
+	// Draw the current line with the CurrentLine background
+	HighlightLine bool
+	// The current line, from 0
+	Line   int
+	Keymap osui.Keymap
+	// Shown in the gutter left of the line numbers by line, from 0. Only the first character of a marker is shown
+	Markers map[int]string
+	offsetX int
+	offsetY int
+	tokens  [][]Token
+	source  string
+	syntax  *Syntax
+}
+
+func (c *CodeViewComponent) GetComponentData() *osui.ComponentData {
+	return &c.Data
+}
+
+func (c *CodeViewComponent) GetKeymap() osui.Keymap {
+	return c.Keymap
+}
+
+func (c *CodeViewComponent) Render() string {
+	style := osui.StateStyle(&c.Data, c.Style)
+	c.highlight()
+	reset := colors.Reset + c.Data.DefaultColor
+	body := colors.Combine(style.Foreground, style.Background)
+	height := c.Data.Height
+	if height == 0 {
+		height = len(c.tokens)
+	}
+	c.Line = min(max(c.Line, 0), len(c.tokens)-1)
+	if c.Line < c.offsetY {
+		c.offsetY = c.Line
+	} else if c.Line >= c.offsetY+height {
+		c.offsetY = c.Line - height + 1
+	}
+	numbers := len(fmt.Sprint(len(c.tokens)))
+	gutter := 0
+	if len(c.Markers) > 0 {
+		gutter = 2
+	}
+	if c.LineNumbers {
+		gutter += numbers + 1
+	}
+	width := max(c.Data.Width-gutter, 0)
+	kinds := map[TokenKind]string{
+		TokenKeyword: style.Keyword,
+		TokenType:    style.Type,
+		TokenString:  style.String,
+		TokenNumber:  style.Number,
+		TokenComment: style.Comment,
+	}
+	res := []string{}
+	for y := c.offsetY; y < len(c.tokens) && y < c.offsetY+height; y++ {
+		line := body
+		if c.HighlightLine && y == c.Line {
+			line += style.CurrentLine
+		}
+		var b strings.Builder
+		if len(c.Markers) > 0 {
+			mark := " "
+			if m := c.Markers[y]; m != "" {
+				mark = osui.Graphemes(m)[0]
+			}
+			b.WriteString(style.Marker + mark + colors.Reset + line + " ")
+		}
+		if c.LineNumbers {
+			b.WriteString(style.LineNumber + fmt.Sprintf("%*d", numbers, y+1) + colors.Reset + line + " ")
+		}
+		b.WriteString(c.visible(c.tokens[y], kinds, line, width))
+		text := line + b.String()
+		text += strings.Repeat(" ", max(c.Data.Width-osui.VisibleWidth(text), 0))
+		res = append(res, text+reset)
+	}
+	return strings.Join(res, "\n")
+}
+
+// The cells of the tokens that are in view when scrolled to the right
+func (c *CodeViewComponent) visible(tokens []Token, kinds map[TokenKind]string, base string, width int) string {
+	var b strings.Builder
+	col := 0
+	for _, t := range tokens {
+		var text strings.Builder
+		for _, g := range osui.Graphemes(t.Text) {
+			w := osui.GraphemeWidth(g)
+			if col >= c.offsetX && col+w <= c.offsetX+width {
+				text.WriteString(g)
+			}
+			col += w
+		}
+		if text.Len() > 0 {
+			b.WriteString(kinds[t.Kind] + text.String() + colors.Reset + base)
+		}
+		if col >= c.offsetX+width {
+			break
+		}
+	}
+	return b.String()
+}
+
+// Split the code into tokens again when it or the syntax changed, tabs are expanded first
+func (c *CodeViewComponent) highlight() {
+	if c.tokens != nil && c.source == c.Code && c.syntax == c.Syntax {
+		return
+	}
+	c.source, c.syntax = c.Code, c.Syntax
+	code := strings.ReplaceAll(c.Code, "\t", "    ")
+	switch {
+	case c.Highlighter != nil:
+		c.tokens = c.Highlighter(code)
+	case c.Syntax != nil:
+		c.tokens = c.Syntax.Highlight(code)
+	default:
+		c.tokens = [][]Token{}
+		for _, line := range strings.Split(code, "\n") {
+			c.tokens = append(c.tokens, []Token{{Text: line}})
+		}
+	}
+	if len(c.tokens) == 0 {
+		c.tokens = [][]Token{{}}
+	}
+}
+
+func (c *CodeViewComponent) Update(key string) bool {
+	page := max(c.Data.Height-1, 1)
+	switch {
+	case c.Keymap.Is("up", key):
+		c.Scroll(0, -1)
+	case c.Keymap.Is("down", key):
+		c.Scroll(0, 1)
+	case c.Keymap.Is("left", key):
+		c.Scroll(-4, 0)
+	case c.Keymap.Is("right", key):
+		c.Scroll(4, 0)
+	case c.Keymap.Is("page_up", key):
+		c.Scroll(0, -page)
+	case c.Keymap.Is("page_down", key):
+		c.Scroll(0, page)
+	case c.Keymap.Is("top", key):
+		c.Line, c.offsetX = 0, 0
+	case c.Keymap.Is("bottom", key):
+		c.Line = c.LineCount() - 1
+	}
+	return false
+}
+
+// Move the current line by dy and scroll the code horizontally by dx
+func (c *CodeViewComponent) Scroll(dx, dy int) bool {
+	line, offset := c.Line, c.offsetX
+	c.Line = min(max(c.Line+dy, 0), c.LineCount()-1)
+	c.offsetX = max(c.offsetX+dx, 0)
+	return c.Line != line || c.offsetX != offset
+}
+
+func (c *CodeViewComponent) LineCount() int {
+	return strings.Count(c.Code, "\n") + 1
+}
+
+// Replace the code, the view goes back to the top
+func (c *CodeViewComponent) SetCode(code string) {
+	c.Code = code
+	c.Line, c.offsetX, c.offsetY = 0, 0, 0
+}
+
+// Put a marker in the gutter of the line, an empty one removes it
+func (c *CodeViewComponent) SetMarker(line int, marker string) {
+	if marker == "" {
+		delete(c.Markers, line)
+		return
+	}
+	if c.Markers == nil {
+		c.Markers = map[int]string{}
+	}
+	c.Markers[line] = marker
+}
+
+func (c *CodeViewComponent) Params(param CodeViewParams) *CodeViewComponent {
+	c.Style = osui.SetDefaults(&param.Style).(*CodeViewStyle)
+	c.Data.Width = osui.LogicValueInt(param.Width == 0, 80, param.Width)
+	c.Data.Height = param.Height
+	if param.Syntax != nil {
+		c.Syntax = param.Syntax
+	}
+	c.Highlighter = param.Highlighter
+	c.LineNumbers = param.LineNumbers
+	c.HighlightLine = param.HighlightLine
+	c.Keymap = CodeViewKeymap.Merge(param.Keymap)
+	c.tokens = nil
+	return c
+}
+
+// Show the code highlighted with the built-in syntax of the language, like CodeView(src, "go")
+func CodeView(code, language string) *CodeViewComponent {
+	return &CodeViewComponent{
+		Code:        code,
+		Syntax:      Syntaxes[language],
+		LineNumbers: true,
+		Style:       osui.SetDefaults(&CodeViewStyle{}).(*CodeViewStyle),
+		Keymap:      CodeViewKeymap,
+		Data:        osui.ComponentData{Width: 80},
+	}
+}
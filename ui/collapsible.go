@@ -0,0 +1,276 @@
+package ui
+
+import (
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+var CollapsibleKeymap = osui.Keymap{
+	"toggle": {Keys: []string{"\r", "\n", " "}, Description: "Expand or collapse"},
+	"body":   {Keys: []string{"\x1b[B"}, Description: "Go into the section"},
+	"header": {Keys: []string{"\x1b[1;5A"}, Description: "Go back to the header"},
+}
+
+type CollapsibleParams struct {
+	Style    CollapsibleStyle
+	Width    int
+	Expanded bool
+	Keymap   osui.Keymap
+	OnToggle func(*CollapsibleComponent)
+}
+
+type CollapsibleStyle struct {
+	Header    string `default:"\x1b[1m" type:"fg"`
+	Active    string `default:"\x1b[34m" type:"fg"`
+	Indicator string `default:"" type:"fg"`
+}
+
+type CollapsibleComponent struct {
+	Data     osui.ComponentData
+	Style    *CollapsibleStyle
+	Title    string
+	Body     osui.Component
+	Expanded bool
+	Keymap   osui.Keymap
+	OnToggle func(*CollapsibleComponent)
+	// The body gets the keys instead of the header
+	bodyFocused bool
+}
+
+func (c *CollapsibleComponent) GetComponentData() *osui.ComponentData {
+	return &c.Data
+}
+
+func (c *CollapsibleComponent) GetKeymap() osui.Keymap {
+	return c.Keymap
+}
+
+func (c *CollapsibleComponent) GetChildren() []osui.Component {
+	if !c.Expanded || c.Body == nil {
+		return nil
+	}
+	return []osui.Component{c.Body}
+}
+
+func (c *CollapsibleComponent) ChildOffset() (int, int) {
+	return 0, 0
+}
+
+// The header line, followed by the indented body while expanded. The height follows the body
+// so the components stacked below move right away
+func (c *CollapsibleComponent) Render() string {
+	style := osui.StateStyle(&c.Data, c.Style)
+	indicator := osui.LogicValue(c.Expanded, osui.Settings.Glyphs.Expanded, osui.Settings.Glyphs.Collapsed)
+	color := osui.LogicValue(c.Data.IsActive && !c.bodyFocused, style.Active, style.Header)
+	header := style.Indicator + indicator + colors.Reset + c.Data.DefaultColor + " " + color + c.Title + colors.Reset + c.Data.DefaultColor
+	if len(c.GetChildren()) == 0 {
+		return header
+	}
+	data := c.Body.GetComponentData()
+	data.X, data.Y = 2, 1
+	data.Width = max(c.Data.Width-2, 0)
+	data.IsActive = c.Data.IsActive && c.bodyFocused
+	data.DefaultColor = c.Data.DefaultColor
+	data.Screen = c.Data.Screen
+	output := osui.RenderComponent(c.Body)
+	frame := osui.NewFrame(c.Data.Width, 1+osui.LogicValueInt(data.Height == 0, strings.Count(output, "\n")+1, data.Height))
+	osui.DrawOutput(&frame, header, 0, 0)
+	osui.PushClip(osui.Rect{X: data.X, Y: data.Y, Width: data.Width, Height: len(frame) - 1})
+	osui.RenderOutputOnFrame(c.Body, output, &frame)
+	osui.PopClip()
+	return strings.Join(frame, "\n")
+}
+
+// On the header the toggle key expands or collapses and down goes into the body,
+// or on to the next component when there is nothing to go into
+func (c *CollapsibleComponent) Update(key string) bool {
+	if !c.bodyFocused {
+		if c.Keymap.Is("toggle", key) {
+			c.Toggle()
+		} else if c.Keymap.Is("body", key) {
+			if len(c.GetChildren()) == 0 {
+				return true
+			}
+			c.bodyFocused = true
+		}
+		return false
+	}
+	if c.Keymap.Is("header", key) {
+		if osui.CanBlur(c.Body) {
+			c.bodyFocused = false
+		}
+		return false
+	}
+	c.Body.GetComponentData().IsActive = c.Data.IsActive
+	if c.Body.Update(key) {
+		c.bodyFocused = false
+		return true
+	}
+	return false
+}
+
+func (c *CollapsibleComponent) Toggle() {
+	c.SetExpanded(!c.Expanded)
+}
+
+func (c *CollapsibleComponent) SetExpanded(expanded bool) {
+	if c.Expanded == expanded {
+		return
+	}
+	if !expanded && c.bodyFocused {
+		if !osui.CanBlur(c.Body) {
+			return
+		}
+		c.bodyFocused = false
+	}
+	c.Expanded = expanded
+	if c.OnToggle != nil {
+		c.OnToggle(c)
+	}
+}
+
+func (c *CollapsibleComponent) Params(param CollapsibleParams) *CollapsibleComponent {
+	c.Style = osui.SetDefaults(&param.Style).(*CollapsibleStyle)
+	c.Data.Width = osui.LogicValueInt(param.Width == 0, 30, param.Width)
+	c.Expanded = param.Expanded
+	c.Keymap = CollapsibleKeymap.Merge(param.Keymap)
+	c.OnToggle = param.OnToggle
+	return c
+}
+
+// A section with a header that shows or hides the body
+func Collapsible(title string, body osui.Component) *CollapsibleComponent {
+	return &CollapsibleComponent{
+		Title:  title,
+		Body:   body,
+		Style:  osui.SetDefaults(&CollapsibleStyle{}).(*CollapsibleStyle),
+		Keymap: CollapsibleKeymap,
+		Data:   osui.ComponentData{Width: 30},
+	}
+}
+
+var AccordionKeymap = osui.Keymap{
+	"previous": {Keys: []string{"\x1b[A"}, Description: "Previous section"},
+}
+
+type AccordionComponent struct {
+	Data     osui.ComponentData
+	Sections []*CollapsibleComponent
+	Active   int
+	Keymap   osui.Keymap
+}
+
+func (a *AccordionComponent) GetComponentData() *osui.ComponentData {
+	return &a.Data
+}
+
+func (a *AccordionComponent) GetKeymap() osui.Keymap {
+	return a.Keymap
+}
+
+func (a *AccordionComponent) GetChildren() []osui.Component {
+	res := make([]osui.Component, len(a.Sections))
+	for i, s := range a.Sections {
+		res[i] = s
+	}
+	return res
+}
+
+func (a *AccordionComponent) ChildOffset() (int, int) {
+	return 0, 0
+}
+
+func (a *AccordionComponent) Render() string {
+	outputs := make([]string, len(a.Sections))
+	height := 0
+	for i, s := range a.Sections {
+		s.Data.IsActive = a.Data.IsActive && i == a.Active
+		s.Data.Width = a.Data.Width
+		s.Data.DefaultColor = a.Data.DefaultColor
+		s.Data.Screen = a.Data.Screen
+		outputs[i] = osui.RenderComponent(s)
+		s.Data.X, s.Data.Y = 0, height
+		height += strings.Count(outputs[i], "\n") + 1
+	}
+	frame := osui.NewFrame(a.Data.Width, max(height, a.Data.Height))
+	for i, s := range a.Sections {
+		osui.RenderOutputOnFrame(s, outputs[i], &frame)
+	}
+	return strings.Join(frame, "\n")
+}
+
+// Up on a header goes to the section above, a section that is done moves on to the one below
+func (a *AccordionComponent) Update(key string) bool {
+	if len(a.Sections) == 0 {
+		return false
+	}
+	s := a.Sections[a.Active]
+	if !s.bodyFocused && a.Keymap.Is("previous", key) {
+		a.focus(a.Active - 1)
+		return false
+	}
+	s.Data.IsActive = a.Data.IsActive
+	if s.Update(key) {
+		if a.Active == len(a.Sections)-1 {
+			return true
+		}
+		a.focus(a.Active + 1)
+	}
+	return false
+}
+
+// Expand the section and collapse every other one
+func (a *AccordionComponent) Expand(i int) {
+	if i >= 0 && i < len(a.Sections) {
+		a.Sections[i].SetExpanded(true)
+	}
+}
+
+// Get the expanded section, -1 if every section is collapsed
+func (a *AccordionComponent) Expanded() int {
+	for i, s := range a.Sections {
+		if s.Expanded {
+			return i
+		}
+	}
+	return -1
+}
+
+func (a *AccordionComponent) focus(i int) {
+	if i >= 0 && i < len(a.Sections) && osui.Focusable(a.Sections[i]) && osui.CanBlur(a.Sections[a.Active]) {
+		a.Active = i
+	}
+}
+
+// Keep only one section open, opening one collapses the others
+func (a *AccordionComponent) collapseOthers(opened *CollapsibleComponent) {
+	for _, s := range a.Sections {
+		if s != opened {
+			s.SetExpanded(false)
+		}
+	}
+}
+
+// Stack the sections with only one of them expanded at a time
+func Accordion(sections ...*CollapsibleComponent) *AccordionComponent {
+	a := &AccordionComponent{Sections: sections, Keymap: AccordionKeymap, Data: osui.ComponentData{Width: 30}}
+	open := false
+	for _, s := range sections {
+		onToggle := s.OnToggle
+		s.OnToggle = func(c *CollapsibleComponent) {
+			if c.Expanded {
+				a.collapseOthers(c)
+			}
+			if onToggle != nil {
+				onToggle(c)
+			}
+		}
+		if s.Expanded && open {
+			s.Expanded = false
+		}
+		open = open || s.Expanded
+	}
+	return a
+}
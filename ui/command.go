@@ -0,0 +1,92 @@
+package ui
+
+import (
+	"bufio"
+	"errors"
+	"io"
+	"os/exec"
+	"regexp"
+	"strings"
+	"sync"
+
+	"github.com/orus-dev/osui"
+)
+
+// Escape sequences other than colors and text styles, they would move the cursor around the frame
+var nonSgrRe = regexp.MustCompile(`\x1b\[[0-9;?]*[A-Za-ln-z]|\x1b\][^\x07\x1b]*(\x07|\x1b\\)|\x1b[^\[\]]`)
+
+type CommandOptions struct {
+	// Keep the colors and text styles of the output instead of dropping every escape sequence
+	Ansi bool
+	// Called on another goroutine once the command is done with its exit code, -1 if it didn't exit by itself
+	OnExit func(code int, err error)
+}
+
+// Run the command and stream its output into a log view or a paragraph line by line as it comes. In a log view
+// stderr lines are errors. Returns the error of starting it, kill it with cmd.Process
+func StreamCommand(cmd *exec.Cmd, target osui.Component, opts CommandOptions) error {
+	var push func(stderr bool, line string)
+	switch t := target.(type) {
+	case *LogViewComponent:
+		push = func(stderr bool, line string) {
+			level := LevelInfo
+			if stderr {
+				level = LevelError
+			}
+			t.PushLevel(level, line)
+		}
+	case *ParagraphComponent:
+		push = func(_ bool, line string) {
+			if t.Data.Screen != nil {
+				t.Data.Screen.Handle().Do(func() { t.AppendLine(line) })
+			} else {
+				t.AppendLine(line)
+			}
+		}
+	default:
+		return errors.New("output can only be streamed into a log view or a paragraph")
+	}
+	stdout, err := cmd.StdoutPipe()
+	if err != nil {
+		return err
+	}
+	stderr, err := cmd.StderrPipe()
+	if err != nil {
+		return err
+	}
+	if err := cmd.Start(); err != nil {
+		return err
+	}
+	var wg sync.WaitGroup
+	read := func(r io.Reader, isErr bool) {
+		defer wg.Done()
+		scanner := bufio.NewScanner(r)
+		scanner.Buffer(make([]byte, 64*1024), 1024*1024)
+		for scanner.Scan() {
+			line := strings.TrimRight(scanner.Text(), "\r")
+			if opts.Ansi {
+				line = nonSgrRe.ReplaceAllString(line, "")
+			} else {
+				line = osui.StripAnsi(line)
+			}
+			push(isErr, line)
+		}
+	}
+	wg.Add(2)
+	go read(stdout, false)
+	go read(stderr, true)
+	go func() {
+		// The pipes have to be read to the end before Wait closes them
+		wg.Wait()
+		err := cmd.Wait()
+		if opts.OnExit == nil {
+			return
+		}
+		code := -1
+		if cmd.ProcessState != nil {
+			code = cmd.ProcessState.ExitCode()
+		}
+		opts.OnExit(code, err)
+	}()
+	return nil
+}
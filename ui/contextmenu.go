@@ -0,0 +1,274 @@
+package ui
+
+import (
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+var ContextMenuKeymap = osui.Keymap{
+	"open":   {Keys: []string{"\x1b[29~", "\x1b[21;2~"}, Description: "Open the context menu"},
+	"close":  {Keys: []string{"\x1b"}, Description: "Close the context menu"},
+	"up":     {Keys: []string{"\x1b[A"}, Description: "Previous action"},
+	"down":   {Keys: []string{"\x1b[B"}, Description: "Next action"},
+	"select": {Keys: []string{"\r", "\n", "\x1b[C"}, Description: "Run the action or open its submenu"},
+	"back":   {Keys: []string{"\x1b[D"}, Description: "Close the submenu"},
+}
+
+// Opened on top of every other floating component
+const contextMenuZIndex = 1000
+
+type ContextMenuParams struct {
+	Style  ContextMenuStyle
+	Keymap osui.Keymap
+}
+
+type ContextMenuStyle struct {
+	Outline    string `default:"" type:"fg"`
+	Background string `default:"" type:"bg"`
+	Foreground string `default:"" type:"fg"`
+	Selected   string `default:"\x1b[7m" type:"bg"`
+	Disabled   string `default:"\x1b[2m" type:"fg"`
+	Shortcut   string `default:"\x1b[2m" type:"fg"`
+}
+
+type ContextMenuItem struct {
+	Label string
+	// Shown right of the label, the key itself has to be bound elsewhere
+	Shortcut string
+	Disabled bool
+	// Draw a line instead of an action
+	Separator bool
+	// Opened as a submenu instead of running Action
+	Items  []ContextMenuItem
+	Action func()
+}
+
+var MenuSeparator = ContextMenuItem{Separator: true}
+
+type contextMenuLevel struct {
+	Data     osui.ComponentData
+	menu     *ContextMenuComponent
+	items    []ContextMenuItem
+	selected int
+}
+
+type ContextMenuComponent struct {
+	Data   osui.ComponentData
+	Style  *ContextMenuStyle
+	Child  osui.Component
+	Items  []ContextMenuItem
+	Keymap osui.Keymap
+	levels []*contextMenuLevel
+}
+
+func (m *ContextMenuComponent) GetComponentData() *osui.ComponentData {
+	return &m.Data
+}
+
+func (m *ContextMenuComponent) GetKeymap() osui.Keymap {
+	return m.Keymap
+}
+
+func (m *ContextMenuComponent) GetChildren() []osui.Component {
+	res := []osui.Component{m.Child}
+	for _, l := range m.levels {
+		res = append(res, l)
+	}
+	return res
+}
+
+func (m *ContextMenuComponent) ChildOffset() (int, int) {
+	return 0, 0
+}
+
+// The menu and its submenus float above everything, only the edges of the screen cut them
+func (m *ContextMenuComponent) Render() string {
+	osui.UseStyle(m.Style)
+	frame := osui.NewFrame(m.Data.Width, m.Data.Height)
+	data := m.Child.GetComponentData()
+	if data.Width == 0 {
+		data.Width = m.Data.Width
+	}
+	if data.Height == 0 {
+		data.Height = m.Data.Height
+	}
+	data.IsActive = m.Data.IsActive && !m.IsOpen()
+	data.DefaultColor = m.Data.DefaultColor
+	data.Screen = m.Data.Screen
+	osui.RenderOnFrame(m.Child, &frame)
+	for i, l := range m.levels {
+		l.Data.IsActive = m.Data.IsActive && i == len(m.levels)-1
+		l.Data.Screen = m.Data.Screen
+		osui.RenderOnFrame(l, &frame)
+	}
+	return strings.Join(frame, "\n")
+}
+
+func (m *ContextMenuComponent) Update(key string) bool {
+	if !m.IsOpen() {
+		if m.Keymap.Is("open", key) {
+			m.Open(1, 1)
+			return false
+		}
+		m.Child.GetComponentData().IsActive = m.Data.IsActive
+		return m.Child.Update(key)
+	}
+	l := m.levels[len(m.levels)-1]
+	if m.Keymap.Is("close", key) {
+		m.Close()
+	} else if m.Keymap.Is("up", key) {
+		l.move(-1)
+	} else if m.Keymap.Is("down", key) {
+		l.move(1)
+	} else if m.Keymap.Is("back", key) && len(m.levels) > 1 {
+		m.levels = m.levels[:len(m.levels)-1]
+	} else if m.Keymap.Is("select", key) {
+		m.activate(len(m.levels) - 1)
+	}
+	return false
+}
+
+// Right click opens the menu where it was clicked, any other click outside of it closes it
+func (m *ContextMenuComponent) OnClick(e osui.MouseEvent) bool {
+	if e.Button == osui.MouseRight {
+		m.Open(e.X, e.Y)
+		return true
+	}
+	if m.IsOpen() {
+		m.Close()
+		return true
+	}
+	return false
+}
+
+// Open the menu with its top left corner at x, y of the target
+func (m *ContextMenuComponent) Open(x, y int) {
+	m.levels = []*contextMenuLevel{m.newLevel(m.Items, x, y)}
+}
+
+func (m *ContextMenuComponent) Close() {
+	m.levels = nil
+}
+
+func (m *ContextMenuComponent) IsOpen() bool {
+	return len(m.levels) > 0
+}
+
+// Run the selected action of the level or open its submenu
+func (m *ContextMenuComponent) activate(level int) {
+	l := m.levels[level]
+	m.levels = m.levels[:level+1]
+	if l.selected < 0 || l.selected >= len(l.items) {
+		return
+	}
+	item := l.items[l.selected]
+	if item.Disabled || item.Separator {
+		return
+	}
+	if len(item.Items) > 0 {
+		m.levels = append(m.levels, m.newLevel(item.Items, l.Data.X+l.Data.Width-1, l.Data.Y+l.selected))
+		return
+	}
+	m.Close()
+	if item.Action != nil {
+		item.Action()
+	}
+}
+
+func (m *ContextMenuComponent) newLevel(items []ContextMenuItem, x, y int) *contextMenuLevel {
+	width := 0
+	for _, item := range items {
+		w := osui.VisibleWidth(item.Label)
+		if item.Shortcut != "" {
+			w += 2 + osui.VisibleWidth(item.Shortcut)
+		}
+		width = max(width, w)
+	}
+	l := &contextMenuLevel{menu: m, items: items, selected: -1}
+	l.Data = osui.ComponentData{X: x, Y: y, Width: width + 6, Height: len(items) + 2, Floating: true, ZIndex: contextMenuZIndex}
+	l.move(1)
+	return l
+}
+
+func (l *contextMenuLevel) GetComponentData() *osui.ComponentData {
+	return &l.Data
+}
+
+func (l *contextMenuLevel) Update(string) bool {
+	return false
+}
+
+func (l *contextMenuLevel) Render() string {
+	style := l.menu.Style
+	g := osui.Settings.Glyphs
+	width := l.Data.Width - 2
+	body := colors.Combine(style.Foreground, style.Background)
+	lines := []string{style.Outline + g.TopBorder(l.Data.Width) + colors.Reset}
+	for i, item := range l.items {
+		var text string
+		if item.Separator {
+			text = style.Outline + strings.Repeat(g.Horizontal, width) + colors.Reset
+		} else {
+			right := item.Shortcut
+			if len(item.Items) > 0 {
+				right = "›"
+			}
+			gap := max(width-2-osui.VisibleWidth(item.Label)-osui.VisibleWidth(right), 1)
+			color := body
+			if item.Disabled {
+				color += style.Disabled
+			}
+			text = color + " " + item.Label + strings.Repeat(" ", gap) + style.Shortcut + right + colors.Reset + color + " " + colors.Reset
+			if i == l.selected && l.Data.IsActive {
+				text = osui.WithStyle(text, style.Selected)
+			}
+		}
+		lines = append(lines, style.Outline+g.Left+colors.Reset+text+style.Outline+g.Right+colors.Reset)
+	}
+	lines = append(lines, style.Outline+g.BottomBorder(l.Data.Width)+colors.Reset)
+	return strings.Join(lines, "\n")
+}
+
+// Clicking an action runs it or opens its submenu
+func (l *contextMenuLevel) OnClick(e osui.MouseEvent) bool {
+	i := e.Y - 1
+	if e.Button != osui.MouseLeft || i < 0 || i >= len(l.items) {
+		return true
+	}
+	for level, other := range l.menu.levels {
+		if other == l {
+			l.selected = i
+			l.menu.activate(level)
+		}
+	}
+	return true
+}
+
+// Move the selection by step to the next action that can run, wrapping around
+func (l *contextMenuLevel) move(step int) {
+	for n, i := 0, l.selected; n < len(l.items); n++ {
+		i = (i + step + len(l.items)) % len(l.items)
+		if !l.items[i].Separator && !l.items[i].Disabled {
+			l.selected = i
+			return
+		}
+	}
+}
+
+func (m *ContextMenuComponent) Params(param ContextMenuParams) *ContextMenuComponent {
+	m.Style = osui.SetDefaults(&param.Style).(*ContextMenuStyle)
+	m.Keymap = ContextMenuKeymap.Merge(param.Keymap)
+	return m
+}
+
+// Add a menu of actions to the target, opened with the menu key, Shift+F10 or a right click
+func ContextMenu(target osui.Component, items ...ContextMenuItem) *ContextMenuComponent {
+	return &ContextMenuComponent{
+		Child:  target,
+		Items:  items,
+		Style:  osui.SetDefaults(&ContextMenuStyle{}).(*ContextMenuStyle),
+		Keymap: ContextMenuKeymap,
+	}
+}
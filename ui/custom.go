@@ -0,0 +1,75 @@
+package ui
+
+import (
+	"strings"
+
+	"github.com/orus-dev/osui"
+)
+
+type CustomComponent struct {
+	Data    osui.ComponentData
+	View    osui.View
+	current osui.Component
+	dirty   bool
+}
+
+func (c *CustomComponent) GetComponentData() *osui.ComponentData {
+	return &c.Data
+}
+
+func (c *CustomComponent) GetChildren() []osui.Component {
+	return []osui.Component{c.build()}
+}
+
+func (c *CustomComponent) ChildOffset() (int, int) {
+	return 0, 0
+}
+
+func (c *CustomComponent) Render() string {
+	current := c.build()
+	data := current.GetComponentData()
+	data.IsActive = c.Data.IsActive
+	if data.Width == 0 {
+		data.Width = c.Data.Width
+	}
+	if data.Height == 0 {
+		data.Height = c.Data.Height
+	}
+	data.DefaultColor = c.Data.DefaultColor
+	data.Screen = c.Data.Screen
+	frame := osui.NewFrame(c.Data.Width, c.Data.Height)
+	osui.RenderOnFrame(current, &frame)
+	return strings.Join(frame, "\n")
+}
+
+func (c *CustomComponent) Update(key string) bool {
+	return c.build().Update(key)
+}
+
+// Rebuild the view on the next render
+func (c *CustomComponent) Invalidate() {
+	c.dirty = true
+	if c.Data.Screen != nil {
+		c.Data.Screen.RequestRender()
+	}
+}
+
+func (c *CustomComponent) build() osui.Component {
+	if c.current == nil || c.dirty {
+		c.current = c.View.View()
+		c.dirty = false
+	}
+	return c.current
+}
+
+// Rebuild the component whenever the state changes
+func Watch[T any](c *CustomComponent, state *osui.State[T]) *CustomComponent {
+	state.SubscribeFor(c, func(T) {
+		c.dirty = true
+	})
+	return c
+}
+
+func Custom(v osui.View) *CustomComponent {
+	return &CustomComponent{View: v}
+}
@@ -0,0 +1,327 @@
+package ui
+
+import (
+	"encoding/json"
+	"fmt"
+	"log"
+	"os"
+	"reflect"
+	"strings"
+	"time"
+
+	"github.com/orus-dev/osui"
+)
+
+// A component in a UI description, read from JSON like
+//
+//	{"type": "div", "id": "main", "style": "outline: blue", "children": [
+//		{"type": "text", "text": "Name"},
+//		{"type": "input", "id": "name", "props": {"width": 30}}
+//	]}
+//
+// Props set x, y, width, height, z-index, disabled, shadow, dim-behind, transition and direction, or the exported
+// fields of the component by their name in lowercase with dashes, like "on" of a toggle. Callbacks can't be
+// described, find the components by id to set them
+type Node struct {
+	Type     string         `json:"type"`
+	Id       string         `json:"id,omitempty"`
+	Class    string         `json:"class,omitempty"`
+	Text     string         `json:"text,omitempty"`
+	Title    string         `json:"title,omitempty"`
+	Style    string         `json:"style,omitempty"`
+	Props    map[string]any `json:"props,omitempty"`
+	Children []Node         `json:"children,omitempty"`
+}
+
+// Builds the component of a node from the components of its children
+type Builder func(n Node, children []osui.Component) (osui.Component, error)
+
+// The builders by node type, add to it to describe your own components
+var Builders = map[string]Builder{
+	"div": func(n Node, children []osui.Component) (osui.Component, error) {
+		return Div(children...), nil
+	},
+	"text": func(n Node, _ []osui.Component) (osui.Component, error) {
+		return Text(n.Text), nil
+	},
+	"paragraph": func(n Node, _ []osui.Component) (osui.Component, error) {
+		return Paragraph(n.Text), nil
+	},
+	"bigtext": func(n Node, _ []osui.Component) (osui.Component, error) {
+		return BigText(n.Text), nil
+	},
+	"button": func(n Node, _ []osui.Component) (osui.Component, error) {
+		return Button(n.Text), nil
+	},
+	"input": func(n Node, _ []osui.Component) (osui.Component, error) {
+		input := InputBox(256)
+		input.InputData = n.Text
+		return input, nil
+	},
+	"toggle": func(n Node, _ []osui.Component) (osui.Component, error) {
+		return Toggle(n.Text, false), nil
+	},
+	"select": func(n Node, _ []osui.Component) (osui.Component, error) {
+		return Select(), nil
+	},
+	"multiselect": func(n Node, _ []osui.Component) (osui.Component, error) {
+		return MultiSelect(), nil
+	},
+	"link": func(n Node, _ []osui.Component) (osui.Component, error) {
+		return Link(n.Text, ""), nil
+	},
+	"menu": func(n Node, _ []osui.Component) (osui.Component, error) {
+		return Menu(), nil
+	},
+	"inspector": func(n Node, _ []osui.Component) (osui.Component, error) {
+		return Inspector(json.RawMessage(n.Text)), nil
+	},
+	"gauge": func(n Node, _ []osui.Component) (osui.Component, error) {
+		return Gauge(0), nil
+	},
+	"form": func(n Node, children []osui.Component) (osui.Component, error) {
+		return Form(children...), nil
+	},
+	"paginator": func(n Node, children []osui.Component) (osui.Component, error) {
+		return Paginator(children...), nil
+	},
+	"card": func(n Node, children []osui.Component) (osui.Component, error) {
+		return Card(n.Title, single(children)), nil
+	},
+	"panel": func(n Node, children []osui.Component) (osui.Component, error) {
+		return Panel(n.Title, single(children)), nil
+	},
+	"collapsible": func(n Node, children []osui.Component) (osui.Component, error) {
+		return Collapsible(n.Title, single(children)), nil
+	},
+	"static": func(n Node, children []osui.Component) (osui.Component, error) {
+		return Static(single(children)), nil
+	},
+	"split": func(n Node, children []osui.Component) (osui.Component, error) {
+		if len(children) != 2 {
+			return nil, fmt.Errorf("a split needs 2 children, got %d", len(children))
+		}
+		return Split(children[0], children[1]), nil
+	},
+	"search": func(n Node, children []osui.Component) (osui.Component, error) {
+		list, ok := single(children).(Filterable)
+		if len(children) != 1 || !ok {
+			return nil, fmt.Errorf("a search needs one list to filter, like a menu or a tree")
+		}
+		return SearchableList(list), nil
+	},
+	"tabs": func(n Node, children []osui.Component) (osui.Component, error) {
+		tabs := make([]TabItem, len(children))
+		for i, c := range children {
+			tabs[i] = Tab(n.Children[i].Title, c)
+		}
+		return Tabs(tabs...), nil
+	},
+}
+
+// One child as it is, several in a div
+func single(children []osui.Component) osui.Component {
+	if len(children) == 1 {
+		return children[0]
+	}
+	return Div(children...)
+}
+
+// Build the component tree of a JSON description
+func FromJSON(data []byte) (osui.Component, error) {
+	var n Node
+	if err := json.Unmarshal(data, &n); err != nil {
+		return nil, err
+	}
+	return Build(n)
+}
+
+func LoadUI(path string) (osui.Component, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, err
+	}
+	c, err := FromJSON(data)
+	if err != nil {
+		return nil, fmt.Errorf("%s: %w", path, err)
+	}
+	return c, nil
+}
+
+// Build the component of the node and its children, errors name the path to the node like div[0] > form
+func Build(n Node) (osui.Component, error) {
+	builder, ok := Builders[n.Type]
+	if !ok {
+		return nil, fmt.Errorf("%s: unknown type", n.Type)
+	}
+	children := make([]osui.Component, len(n.Children))
+	for i, child := range n.Children {
+		c, err := Build(child)
+		if err != nil {
+			return nil, fmt.Errorf("%s[%d] > %w", n.Type, i, err)
+		}
+		children[i] = c
+	}
+	c, err := builder(n, children)
+	if err != nil {
+		return nil, fmt.Errorf("%s: %w", n.Type, err)
+	}
+	data := c.GetComponentData()
+	data.Id = n.Id
+	for _, class := range strings.Fields(n.Class) {
+		data.AddClass(class)
+	}
+	for key, value := range n.Props {
+		if err := setProp(c, key, value); err != nil {
+			return nil, fmt.Errorf("%s: %s: %w", n.Type, key, err)
+		}
+	}
+	if n.Style != "" {
+		if err := osui.ApplyStyle(c, n.Style); err != nil {
+			return nil, fmt.Errorf("%s: style %w", n.Type, err)
+		}
+	}
+	return c, nil
+}
+
+// Set a prop on the data of the component or an exported field of the same name
+func setProp(c osui.Component, key string, value any) error {
+	data := c.GetComponentData()
+	switch key {
+	case "x", "y", "width", "height", "z-index":
+		n, ok := value.(float64)
+		if !ok {
+			return fmt.Errorf("expected a number")
+		}
+		switch key {
+		case "x":
+			data.X = int(n)
+		case "y":
+			data.Y = int(n)
+		case "width":
+			data.Width = int(n)
+		case "height":
+			data.Height = int(n)
+		case "z-index":
+			data.ZIndex = int(n)
+		}
+		return nil
+	case "disabled", "shadow", "dim-behind":
+		b, ok := value.(bool)
+		if !ok {
+			return fmt.Errorf("expected true or false")
+		}
+		switch key {
+		case "disabled":
+			data.Disabled = b
+		case "shadow":
+			data.Shadow = b
+		case "dim-behind":
+			data.DimBehind = b
+		}
+		return nil
+	case "transition":
+		s, _ := value.(string)
+		d, err := time.ParseDuration(s)
+		if err != nil {
+			return fmt.Errorf("expected a duration like 200ms")
+		}
+		data.Transition = d
+		return nil
+	case "direction":
+		s, _ := value.(string)
+		dir, err := osui.ParseDirection(s)
+		if err != nil {
+			return err
+		}
+		data.Direction = dir
+		return nil
+	}
+	v := reflect.ValueOf(c)
+	for v.Kind() == reflect.Ptr {
+		v = v.Elem()
+	}
+	name := strings.ReplaceAll(key, "-", "")
+	for i := 0; i < v.NumField(); i++ {
+		if f := v.Field(i); strings.ToLower(v.Type().Field(i).Name) == name && f.CanSet() {
+			return setValue(f, value)
+		}
+	}
+	return fmt.Errorf("unknown prop")
+}
+
+// Set a field from a decoded JSON value
+func setValue(f reflect.Value, value any) error {
+	switch f.Kind() {
+	case reflect.String:
+		if s, ok := value.(string); ok {
+			f.SetString(s)
+			return nil
+		}
+	case reflect.Bool:
+		if b, ok := value.(bool); ok {
+			f.SetBool(b)
+			return nil
+		}
+	case reflect.Int, reflect.Int8, reflect.Int16, reflect.Int32, reflect.Int64:
+		if n, ok := value.(float64); ok {
+			f.SetInt(int64(n))
+			return nil
+		}
+	case reflect.Uint, reflect.Uint8, reflect.Uint16, reflect.Uint32, reflect.Uint64:
+		if n, ok := value.(float64); ok && n >= 0 {
+			f.SetUint(uint64(n))
+			return nil
+		}
+	case reflect.Float32, reflect.Float64:
+		if n, ok := value.(float64); ok {
+			f.SetFloat(n)
+			return nil
+		}
+	case reflect.Slice:
+		items, ok := value.([]any)
+		if !ok {
+			break
+		}
+		res := reflect.MakeSlice(f.Type(), len(items), len(items))
+		for i, item := range items {
+			if err := setValue(res.Index(i), item); err != nil {
+				return err
+			}
+		}
+		f.Set(res)
+		return nil
+	}
+	return fmt.Errorf("can't set %v on a %s", value, f.Type())
+}
+
+// Rebuild the root of the screen from the description whenever the file changes, for iterating on a layout
+// while the app runs. A description that doesn't build keeps the current tree and its error goes to onError,
+// nil logs it. setup runs on every new tree, like to set the callbacks
+func WatchUI(s *osui.Screen, path string, setup func(osui.Component), onError func(error)) (stop func(), err error) {
+	c, err := LoadUI(path)
+	if err != nil {
+		return nil, err
+	}
+	show := func(c osui.Component) {
+		if setup != nil {
+			setup(c)
+		}
+		s.SetComponent(c)
+	}
+	show(c)
+	return osui.WatchFile(path, func() {
+		c, err := LoadUI(path)
+		if err != nil {
+			if onError != nil {
+				onError(err)
+			} else {
+				log.Printf("osui: %v", err)
+			}
+			return
+		}
+		s.Handle().Do(func() {
+			show(c)
+		})
+	}), nil
+}
@@ -0,0 +1,335 @@
+package ui
+
+import (
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+	"github.com/orus-dev/osui/isKey"
+)
+
+var DialogKeymap = osui.Keymap{
+	"cancel":   {Keys: []string{"\x1b"}, Description: "Close the dialog"},
+	"next":     {Keys: []string{"\t", "\x1b[C"}, Description: "Next button"},
+	"previous": {Keys: []string{"\x1b[Z", "\x1b[D"}, Description: "Previous button"},
+	"confirm":  {Keys: []string{"\r", "\n"}, Description: "Press the selected button"},
+}
+
+type DialogParams struct {
+	Style   DialogStyle
+	Width   int
+	Buttons []string
+	Keymap  osui.Keymap
+	OnClose func(*DialogComponent, int)
+}
+
+type DialogStyle struct {
+	Outline    string `default:"" type:"fg"`
+	Title      string `default:"\x1b[1m" type:"fg"`
+	Background string `default:"" type:"bg"`
+	Foreground string `default:"" type:"fg"`
+	Button     string `default:"" type:"fg"`
+	Selected   string `default:"\x1b[7m" type:"bg"`
+}
+
+// A bordered box with a message, an optional component like an input box and a row of buttons. Open it with
+// Screen.OpenModal or run it on its own with RunDialog
+type DialogComponent struct {
+	Data    osui.ComponentData
+	Style   *DialogStyle
+	Title   string
+	Message string
+	// Gets the keys instead of the buttons, the dialog closes with 0 once its Update returns true
+	Content  osui.Component
+	Buttons  []string
+	Selected int
+	Keymap   osui.Keymap
+	// Called with the index of the pressed button, -1 when it was cancelled
+	OnClose func(*DialogComponent, int)
+	// The result it closed with, -1 until then
+	Result int
+	closed bool
+	// The row of the buttons in the last output
+	buttonRow int
+}
+
+func (d *DialogComponent) GetComponentData() *osui.ComponentData {
+	return &d.Data
+}
+
+func (d *DialogComponent) GetKeymap() osui.Keymap {
+	return d.Keymap
+}
+
+// The height follows the message and the content, only the width is taken from the data
+func (d *DialogComponent) Render() string {
+	style := osui.StateStyle(&d.Data, d.Style)
+	g := osui.Settings.Glyphs
+	width := max(d.Data.Width, 10)
+	inner := width - 4
+	body := colors.Combine(style.Foreground, style.Background)
+	rows := []string{}
+	if d.Message != "" {
+		for _, spans := range wrapSpans([]span{{text: d.Message}}, inner) {
+			var line strings.Builder
+			for _, s := range spans {
+				line.WriteString(s.text)
+			}
+			rows = append(rows, body+line.String())
+		}
+	}
+	if d.Content != nil {
+		if len(rows) > 0 {
+			rows = append(rows, "")
+		}
+		data := d.Content.GetComponentData()
+		if data.Width == 0 || data.Width > inner {
+			data.Width = inner
+		}
+		data.IsActive = d.Data.IsActive
+		data.DefaultColor = body
+		data.Screen = d.Data.Screen
+		rows = append(rows, strings.Split(osui.RenderComponent(d.Content), "\n")...)
+	}
+	if len(d.Buttons) > 0 {
+		if len(rows) > 0 {
+			rows = append(rows, "")
+		}
+		rows = append(rows, d.buttons(style, body, inner))
+	}
+	d.buttonRow = len(rows)
+
+	lines := []string{d.header(style, width)}
+	for _, row := range rows {
+		row = osui.TruncateVisible(row, inner)
+		pad := strings.Repeat(" ", max(inner-osui.VisibleWidth(row), 0))
+		lines = append(lines, style.Outline+g.Left+colors.Reset+osui.WithStyle(" "+row+pad+" ", body)+style.Outline+g.Right+colors.Reset)
+	}
+	lines = append(lines, style.Outline+g.BottomBorder(width)+colors.Reset+d.Data.DefaultColor)
+	return strings.Join(lines, "\n")
+}
+
+// The top border with the title in it
+func (d *DialogComponent) header(style *DialogStyle, width int) string {
+	g := osui.Settings.Glyphs
+	if d.Title == "" {
+		return style.Outline + g.TopBorder(width) + colors.Reset
+	}
+	title := osui.TruncateEllipsis(" "+d.Title+" ", width-4)
+	rest := max(width-3-osui.VisibleWidth(title), 0)
+	return style.Outline + g.TopLeft + g.Top + colors.Reset + style.Title + title + colors.Reset +
+		style.Outline + strings.Repeat(g.Top, rest) + g.TopRight + colors.Reset
+}
+
+// The buttons right aligned, the selected one is highlighted while the dialog is active
+func (d *DialogComponent) buttons(style *DialogStyle, body string, width int) string {
+	res, used := "", 0
+	for i, b := range d.Buttons {
+		label := style.Button + "[ " + b + " ]" + colors.Reset + body
+		if i == d.Selected && d.Content == nil && d.Data.IsActive {
+			label = osui.WithStyle(label, style.Selected)
+		}
+		if i > 0 {
+			res += " "
+			used++
+		}
+		res += label
+		used += osui.VisibleWidth(b) + 4
+	}
+	return strings.Repeat(" ", max(width-used, 0)) + res
+}
+
+// Without content the arrows and Tab move between the buttons and the first letter of a button presses it
+func (d *DialogComponent) Update(key string) bool {
+	if d.Keymap.Is("cancel", key) {
+		d.Close(-1)
+		return true
+	}
+	if d.Content != nil {
+		d.Content.GetComponentData().IsActive = d.Data.IsActive
+		if d.Content.Update(key) {
+			// The content may have cancelled it already
+			d.Close(0)
+			return true
+		}
+		return false
+	}
+	if len(d.Buttons) == 0 {
+		if d.Keymap.Is("confirm", key) {
+			d.Close(0)
+			return true
+		}
+		return false
+	}
+	switch {
+	case d.Keymap.Is("next", key):
+		d.Selected = (d.Selected + 1) % len(d.Buttons)
+	case d.Keymap.Is("previous", key):
+		d.Selected = (d.Selected + len(d.Buttons) - 1) % len(d.Buttons)
+	case d.Keymap.Is("confirm", key):
+		d.Close(d.Selected)
+		return true
+	case isKey.Printable(key):
+		for i, b := range d.Buttons {
+			if strings.HasPrefix(strings.ToLower(b), strings.ToLower(key)) {
+				d.Close(i)
+				return true
+			}
+		}
+	}
+	return false
+}
+
+// Pass pasted text on to the content
+func (d *DialogComponent) Paste(text string) {
+	if p, ok := d.Content.(osui.Paster); ok {
+		p.Paste(text)
+	}
+}
+
+// Clicking a button presses it
+func (d *DialogComponent) OnClick(e osui.MouseEvent) bool {
+	if e.Button != osui.MouseLeft || len(d.Buttons) == 0 || d.Content != nil {
+		return true
+	}
+	if e.Y != d.buttonRow {
+		return true
+	}
+	x := d.Data.Width - 2
+	for i := len(d.Buttons) - 1; i >= 0; i-- {
+		start := x - osui.VisibleWidth(d.Buttons[i]) - 4
+		if e.X >= start && e.X < x {
+			d.Close(i)
+			if d.Data.Screen != nil {
+				d.Data.Screen.CloseModal(d)
+			}
+			return true
+		}
+		x = start - 1
+	}
+	return true
+}
+
+// Set the result and call OnClose, only the first call counts. The screen closes the modal once Update returns true
+func (d *DialogComponent) Close(result int) {
+	if d.closed {
+		return
+	}
+	d.closed = true
+	d.Result = result
+	if d.OnClose != nil {
+		d.OnClose(d, result)
+	}
+}
+
+func (d *DialogComponent) Params(param DialogParams) *DialogComponent {
+	d.Style = osui.SetDefaults(&param.Style).(*DialogStyle)
+	d.Data.Width = osui.LogicValueInt(param.Width == 0, 40, param.Width)
+	if param.Buttons != nil {
+		d.Buttons = param.Buttons
+	}
+	d.Keymap = DialogKeymap.Merge(param.Keymap)
+	d.OnClose = param.OnClose
+	return d
+}
+
+func Dialog(title, message string, buttons ...string) *DialogComponent {
+	return &DialogComponent{
+		Title:   title,
+		Message: message,
+		Buttons: buttons,
+		Result:  -1,
+		Style:   osui.SetDefaults(&DialogStyle{}).(*DialogStyle),
+		Keymap:  DialogKeymap,
+		Data:    osui.ComponentData{Width: 40, Shadow: true, DimBehind: true},
+	}
+}
+
+// Show the dialog on its own screen until it closes and return its result, for scripts without an app
+func RunDialog(d *DialogComponent) int {
+	s := osui.NewScreen(Div())
+	onClose := d.OnClose
+	d.OnClose = func(d *DialogComponent, result int) {
+		if onClose != nil {
+			onClose(d, result)
+		}
+		s.Handle().Exit()
+	}
+	s.OpenModal(d)
+	s.Run()
+	return d.Result
+}
+
+// Ask a yes or no question, Esc answers no
+func Confirm(question string) bool {
+	return RunDialog(confirmDialog(question)) == 0
+}
+
+// Ask for a line of text, ok is false when it was cancelled
+func InputPrompt(label string) (text string, ok bool) {
+	d, input := inputDialog(label)
+	ok = RunDialog(d) == 0
+	return input.InputData, ok
+}
+
+// Pick one of the items, ok is false when it was cancelled
+func SelectPrompt(label string, items ...string) (index int, ok bool) {
+	d, menu := selectDialog(label, items)
+	ok = RunDialog(d) == 0
+	return menu.SelectedItem, ok
+}
+
+// Ask a yes or no question over the running app, done gets the answer on the ui loop
+func ConfirmModal(s *osui.Screen, question string, done func(bool)) *DialogComponent {
+	d := confirmDialog(question)
+	d.OnClose = func(_ *DialogComponent, result int) {
+		done(result == 0)
+	}
+	s.OpenModal(d)
+	return d
+}
+
+// Ask for a line of text over the running app, done gets it on the ui loop
+func InputModal(s *osui.Screen, label string, done func(text string, ok bool)) *DialogComponent {
+	d, input := inputDialog(label)
+	d.OnClose = func(_ *DialogComponent, result int) {
+		done(input.InputData, result == 0)
+	}
+	s.OpenModal(d)
+	return d
+}
+
+// Pick one of the items over the running app, done gets the index on the ui loop
+func SelectModal(s *osui.Screen, label string, items []string, done func(index int, ok bool)) *DialogComponent {
+	d, menu := selectDialog(label, items)
+	d.OnClose = func(_ *DialogComponent, result int) {
+		done(menu.SelectedItem, result == 0)
+	}
+	s.OpenModal(d)
+	return d
+}
+
+func confirmDialog(question string) *DialogComponent {
+	return Dialog("", question, "Yes", "No")
+}
+
+// The box fills the inside of the dialog, its border included
+func inputDialog(label string) (*DialogComponent, *InputBoxComponent) {
+	d := Dialog("", label)
+	input := InputBox(uint(d.Data.Width - 6))
+	d.Content = input
+	return d, input
+}
+
+// Esc or q in the menu cancel the dialog
+func selectDialog(label string, items []string) (*DialogComponent, *MenuComponent) {
+	menu := Menu(items...)
+	d := Dialog("", label)
+	d.Content = menu
+	menu.OnSelected = func(_ *MenuComponent, ok bool) {
+		if !ok {
+			d.Close(-1)
+		}
+	}
+	return d, menu
+}
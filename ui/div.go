@@ -12,12 +12,15 @@ type DivParams struct {
 	Style  DivStyle
 	Width  int
 	Height int
+	Stack  bool
 }
 
 type DivStyle struct {
 	Background string `default:"" type:"bg"`
 	Foreground string `default:"" type:"fg"`
 	Outline    string `default:"" type:"fg"`
+	// Drawn in the cells no child covers, like a shade, spaces by default
+	Fill string `default:""`
 }
 
 type DivComponent struct {
@@ -25,15 +28,29 @@ type DivComponent struct {
 	Style           *DivStyle
 	Components      []osui.Component
 	ActiveComponent int
+	// Lay the children out from top to bottom instead of by their position
+	Stack bool
 }
 
 func (d *DivComponent) GetComponentData() *osui.ComponentData {
 	return &d.Data
 }
 
+func (d *DivComponent) GetChildren() []osui.Component {
+	return d.Components
+}
+
+func (d *DivComponent) ChildOffset() (int, int) {
+	if d.Style.Outline == "" {
+		return 0, 0
+	}
+	return 1, 1
+}
+
 func (d *DivComponent) Render() string {
-	osui.UseStyle(d.Style)
-	frame := osui.NewFrame(d.Data.Width-2, d.Data.Height-2)
+	style := osui.StateStyle(&d.Data, d.Style)
+	frame := osui.NewFilledFrame(d.Data.Width-2, d.Data.Height-2, style.Fill)
+	y := 0
 	for i, c := range d.Components {
 		data := c.GetComponentData()
 		if i == d.ActiveComponent {
@@ -44,24 +61,38 @@ func (d *DivComponent) Render() string {
 		if data.Width == 0 {
 			data.Width = d.Data.Width - 2
 		}
-		if data.Height == 0 {
+		if data.Height == 0 && !d.Stack {
 			data.Height = d.Data.Height - 2
 		}
-		data.DefaultColor = colors.Combine(d.Style.Background, d.Style.Foreground)
+		data.DefaultColor = colors.Combine(style.Background, style.Foreground)
 		data.Screen = d.Data.Screen
-		osui.RenderOnFrame(c, &frame)
+		if d.Stack {
+			output := osui.RenderComponent(c)
+			data.Y = y
+			height := osui.LogicValueInt(data.Height == 0, strings.Count(output, "\n")+1, data.Height)
+			y += height
+			osui.PushClip(osui.Rect{X: data.X, Y: data.Y, Width: data.Width, Height: height})
+			osui.RenderOutputOnFrame(c, output, &frame)
+		} else {
+			osui.PushClip(osui.Rect{X: data.X, Y: data.Y, Width: data.Width, Height: data.Height})
+			osui.RenderOnFrame(c, &frame)
+		}
+		osui.PopClip()
 	}
-	if d.Style.Outline == "" {
+	g := osui.Settings.Glyphs
+	// The background goes on again after the resets of the children, so it covers the whole area
+	body := colors.Combine(style.Foreground, style.Background)
+	if style.Outline == "" {
 		for i, f := range frame {
-			frame[i] = colors.Combine(d.Style.Foreground, d.Style.Background) + f + colors.Reset
+			frame[i] = osui.WithStyle(f, body)
 		}
 		return strings.Join(frame, "\n")
 	} else {
 		for i, f := range frame {
-			frame[i] = d.Style.Outline + "│" + colors.Reset + colors.Combine(d.Style.Foreground, d.Style.Background) + f + colors.Reset + d.Style.Outline + "│" + colors.Reset
+			frame[i] = style.Outline + g.Left + colors.Reset + osui.WithStyle(f, body) + style.Outline + g.Right + colors.Reset
 		}
 	}
-	return " " + d.Style.Outline + strings.Repeat("_", d.Data.Width-2) + colors.Reset + "\n" + strings.Join(frame, "\n") + "\n " + d.Style.Outline + strings.Repeat("‾", d.Data.Width-2) + colors.Reset
+	return style.Outline + g.TopBorder(d.Data.Width) + colors.Reset + "\n" + strings.Join(frame, "\n") + "\n" + style.Outline + g.BottomBorder(d.Data.Width) + colors.Reset
 }
 
 func (d *DivComponent) Update(key string) bool {
@@ -77,11 +108,14 @@ func (d *DivComponent) Update(key string) bool {
 		if len(d.Components) > 0 {
 			d.Components[d.ActiveComponent].GetComponentData().IsActive = d.Data.IsActive
 			if d.Components[d.ActiveComponent].Update(key) {
-				if d.ActiveComponent < len(d.Components)-1 {
-					d.updateActive(d.ActiveComponent + 1)
-				} else {
+				next := d.ActiveComponent + 1
+				for next < len(d.Components) && !osui.Focusable(d.Components[next]) {
+					next++
+				}
+				if next == len(d.Components) {
 					return true
 				}
+				d.updateActive(next)
 			}
 		}
 	}
@@ -91,11 +125,86 @@ func (d *DivComponent) Update(key string) bool {
 func (b *DivComponent) Params(param DivParams) *DivComponent {
 	b.Style = osui.SetDefaults(&param.Style).(*DivStyle)
 	b.Data.Width = osui.LogicValueInt(param.Width == 0, 20, param.Width)
+	b.Stack = param.Stack
 	return b
 }
 
+func (d *DivComponent) PushChild(c osui.Component) {
+	d.Components = append(d.Components, c)
+}
+
+func (d *DivComponent) InsertChild(i int, c osui.Component) {
+	i = min(max(i, 0), len(d.Components))
+	d.Components = append(d.Components[:i], append([]osui.Component{c}, d.Components[i:]...)...)
+	if i <= d.ActiveComponent && len(d.Components) > 1 {
+		d.ActiveComponent++
+	}
+}
+
+// Remove the child with the given id, returns false if there is none
+func (d *DivComponent) RemoveChild(id string) bool {
+	for i, c := range d.Components {
+		if c.GetComponentData().Id == id {
+			d.RemoveChildAt(i)
+			return true
+		}
+	}
+	return false
+}
+
+func (d *DivComponent) RemoveChildAt(i int) {
+	if i < 0 || i >= len(d.Components) {
+		return
+	}
+	d.Components = append(d.Components[:i], d.Components[i+1:]...)
+	if i < d.ActiveComponent || d.ActiveComponent >= len(d.Components) {
+		d.ActiveComponent = max(d.ActiveComponent-1, 0)
+	}
+}
+
+func (d *DivComponent) ClearChildren() {
+	d.Components = nil
+	d.ActiveComponent = 0
+}
+
+// Rebuild the children from items, children with a matching key are reused so they keep their state
+func Reconcile[T any](d *DivComponent, items []T, key func(T) string, build func(T) osui.Component) {
+	existing := map[string]osui.Component{}
+	for _, c := range d.Components {
+		if k := c.GetComponentData().Key; k != "" {
+			existing[k] = c
+		}
+	}
+	activeKey := ""
+	if d.ActiveComponent < len(d.Components) {
+		activeKey = d.Components[d.ActiveComponent].GetComponentData().Key
+	}
+
+	children := make([]osui.Component, 0, len(items))
+	active := min(d.ActiveComponent, max(len(items)-1, 0))
+	for _, item := range items {
+		k := key(item)
+		c, ok := existing[k]
+		if ok {
+			delete(existing, k)
+		} else {
+			c = build(item)
+			c.GetComponentData().Key = k
+		}
+		if k != "" && k == activeKey {
+			active = len(children)
+		}
+		children = append(children, c)
+	}
+	d.Components = children
+	d.ActiveComponent = active
+}
+
 func (d *DivComponent) updateActive(newIndex int) {
-	if newIndex >= 0 && newIndex < len(d.Components) && len(d.Components) > 0 {
+	if newIndex >= 0 && newIndex < len(d.Components) && osui.Focusable(d.Components[newIndex]) {
+		if newIndex != d.ActiveComponent && !osui.CanBlur(d.Components[d.ActiveComponent]) {
+			return
+		}
 		d.ActiveComponent = newIndex
 	}
 }
@@ -0,0 +1,248 @@
+package ui
+
+import (
+	"fmt"
+	"reflect"
+	"strconv"
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+var FormKeymap = osui.Keymap{
+	"next":     {Keys: []string{"\t"}, Description: "Next field"},
+	"previous": {Keys: []string{"\x1b[Z"}, Description: "Previous field"},
+}
+
+type FormParams struct {
+	Style    FormStyle
+	Width    int
+	Gap      int
+	Keymap   osui.Keymap
+	OnSubmit func(*FormComponent, map[string]string)
+	OnError  func(*FormComponent, []osui.FieldError)
+}
+
+type FormStyle struct {
+	Background string `default:"" type:"bg"`
+	Foreground string `default:"" type:"fg"`
+}
+
+type FormComponent struct {
+	Data        osui.ComponentData
+	Style       *FormStyle
+	Fields      []osui.Component
+	ActiveField int
+	Keymap      osui.Keymap
+	// Empty rows between the fields
+	Gap int
+	// Called with the values by id once every field is valid
+	OnSubmit func(*FormComponent, map[string]string)
+	// Called with the failed fields when a submit is rejected
+	OnError func(*FormComponent, []osui.FieldError)
+}
+
+func (f *FormComponent) GetComponentData() *osui.ComponentData {
+	return &f.Data
+}
+
+func (f *FormComponent) GetKeymap() osui.Keymap {
+	return f.Keymap
+}
+
+func (f *FormComponent) GetChildren() []osui.Component {
+	return f.Fields
+}
+
+func (f *FormComponent) ChildOffset() (int, int) {
+	return 0, 0
+}
+
+func (f *FormComponent) Render() string {
+	osui.UseStyle(f.Style)
+	style := colors.Combine(f.Style.Foreground, f.Style.Background)
+	outputs := make([]string, len(f.Fields))
+	heights := make([]int, len(f.Fields))
+	height := 0
+	for i, c := range f.Fields {
+		data := c.GetComponentData()
+		data.IsActive = f.Data.IsActive && i == f.ActiveField
+		data.DefaultColor = style
+		data.Screen = f.Data.Screen
+		if i > 0 {
+			height += f.Gap
+		}
+		outputs[i] = osui.RenderComponent(c)
+		data.X, data.Y = 0, height
+		heights[i] = osui.LogicValueInt(data.Height == 0, strings.Count(outputs[i], "\n")+1, data.Height)
+		height += heights[i]
+	}
+	frame := osui.NewFrame(f.Data.Width, height)
+	for i, c := range f.Fields {
+		data := c.GetComponentData()
+		osui.PushClip(osui.Rect{X: 0, Y: data.Y, Width: f.Data.Width, Height: heights[i]})
+		osui.RenderOutputOnFrame(c, outputs[i], &frame)
+		osui.PopClip()
+	}
+	for i := range frame {
+		frame[i] = osui.WithStyle(frame[i], style) + f.Data.DefaultColor
+	}
+	return strings.Join(frame, "\n")
+}
+
+func (f *FormComponent) Update(key string) bool {
+	if f.Keymap.Is("next", key) {
+		f.focus(f.ActiveField, 1)
+	} else if f.Keymap.Is("previous", key) {
+		f.focus(f.ActiveField, -1)
+	} else if f.ActiveField < len(f.Fields) && f.Fields[f.ActiveField].Update(key) {
+		if !f.focus(f.ActiveField, 1) {
+			return f.Submit()
+		}
+	}
+	return false
+}
+
+// Validate the fields and call OnSubmit with their values, returns whether the form was valid
+func (f *FormComponent) Submit() bool {
+	if errs := osui.ValidateAll(f); len(errs) > 0 {
+		if f.OnError != nil {
+			f.OnError(f, errs)
+		}
+		// Focus the field with the first error
+		for i, c := range f.Fields {
+			found := false
+			osui.Walk(c, func(d osui.Component) bool {
+				found = d == errs[0].Component
+				return !found
+			})
+			if found {
+				f.ActiveField = i
+				break
+			}
+		}
+		return false
+	}
+	if f.OnSubmit != nil {
+		f.OnSubmit(f, f.Values())
+	}
+	return true
+}
+
+// Reset every field and focus the first one
+func (f *FormComponent) Reset() {
+	for _, c := range f.Fields {
+		osui.Walk(c, func(c osui.Component) bool {
+			if r, ok := c.(osui.Resetter); ok {
+				r.Reset()
+			}
+			return true
+		})
+	}
+	f.ActiveField = 0
+	f.focus(-1, 1)
+}
+
+// Get the values of the fields with an id
+func (f *FormComponent) Values() map[string]string {
+	res := map[string]string{}
+	osui.Walk(f, func(c osui.Component) bool {
+		if v, ok := c.(osui.Valuer); ok && c.GetComponentData().Id != "" {
+			res[c.GetComponentData().Id] = v.Value()
+		}
+		return true
+	})
+	return res
+}
+
+// Fill the fields of the struct v points to from the values, a field takes the value of the id in its
+// form tag or of its name in lower case, strings, numbers and bools are supported
+func (f *FormComponent) Decode(v any) error {
+	val := reflect.ValueOf(v)
+	if val.Kind() != reflect.Ptr || val.Elem().Kind() != reflect.Struct {
+		return fmt.Errorf("Decode: expected a pointer to a struct")
+	}
+	values := f.Values()
+	val = val.Elem()
+	typ := val.Type()
+	for i := 0; i < val.NumField(); i++ {
+		structField := typ.Field(i)
+		id, ok := structField.Tag.Lookup("form")
+		if !ok {
+			id = strings.ToLower(structField.Name)
+		}
+		value, ok := values[id]
+		if !ok || !structField.IsExported() {
+			continue
+		}
+		field := val.Field(i)
+		switch field.Kind() {
+		case reflect.String:
+			field.SetString(value)
+		case reflect.Int, reflect.Int8, reflect.Int16, reflect.Int32, reflect.Int64:
+			n, err := strconv.ParseInt(value, 10, 64)
+			if err != nil {
+				return fmt.Errorf("Decode: field %s: %w", id, err)
+			}
+			field.SetInt(n)
+		case reflect.Uint, reflect.Uint8, reflect.Uint16, reflect.Uint32, reflect.Uint64:
+			n, err := strconv.ParseUint(value, 10, 64)
+			if err != nil {
+				return fmt.Errorf("Decode: field %s: %w", id, err)
+			}
+			field.SetUint(n)
+		case reflect.Float32, reflect.Float64:
+			n, err := strconv.ParseFloat(value, 64)
+			if err != nil {
+				return fmt.Errorf("Decode: field %s: %w", id, err)
+			}
+			field.SetFloat(n)
+		case reflect.Bool:
+			b, err := strconv.ParseBool(value)
+			if err != nil {
+				return fmt.Errorf("Decode: field %s: %w", id, err)
+			}
+			field.SetBool(b)
+		}
+	}
+	return nil
+}
+
+func (f *FormComponent) Params(param FormParams) *FormComponent {
+	f.Style = osui.SetDefaults(&param.Style).(*FormStyle)
+	f.Data.Width = osui.LogicValueInt(param.Width == 0, 30, param.Width)
+	f.Gap = param.Gap
+	f.Keymap = FormKeymap.Merge(param.Keymap)
+	f.OnSubmit = param.OnSubmit
+	f.OnError = param.OnError
+	return f
+}
+
+// Move the focus from the field by step to the next field that takes input, text labels are skipped.
+// Returns false if there is none in that direction
+func (f *FormComponent) focus(from, step int) bool {
+	for i := from + step; i >= 0 && i < len(f.Fields); i += step {
+		if _, isText := f.Fields[i].(*TextComponent); isText || !osui.Focusable(f.Fields[i]) {
+			continue
+		}
+		if from >= 0 && from < len(f.Fields) && !osui.CanBlur(f.Fields[from]) {
+			return true
+		}
+		f.ActiveField = i
+		return true
+	}
+	return false
+}
+
+// Group fields like inputs and buttons, Tab and Enter move through them and confirming the last one submits
+func Form(fields ...osui.Component) *FormComponent {
+	f := &FormComponent{
+		Fields: fields,
+		Style:  osui.SetDefaults(&FormStyle{}).(*FormStyle),
+		Keymap: FormKeymap,
+		Data:   osui.ComponentData{Width: 30},
+	}
+	f.focus(-1, 1)
+	return f
+}
@@ -0,0 +1,62 @@
+package ui
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/osuitest"
+)
+
+func TestFormSubmit(t *testing.T) {
+	name, email := InputBox(20), InputBox(20)
+	name.Data.Id, email.Data.Id = "name", "email"
+	var values map[string]string
+	form := Form(Text("Sign up"), name, email).Params(FormParams{
+		OnSubmit: func(_ *FormComponent, v map[string]string) {
+			values = v
+		},
+	})
+	s := osuitest.New(t, form, 30, 10).
+		ExpectContains("Sign up").
+		Type("ada").
+		Press(osuitest.KeyEnter)
+	if form.ActiveField != 2 || values != nil {
+		t.Fatalf("Enter in the first field should move to the next one, the active field is %d", form.ActiveField)
+	}
+	s.Type("ada@example.com").
+		ExpectContains("ada@example.com").
+		Press(osuitest.KeyEnter).
+		ExpectDone()
+	if values["name"] != "ada" || values["email"] != "ada@example.com" {
+		t.Fatalf("got the values %v", values)
+	}
+}
+
+func TestFormRejectsInvalidFields(t *testing.T) {
+	name := InputBox(20)
+	name.Data.Id = "name"
+	name.Validator = func(s string) error {
+		if s == "" {
+			return errors.New("required")
+		}
+		return nil
+	}
+	submitted := false
+	form := Form(name).Params(FormParams{
+		OnSubmit: func(*FormComponent, map[string]string) {
+			submitted = true
+		},
+	})
+	b := osui.NewTestBackend(form, 30, 10)
+	b.Render()
+	b.Press("\r")
+	if submitted || b.Done() || form.Submit() {
+		t.Fatal("the form was submitted with an empty required field")
+	}
+	b.Type("ada")
+	b.Press("\r")
+	if !submitted || !b.Done() {
+		t.Fatal("the form wasn't submitted once the field was valid")
+	}
+}
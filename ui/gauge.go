@@ -0,0 +1,135 @@
+package ui
+
+import (
+	"fmt"
+	"math"
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+// The color used for values below the limit
+type GaugeThreshold struct {
+	Below float64
+	Color string
+}
+
+type GaugeParams struct {
+	Style      GaugeStyle
+	Width      int
+	Thresholds []GaugeThreshold
+	Label      func(value float64) string
+	Bind       *osui.State[float64]
+}
+
+type GaugeStyle struct {
+	Filled     string `default:"\x1b[31m" type:"fg"`
+	Label      string `default:"" type:"fg"`
+	Background string `default:"" type:"bg"`
+}
+
+type GaugeComponent struct {
+	Data osui.ComponentData
+	// In [0, 1]
+	Value      float64
+	Style      *GaugeStyle
+	Thresholds []GaugeThreshold
+	Label      func(value float64) string
+}
+
+var DefaultGaugeThresholds = []GaugeThreshold{
+	{Below: 0.7, Color: colors.Green},
+	{Below: 0.9, Color: colors.Yellow},
+}
+
+var partialBlocks = []rune(" ▏▎▍▌▋▊▉█")
+
+func (g *GaugeComponent) GetComponentData() *osui.ComponentData {
+	return &g.Data
+}
+
+func (g *GaugeComponent) Render() string {
+	osui.UseStyle(g.Style)
+	width := max(g.Data.Width, 1)
+	value := math.Min(math.Max(g.Value, 0), 1)
+	color := g.color(value)
+	eighths := int(math.Round(value * float64(width*8)))
+
+	var label []rune
+	if g.Label != nil {
+		label = []rune(g.Label(value))
+	} else {
+		label = []rune(fmt.Sprintf("%d%%", int(math.Round(value*100))))
+	}
+	start := (width - len(label)) / 2
+
+	height := max(g.Data.Height, 1)
+	lines := make([]string, height)
+	for y := range lines {
+		var line strings.Builder
+		for x := 0; x < width; x++ {
+			fill := min(max(eighths-x*8, 0), 8)
+			// A gradient fill fades across the whole width
+			cell := colors.GradientAt(color, float64(x)/float64(max(width-1, 1)))
+			line.WriteString(colors.Reset + g.Style.Background)
+			if y == height/2 && x >= start && x-start < len(label) {
+				// Text over the filled part swaps the colors so it stays readable
+				line.WriteString(osui.LogicValue(fill >= 4, colors.Reverse+cell, g.Style.Label) + string(label[x-start]))
+			} else {
+				line.WriteString(cell + string(partialBlocks[fill]))
+			}
+		}
+		lines[y] = line.String() + colors.Reset + g.Data.DefaultColor
+	}
+	return strings.Join(lines, "\n")
+}
+
+func (g *GaugeComponent) Update(key string) bool {
+	return false
+}
+
+func (g *GaugeComponent) SetProgress(percent float64) {
+	g.Value = percent
+}
+
+// Show the value of the state, updated whenever it changes
+func (g *GaugeComponent) Bind(state *osui.State[float64]) *GaugeComponent {
+	g.Value = state.Get()
+	state.SubscribeFor(g, func(v float64) {
+		g.Value = v
+	})
+	return g
+}
+
+func (g *GaugeComponent) Params(param GaugeParams) *GaugeComponent {
+	g.Style = osui.SetDefaults(&param.Style).(*GaugeStyle)
+	g.Data.Width = osui.LogicValueInt(param.Width == 0, 20, param.Width)
+	if param.Thresholds != nil {
+		g.Thresholds = param.Thresholds
+	}
+	g.Label = param.Label
+	if param.Bind != nil {
+		g.Bind(param.Bind)
+	}
+	return g
+}
+
+// The color of the first threshold above the value, the filled style if there is none
+func (g *GaugeComponent) color(value float64) string {
+	for _, t := range g.Thresholds {
+		if value < t.Below {
+			return t.Color
+		}
+	}
+	return g.Style.Filled
+}
+
+func Gauge(value float64) *GaugeComponent {
+	return &GaugeComponent{
+		Value:      value,
+		Style:      osui.SetDefaults(&GaugeStyle{}).(*GaugeStyle),
+		Thresholds: DefaultGaugeThresholds,
+		Data:       osui.ComponentData{Width: 20, Height: 1},
+	}
+}
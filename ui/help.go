@@ -0,0 +1,178 @@
+package ui
+
+import (
+	"sort"
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+var HelpOverlayKeymap = osui.Keymap{
+	"toggle": {Keys: []string{"?"}, Description: "Show or hide the keybindings"},
+	"close":  {Keys: []string{"\x1b"}, Description: "Close the keybindings"},
+}
+
+type HelpOverlayParams struct {
+	Style  HelpOverlayStyle
+	Title  string
+	Keymap osui.Keymap
+}
+
+type HelpOverlayStyle struct {
+	Outline     string `default:"" type:"fg"`
+	Background  string `default:"" type:"bg"`
+	Title       string `default:"\x1b[1m" type:"fg"`
+	Key         string `default:"\x1b[36m" type:"fg"`
+	Description string `default:"" type:"fg"`
+}
+
+type HelpOverlayComponent struct {
+	Data    osui.ComponentData
+	Style   *HelpOverlayStyle
+	Child   osui.Component
+	Title   string
+	Keymap  osui.Keymap
+	Visible bool
+}
+
+func (h *HelpOverlayComponent) GetComponentData() *osui.ComponentData {
+	return &h.Data
+}
+
+func (h *HelpOverlayComponent) GetKeymap() osui.Keymap {
+	return h.Keymap
+}
+
+func (h *HelpOverlayComponent) GetChildren() []osui.Component {
+	return []osui.Component{h.Child}
+}
+
+func (h *HelpOverlayComponent) ChildOffset() (int, int) {
+	return 0, 0
+}
+
+func (h *HelpOverlayComponent) Render() string {
+	osui.UseStyle(h.Style)
+	frame := osui.NewFrame(h.Data.Width, h.Data.Height)
+	data := h.Child.GetComponentData()
+	if data.Width == 0 {
+		data.Width = h.Data.Width
+	}
+	if data.Height == 0 {
+		data.Height = h.Data.Height
+	}
+	data.IsActive = h.Data.IsActive
+	data.DefaultColor = h.Data.DefaultColor
+	data.Screen = h.Data.Screen
+	osui.RenderOnFrame(h.Child, &frame)
+	if h.Visible {
+		box := h.renderBox()
+		lines := strings.Split(box, "\n")
+		width := osui.VisibleWidth(lines[0])
+		osui.DrawOutput(&frame, box, (h.Data.Width-width)/2, (h.Data.Height-len(lines))/2)
+	}
+	return strings.Join(frame, "\n")
+}
+
+func (h *HelpOverlayComponent) Update(key string) bool {
+	if h.Keymap.Is("toggle", key) && (h.Visible || !h.typing()) {
+		h.Visible = !h.Visible
+		return false
+	}
+	if h.Visible {
+		if h.Keymap.Is("close", key) {
+			h.Visible = false
+		}
+		return false
+	}
+	return h.Child.Update(key)
+}
+
+// Whether the focused component takes text like an input box or captures the keys, the toggle key is left to it then
+func (h *HelpOverlayComponent) typing() bool {
+	if h.Data.Screen == nil {
+		return false
+	}
+	focused := h.Data.Screen.Focused()
+	if c, ok := focused.(osui.KeyCapturer); ok && c.CapturesKeys() {
+		return true
+	}
+	_, ok := focused.(osui.Paster)
+	return ok
+}
+
+func (h *HelpOverlayComponent) Params(param HelpOverlayParams) *HelpOverlayComponent {
+	h.Style = osui.SetDefaults(&param.Style).(*HelpOverlayStyle)
+	if param.Title != "" {
+		h.Title = param.Title
+	}
+	h.Keymap = HelpOverlayKeymap.Merge(param.Keymap)
+	return h
+}
+
+// The bindings of the focused components, the deepest one first
+func (h *HelpOverlayComponent) bindings() [][2]string {
+	keymaps := []osui.Keymap{h.Keymap}
+	if h.Data.Screen != nil {
+		keymaps = h.Data.Screen.ActiveKeymaps()
+	}
+	res := [][2]string{}
+	seen := map[string]bool{}
+	for i := len(keymaps) - 1; i >= 0; i-- {
+		actions := make([]string, 0, len(keymaps[i]))
+		for action := range keymaps[i] {
+			actions = append(actions, action)
+		}
+		sort.Strings(actions)
+		for _, action := range actions {
+			b := keymaps[i][action]
+			names := make([]string, len(b.Keys))
+			for j, k := range b.Keys {
+				names[j] = osui.KeyName(k)
+			}
+			keys := strings.Join(names, "/")
+			if len(b.Keys) == 0 || seen[keys] {
+				continue
+			}
+			seen[keys] = true
+			res = append(res, [2]string{keys, osui.LogicValue(b.Description == "", action, b.Description)})
+		}
+	}
+	return res
+}
+
+func (h *HelpOverlayComponent) renderBox() string {
+	bindings := h.bindings()
+	keyWidth, descWidth := 0, osui.VisibleWidth(h.Title)
+	for _, b := range bindings {
+		keyWidth = max(keyWidth, osui.VisibleWidth(b[0]))
+		descWidth = max(descWidth, osui.VisibleWidth(b[1]))
+	}
+	inner := min(keyWidth+2+descWidth, max(h.Data.Width-4, 1))
+	g := osui.Settings.Glyphs
+	row := func(content string) string {
+		content = osui.TruncateVisible(content, inner)
+		return h.Style.Outline + g.Left + colors.Reset + h.Style.Background + " " + content + strings.Repeat(" ", inner-osui.VisibleWidth(content)) + " " + colors.Reset + h.Style.Outline + g.Right + colors.Reset
+	}
+	lines := []string{
+		h.Style.Outline + g.TopBorder(inner+4) + colors.Reset,
+		row(h.Style.Title + h.Title + colors.Reset + h.Style.Background),
+	}
+	for _, b := range bindings {
+		key := b[0] + strings.Repeat(" ", keyWidth-osui.VisibleWidth(b[0]))
+		lines = append(lines, row(h.Style.Key+key+colors.Reset+h.Style.Background+"  "+h.Style.Description+b[1]+colors.Reset+h.Style.Background))
+	}
+	lines = append(lines, h.Style.Outline+g.BottomBorder(inner+4)+colors.Reset)
+	return strings.Join(lines, "\n")
+}
+
+// Wrap the component so the toggle key shows the keybindings of the focused components over it
+func HelpOverlay(child osui.Component) *HelpOverlayComponent {
+	return &HelpOverlayComponent{
+		Child:  child,
+		Title:  "Keybindings",
+		Style:  osui.SetDefaults(&HelpOverlayStyle{}).(*HelpOverlayStyle),
+		Keymap: HelpOverlayKeymap,
+	}
+}
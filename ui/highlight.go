@@ -0,0 +1,195 @@
+package ui
+
+import (
+	"path/filepath"
+	"strings"
+	"unicode"
+)
+
+type TokenKind int
+
+const (
+	TokenText TokenKind = iota
+	TokenKeyword
+	TokenType
+	TokenString
+	TokenNumber
+	TokenComment
+)
+
+// A run of code of one kind, the code view colors it by its kind
+type Token struct {
+	Text string
+	Kind TokenKind
+}
+
+// Split code into the tokens of each of its lines. Plug in a full highlighter with it, the built-in syntaxes only
+// know keywords, strings, numbers and comments
+type Highlighter func(code string) [][]Token
+
+// The words and delimiters of a language for the built-in highlighter
+type Syntax struct {
+	Keywords []string
+	Types    []string
+	// Like // or #, empty if the language has none
+	LineComment  string
+	BlockComment [2]string
+	// The characters strings start and end with, a backslash escapes them
+	Quotes string
+}
+
+var (
+	goSyntax = &Syntax{
+		Keywords:     strings.Fields("break case chan const continue default defer else fallthrough for func go goto if import interface map package range return select struct switch type var nil true false iota"),
+		Types:        strings.Fields("bool byte complex64 complex128 error float32 float64 int int8 int16 int32 int64 rune string uint uint8 uint16 uint32 uint64 uintptr any"),
+		LineComment:  "//",
+		BlockComment: [2]string{"/*", "*/"},
+		Quotes:       "\"'`",
+	}
+	rustSyntax = &Syntax{
+		Keywords:     strings.Fields("as async await break const continue crate dyn else enum extern false fn for if impl in let loop match mod move mut pub ref return self Self static struct super trait true type unsafe use where while"),
+		Types:        strings.Fields("bool char f32 f64 i8 i16 i32 i64 i128 isize str u8 u16 u32 u64 u128 usize String Vec Option Result Box"),
+		LineComment:  "//",
+		BlockComment: [2]string{"/*", "*/"},
+		Quotes:       "\"",
+	}
+	cSyntax = &Syntax{
+		Keywords:     strings.Fields("break case const continue default do else enum extern for goto if inline return sizeof static struct switch typedef union volatile while NULL true false class namespace public private protected template new delete this"),
+		Types:        strings.Fields("auto bool char double float int long short signed unsigned void size_t"),
+		LineComment:  "//",
+		BlockComment: [2]string{"/*", "*/"},
+		Quotes:       "\"'",
+	}
+	jsSyntax = &Syntax{
+		Keywords:     strings.Fields("async await break case catch class const continue default delete do else export extends false finally for from function if import in instanceof let new null return static super switch this throw true try typeof undefined var void while yield interface type enum implements"),
+		Types:        strings.Fields("any boolean number string object symbol unknown never void"),
+		LineComment:  "//",
+		BlockComment: [2]string{"/*", "*/"},
+		Quotes:       "\"'`",
+	}
+	pythonSyntax = &Syntax{
+		Keywords:    strings.Fields("and as assert async await break class continue def del elif else except False finally for from global if import in is lambda None nonlocal not or pass raise return True try while with yield self"),
+		Types:       strings.Fields("bool bytes dict float int list object set str tuple"),
+		LineComment: "#",
+		Quotes:      "\"'",
+	}
+	shellSyntax = &Syntax{
+		Keywords:    strings.Fields("case do done elif else esac export fi for function if in local return then until while echo exit set unset"),
+		LineComment: "#",
+		Quotes:      "\"'",
+	}
+	jsonSyntax = &Syntax{
+		Keywords: strings.Fields("true false null"),
+		Quotes:   "\"",
+	}
+)
+
+// The built-in syntaxes by language name
+var Syntaxes = map[string]*Syntax{
+	"go":         goSyntax,
+	"rust":       rustSyntax,
+	"c":          cSyntax,
+	"cpp":        cSyntax,
+	"javascript": jsSyntax,
+	"typescript": jsSyntax,
+	"python":     pythonSyntax,
+	"shell":      shellSyntax,
+	"json":       jsonSyntax,
+}
+
+var syntaxExtensions = map[string]string{
+	".go": "go", ".rs": "rust", ".c": "c", ".h": "c", ".cpp": "cpp", ".cc": "cpp", ".hpp": "cpp",
+	".js": "javascript", ".mjs": "javascript", ".jsx": "javascript", ".ts": "typescript", ".tsx": "typescript",
+	".py": "python", ".sh": "shell", ".bash": "shell", ".zsh": "shell", ".json": "json",
+}
+
+// The built-in syntax for the file by its extension, nil if there is none
+func SyntaxFor(path string) *Syntax {
+	return Syntaxes[syntaxExtensions[strings.ToLower(filepath.Ext(path))]]
+}
+
+// Split the code into tokens, block comments can span lines while strings end with the line
+func (s *Syntax) Highlight(code string) [][]Token {
+	keywords := map[string]TokenKind{}
+	for _, k := range s.Keywords {
+		keywords[k] = TokenKeyword
+	}
+	for _, t := range s.Types {
+		keywords[t] = TokenType
+	}
+	res := [][]Token{}
+	comment := false
+	for _, line := range strings.Split(code, "\n") {
+		tokens := []Token{}
+		add := func(text string, kind TokenKind) {
+			if n := len(tokens); n > 0 && tokens[n-1].Kind == kind {
+				tokens[n-1].Text += text
+			} else if text != "" {
+				tokens = append(tokens, Token{Text: text, Kind: kind})
+			}
+		}
+		for i := 0; i < len(line); {
+			rest := line[i:]
+			switch {
+			case comment:
+				end := strings.Index(rest, s.BlockComment[1])
+				if end < 0 {
+					add(rest, TokenComment)
+					i = len(line)
+					continue
+				}
+				add(rest[:end+len(s.BlockComment[1])], TokenComment)
+				i += end + len(s.BlockComment[1])
+				comment = false
+			case s.LineComment != "" && strings.HasPrefix(rest, s.LineComment):
+				add(rest, TokenComment)
+				i = len(line)
+			case s.BlockComment[0] != "" && strings.HasPrefix(rest, s.BlockComment[0]):
+				add(s.BlockComment[0], TokenComment)
+				i += len(s.BlockComment[0])
+				comment = true
+			case strings.IndexByte(s.Quotes, rest[0]) >= 0:
+				end := 1
+				for end < len(rest) && rest[end] != rest[0] {
+					if rest[end] == '\\' {
+						end++
+					}
+					end++
+				}
+				end = min(end+1, len(rest))
+				add(rest[:end], TokenString)
+				i += end
+			case rest[0] >= '0' && rest[0] <= '9':
+				end := identEnd(rest)
+				add(rest[:end], TokenNumber)
+				i += end
+			case isIdent(rune(rest[0])) || rest[0] >= 0x80:
+				end := identEnd(rest)
+				word := rest[:end]
+				add(word, keywords[word])
+				i += end
+			default:
+				add(rest[:1], TokenText)
+				i++
+			}
+		}
+		res = append(res, tokens)
+	}
+	return res
+}
+
+func isIdent(r rune) bool {
+	return r == '_' || unicode.IsLetter(r) || unicode.IsDigit(r)
+}
+
+// The end of the word at the start of s, numbers like 0x1F and 1.5e3 are one word. The first character is
+// always part of it
+func identEnd(s string) int {
+	number := s[0] >= '0' && s[0] <= '9'
+	for i, r := range s {
+		if i > 0 && !isIdent(r) && !(number && r == '.') {
+			return i
+		}
+	}
+	return len(s)
+}
@@ -9,10 +9,26 @@ import (
 	"github.com/orus-dev/osui/isKey"
 )
 
+var InputBoxKeymap = osui.Keymap{
+	"reveal": {Keys: []string{"\x12"}, Description: "Show the masked text until the next key"},
+	"left":   {Keys: []string{"\x1b[D"}, Description: "Move the caret left"},
+	"right":  {Keys: []string{"\x1b[C"}, Description: "Move the caret right"},
+	"home":   {Keys: []string{"\x1b[H", "\x1bOH", "\x1b[1~"}, Description: "Move to the start"},
+	"end":    {Keys: []string{"\x1b[F", "\x1bOF", "\x1b[4~"}, Description: "Move to the end"},
+	"delete": {Keys: []string{"\x1b[3~"}, Description: "Delete after the caret"},
+}
+
 type InputBoxParams struct {
-	Style  DivStyle
-	Width  int
-	Height int
+	Style        InputBoxStyle
+	Width        int
+	Height       int
+	Bind         *osui.State[string]
+	OnBeforeBlur func(*InputBoxComponent) bool
+	Caret        osui.Caret
+	Mask         rune
+	Keymap       osui.Keymap
+	Validator    func(string) error
+	ShowError    bool
 }
 
 type InputBoxStyle struct {
@@ -20,65 +36,112 @@ type InputBoxStyle struct {
 	Foreground string `default:"" type:"fg"`
 	Outline    string `default:"" type:"fg"`
 	Cursor     string `default:"" type:"fg"`
+	Error      string `default:"\x1b[31m" type:"fg"`
 }
 
 type InputBoxComponent struct {
 	Data      osui.ComponentData
 	Style     *InputBoxStyle
 	max_size  uint
-	cursor    uint
+	after     int // Grapheme clusters after the caret, so it stays at the end when the text is set
+	bind      *osui.State[string]
 	InputData string
+	// Called before the input loses focus, returning false keeps the focus
+	OnBeforeBlur func(*InputBoxComponent) bool
+	// The color of the caret falls back to the cursor style
+	Caret      osui.Caret
+	caretShown bool
+	// Drawn instead of every character, like for passwords, 0 shows the text
+	Mask     rune
+	Keymap   osui.Keymap
+	revealed bool
+	// Checked after every edit, an error adds the "error" class and keeps Enter from moving on
+	Validator func(string) error
+	// Show the message of the validation error below the field
+	ShowError bool
+	err       error
+	checked   string
 }
 
 func (s *InputBoxComponent) GetComponentData() *osui.ComponentData {
 	return &s.Data
 }
 
-func (s InputBoxComponent) Render() string {
-	osui.UseStyle(s.Style)
-	if s.max_size > uint(len(s.InputData)) {
-		return fmt.Sprintf(
-			" %s\n%s│%s%s│%s\n %s",
-			colors.Reset+s.Style.Outline+strings.Repeat("_", int(s.max_size))+colors.Reset,
-			colors.Reset+s.Style.Outline,
-			colors.Combine(s.Style.Foreground, s.Style.Background)+s.InputData+osui.LogicValue(s.Data.IsActive, s.Style.Cursor+"█"+colors.Combine(s.Style.Foreground, s.Style.Background), ""),
-			strings.Repeat(" ", int(s.max_size)-len(s.InputData)-osui.LogicValueInt(s.Data.IsActive, 1, 0))+colors.Reset+s.Style.Outline,
+func (s *InputBoxComponent) GetKeymap() osui.Keymap {
+	return s.Keymap
+}
+
+func (s *InputBoxComponent) Render() string {
+	style := osui.StateStyle(&s.Data, s.Style)
+	g := osui.Settings.Glyphs
+	s.revealed = s.revealed && s.Data.IsActive
+	outline := osui.LogicValue(s.err != nil, style.Error, style.Outline)
+	text, caretWidth := s.content(colors.Combine(style.Foreground, style.Background))
+	used := osui.VisibleWidth(s.displayText())
+	res := ""
+	if int(s.max_size) > used {
+		field := colors.Combine(style.Foreground, style.Background)
+		pad := strings.Repeat(" ", max(int(s.max_size)-used-caretWidth, 0))
+		line := field + text + pad
+		if s.Data.Direction == osui.Rtl {
+			line = field + pad + text
+		}
+		res = fmt.Sprintf(
+			"%s\n%s%s%s%s\n%s",
+			colors.Reset+outline+g.TopBorder(int(s.max_size)+2)+colors.Reset,
+			colors.Reset+outline+g.Left,
+			line,
+			colors.Reset+outline,
+			g.Right+colors.Reset+s.Data.DefaultColor,
+			outline+g.BottomBorder(int(s.max_size)+2)+colors.Reset+s.Data.DefaultColor,
+		)
+	} else {
+		res = fmt.Sprintf(
+			"%s\n%s%s%s\n%s",
+			colors.Reset+outline+g.TopBorder(int(s.max_size)+2)+colors.Reset,
+			colors.Reset+outline+g.Left,
+			colors.Combine(style.Foreground, style.Background)+text+osui.LogicValue(s.Data.IsActive, colors.Reset, outline+"|"+colors.Reset),
 			colors.Reset+s.Data.DefaultColor,
-			s.Style.Outline+strings.Repeat("‾", int(s.max_size))+colors.Reset+s.Data.DefaultColor,
+			colors.Reset+outline+g.BottomBorder(int(s.max_size)+2)+colors.Reset+s.Data.DefaultColor,
 		)
 	}
-
-	return fmt.Sprintf(
-		" %s\n%s│%s%s\n %s",
-		colors.Reset+s.Style.Outline+strings.Repeat("_", int(s.max_size))+colors.Reset,
-		colors.Reset+s.Style.Outline,
-		colors.Combine(s.Style.Foreground, s.Style.Background)+s.InputData+osui.LogicValue(s.Data.IsActive, s.Style.Cursor+"█"+colors.Reset, s.Style.Outline+"|"+colors.Reset),
-		colors.Reset+s.Data.DefaultColor,
-		colors.Reset+s.Style.Outline+strings.Repeat("‾", int(s.max_size))+colors.Reset+s.Data.DefaultColor,
-	)
+	if s.ShowError && s.err != nil {
+		res += "\n" + style.Error + osui.TruncateEllipsis(s.err.Error(), int(s.max_size)+2) + colors.Reset + s.Data.DefaultColor
+	}
+	return res
 }
 
 func (s *InputBoxComponent) Update(key string) bool {
+	if s.Data.Disabled {
+		return false
+	}
+	defer s.sync()
+	if s.Mask != 0 && s.Keymap.Is("reveal", key) {
+		s.revealed = !s.revealed
+		return false
+	}
+	s.revealed = false
 	if isKey.Enter(key) {
-		return true
+		return s.Validate() == nil
 	} else if isKey.Backspace(key) {
-		if len(s.InputData) > 0 {
-			s.InputData = s.InputData[:len(s.InputData)-1]
-		}
-	} else if isKey.Left(key) {
-		if s.cursor > 0 {
-			s.cursor--
-		}
-	} else if isKey.Right(key) {
-		if s.cursor < s.max_size {
-			s.cursor++
-		}
-	} else {
-		if len(key) == 1 {
-			if int(s.max_size) > len(s.InputData) {
-				s.InputData += key
-			}
+		before, after := s.split()
+		s.InputData = dropLastGrapheme(before) + after
+	} else if s.Keymap.Is("delete", key) {
+		before, after := s.split()
+		if after != "" {
+			s.InputData = before + after[len(osui.Graphemes(after)[0]):]
+			s.after--
 		}
+	} else if s.Keymap.Is("left", key) {
+		s.after = min(s.after+1, len(osui.Graphemes(s.InputData)))
+	} else if s.Keymap.Is("right", key) {
+		s.after = max(s.after-1, 0)
+	} else if s.Keymap.Is("home", key) {
+		s.after = len(osui.Graphemes(s.InputData))
+	} else if s.Keymap.Is("end", key) {
+		s.after = 0
+	} else if isKey.Printable(key) {
+		s.insert(key)
 	}
 	return false
 }
@@ -87,9 +150,184 @@ func (b *InputBoxComponent) Params(p interface{}) *InputBoxComponent {
 	param := p.(InputBoxParams)
 	b.Style = osui.SetDefaults(&param.Style).(*InputBoxStyle)
 	b.Data.Width = osui.LogicValueInt(param.Width == 0, 20, param.Width)
+	if param.Bind != nil {
+		b.Bind(param.Bind)
+	}
+	b.OnBeforeBlur = param.OnBeforeBlur
+	b.Caret = param.Caret
+	b.Mask = param.Mask
+	b.Keymap = InputBoxKeymap.Merge(param.Keymap)
+	b.Validator = param.Validator
+	b.ShowError = param.ShowError
 	return b
 }
 
+// Redraw when a blinking caret changes its phase
+func (s *InputBoxComponent) Tick() bool {
+	return s.Data.IsActive && s.Caret.Blink && !osui.Settings.TerminalCursor && s.Caret.Visible() != s.caretShown
+}
+
+// The shown text with the caret at the cursor, and the cells the caret adds after the text
+func (s *InputBoxComponent) content(base string) (string, int) {
+	g := osui.Graphemes(s.displayText())
+	at := len(g) - min(s.after, len(g))
+	if osui.BidiEnabled(&s.Data) {
+		return s.bidiContent(g, at, base)
+	}
+	before := strings.Join(g[:at], "")
+	if !s.Data.IsActive {
+		return before + strings.Join(g[at:], ""), 0
+	}
+	if at == len(g) {
+		return before + s.renderCaret(before, " ", base), 1
+	}
+	return before + s.renderCaret(before, g[at], base) + strings.Join(g[at+1:], ""), 0
+}
+
+// The text in visual order with the caret on the cluster after it in logical order, editing stays logical.
+// At the end of the text the caret is right of it, or left of it for right-to-left inputs
+func (s *InputBoxComponent) bidiContent(g []string, at int, base string) (string, int) {
+	var b strings.Builder
+	caret := func(char string) {
+		if s.Data.IsActive {
+			b.WriteString(s.renderCaret(b.String(), char, base))
+		} else {
+			b.WriteString(char)
+		}
+	}
+	end := at == len(g) && s.Data.IsActive
+	if at == len(g) && s.Data.Direction == osui.Rtl {
+		caret(osui.LogicValue(end, " ", ""))
+	}
+	for _, i := range osui.BidiOrder(g, s.Data.Direction) {
+		if i == at {
+			caret(g[i])
+		} else {
+			b.WriteString(g[i])
+		}
+	}
+	if at == len(g) && s.Data.Direction != osui.Rtl {
+		caret(osui.LogicValue(end, " ", ""))
+	}
+	return b.String(), osui.LogicValueInt(end, 1, 0)
+}
+
+// Draw the caret over char after the text before it, or hand it to the real terminal cursor
+func (s *InputBoxComponent) renderCaret(before, char, base string) string {
+	style := osui.StateStyle(&s.Data, s.Style)
+	caret := s.Caret
+	if caret.Color == "" {
+		caret.Color = style.Cursor
+	}
+	if osui.Settings.TerminalCursor && s.Data.Screen != nil {
+		s.Data.Screen.SetCursor(s, 1+osui.VisibleWidth(before), 1, caret)
+		return char
+	}
+	s.caretShown = caret.Visible()
+	return caret.Render(char, base)
+}
+
+// Split the text at the caret, whole grapheme clusters stay on one side
+func (s *InputBoxComponent) split() (string, string) {
+	g := osui.Graphemes(s.InputData)
+	s.after = min(s.after, len(g))
+	at := len(g) - s.after
+	return strings.Join(g[:at], ""), strings.Join(g[at:], "")
+}
+
+// Insert pasted text, new lines become spaces
+func (s *InputBoxComponent) Paste(text string) {
+	s.insert(strings.NewReplacer("\r\n", " ", "\n", " ", "\r", " ").Replace(text))
+	s.sync()
+}
+
+func (s *InputBoxComponent) BeforeBlur() bool {
+	return s.OnBeforeBlur == nil || s.OnBeforeBlur(s)
+}
+
+// Keep the input data and the state in sync both ways
+func (s *InputBoxComponent) Bind(state *osui.State[string]) *InputBoxComponent {
+	s.bind = state
+	s.InputData = state.Get()
+	state.SubscribeFor(s, func(v string) {
+		if s.bind == state {
+			s.InputData = v
+		}
+	})
+	return s
+}
+
+// The text as it is shown, masked unless it is revealed
+func (s *InputBoxComponent) displayText() string {
+	if s.Mask == 0 || s.revealed {
+		return s.InputData
+	}
+	return strings.Repeat(string(s.Mask), len(osui.Graphemes(s.InputData)))
+}
+
+// Insert text at the caret as long as it fits, whole grapheme clusters at a time
+func (s *InputBoxComponent) insert(text string) {
+	before, after := s.split()
+	used := osui.VisibleWidth(s.InputData)
+	for _, g := range osui.Graphemes(text) {
+		w := osui.GraphemeWidth(g)
+		if used+w > int(s.max_size) {
+			break
+		}
+		before += g
+		used += w
+	}
+	s.InputData = before + after
+}
+
+func dropLastGrapheme(text string) string {
+	g := osui.Graphemes(text)
+	if len(g) == 0 {
+		return text
+	}
+	return text[:len(text)-len(g[len(g)-1])]
+}
+
+// Run the validator on the current text, the error stays until the next validation
+func (s *InputBoxComponent) Validate() error {
+	s.checked = s.InputData
+	s.err = nil
+	if s.Validator != nil {
+		s.err = s.Validator(s.InputData)
+	}
+	if s.err != nil {
+		s.Data.AddClass("error")
+	} else {
+		s.Data.RemoveClass("error")
+	}
+	return s.err
+}
+
+func (s *InputBoxComponent) Value() string {
+	return s.InputData
+}
+
+// Clear the text and the validation error
+func (s *InputBoxComponent) Reset() {
+	s.InputData, s.checked, s.err, s.after = "", "", nil, 0
+	s.Data.RemoveClass("error")
+	s.sync()
+}
+
+// Get the error of the last validation, nil if it passed
+func (s *InputBoxComponent) Err() error {
+	return s.err
+}
+
+func (s *InputBoxComponent) sync() {
+	if s.Validator != nil && s.InputData != s.checked {
+		s.Validate()
+	}
+	if s.bind != nil && s.bind.Get() != s.InputData {
+		s.bind.Set(s.InputData)
+	}
+}
+
 func InputBox(max_size uint) *InputBoxComponent {
-	return &InputBoxComponent{max_size: max_size, Style: osui.SetDefaults(&InputBoxStyle{}).(*InputBoxStyle)}
+	return &InputBoxComponent{max_size: max_size, Style: osui.SetDefaults(&InputBoxStyle{}).(*InputBoxStyle), Keymap: InputBoxKeymap}
 }
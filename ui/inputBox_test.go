@@ -0,0 +1,52 @@
+package ui
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/orus-dev/osui"
+)
+
+func TestInputBoxEditing(t *testing.T) {
+	input := InputBox(20)
+	b := osui.NewTestBackend(input, 30, 3)
+	b.Render()
+	b.Type("helo")
+	b.Press("\x1b[D", "l")
+	if input.InputData != "hello" {
+		t.Fatalf("inserting before the caret: got %q", input.InputData)
+	}
+	b.Press("\x1b[H", "\x1b[3~")
+	if input.InputData != "ello" {
+		t.Fatalf("deleting at the start: got %q", input.InputData)
+	}
+	b.Press("\x1b[F", "\x7f")
+	if input.InputData != "ell" {
+		t.Fatalf("backspace at the end: got %q", input.InputData)
+	}
+	if !strings.Contains(b.Text(), "ell") {
+		t.Fatalf("the text isn't drawn:\n%s", b.Text())
+	}
+}
+
+func TestInputBoxMaxSize(t *testing.T) {
+	input := InputBox(5)
+	b := osui.NewTestBackend(input, 20, 3)
+	b.Render()
+	b.Type("abcdefgh")
+	if input.InputData != "abcde" {
+		t.Fatalf("got %q, want the first 5 characters", input.InputData)
+	}
+}
+
+func TestInputBoxPaste(t *testing.T) {
+	input := InputBox(20)
+	b := osui.NewTestBackend(input, 30, 3)
+	b.Render()
+	b.Type("ab")
+	b.Press("\x1b[D")
+	b.Paste("x\ny")
+	if input.InputData != "ax yb" {
+		t.Fatalf("got %q, the paste should be inserted at the caret on one line", input.InputData)
+	}
+}
@@ -0,0 +1,212 @@
+package ui
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"strconv"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+	"github.com/orus-dev/osui/isKey"
+)
+
+var InspectorKeymap = osui.Keymap{
+	"search": {Keys: []string{"/"}, Description: "Search keys and values"},
+	"clear":  {Keys: []string{"\x1b"}, Description: "Clear the search"},
+}
+
+type InspectorParams struct {
+	Style  InspectorStyle
+	Width  int
+	Height int
+	Keymap osui.Keymap
+}
+
+type InspectorStyle struct {
+	String string `default:"\x1b[32m" type:"fg"`
+	Number string `default:"\x1b[33m" type:"fg"`
+	Bool   string `default:"\x1b[35m" type:"fg"`
+	Null   string `default:"\x1b[2m" type:"fg"`
+	Size   string `default:"\x1b[2m" type:"fg"`
+	Search string `default:"\x1b[36m" type:"fg"`
+}
+
+// An expandable tree of the keys and values of JSON data, the values are colored by their type. / searches
+// keys and values, Esc clears the search
+type InspectorComponent struct {
+	Data   osui.ComponentData
+	Style  *InspectorStyle
+	Tree   *TreeComponent
+	Keymap osui.Keymap
+	// The value the tree was built from, re-decoded from JSON
+	Value     any
+	source    any
+	err       error
+	searching bool
+}
+
+func (i *InspectorComponent) GetComponentData() *osui.ComponentData {
+	return &i.Data
+}
+
+func (i *InspectorComponent) GetKeymap() osui.Keymap {
+	return i.Keymap
+}
+
+// The tree with the search line below it while searching or filtered
+func (i *InspectorComponent) Render() string {
+	style := osui.StateStyle(&i.Data, i.Style)
+	if i.err != nil {
+		return style.Null + osui.TruncateEllipsis(i.err.Error(), max(i.Data.Width, 1)) + colors.Reset + i.Data.DefaultColor
+	}
+	search := i.searching || i.Tree.Query() != ""
+	data := i.Tree.GetComponentData()
+	data.Width = i.Data.Width
+	data.Height = i.Data.Height
+	if search && data.Height > 0 {
+		data.Height--
+	}
+	data.IsActive = i.Data.IsActive && !i.searching
+	data.DefaultColor = i.Data.DefaultColor
+	data.Screen = i.Data.Screen
+	output := osui.RenderComponent(i.Tree)
+	if !search {
+		return output
+	}
+	cursor := osui.LogicValue(i.searching && i.Data.IsActive, colors.Reverse+" "+colors.Reset, "")
+	return output + "\n" + style.Search + "/" + i.Tree.Query() + colors.Reset + cursor + i.Data.DefaultColor
+}
+
+// While searching the keys edit the query and Enter goes back to the tree with the matches
+func (i *InspectorComponent) Update(key string) bool {
+	if i.Keymap.Is("clear", key) && (i.searching || i.Tree.Query() != "") {
+		i.searching = false
+		i.Tree.SetQuery("")
+		return false
+	}
+	if i.searching {
+		switch {
+		case isKey.Enter(key):
+			i.searching = false
+		case isKey.Backspace(key):
+			i.Tree.SetQuery(dropLastGrapheme(i.Tree.Query()))
+		case isKey.Printable(key):
+			i.Tree.SetQuery(i.Tree.Query() + key)
+		}
+		return false
+	}
+	if i.Keymap.Is("search", key) {
+		i.searching = true
+		return false
+	}
+	i.Tree.GetComponentData().IsActive = i.Data.IsActive
+	return i.Tree.Update(key)
+}
+
+func (i *InspectorComponent) Scroll(dx, dy int) bool {
+	return i.Tree.Scroll(dx, dy)
+}
+
+func (i *InspectorComponent) OnClick(e osui.MouseEvent) bool {
+	return i.Tree.OnClick(e)
+}
+
+// Show another value, anything encoding/json can encode. Objects keep the order of their keys
+func (i *InspectorComponent) Inspect(v any) {
+	i.source = v
+	var data []byte
+	switch v := v.(type) {
+	case json.RawMessage:
+		data = v
+	default:
+		data, i.err = json.Marshal(v)
+		if i.err != nil {
+			return
+		}
+	}
+	dec := json.NewDecoder(bytes.NewReader(data))
+	dec.UseNumber()
+	node := &TreeNode{Label: "$"}
+	i.err = i.decode(dec, node)
+	if i.err != nil {
+		return
+	}
+	i.Value = node.Value
+	node.Expanded = true
+	i.Tree.Roots = []*TreeNode{node}
+	i.Tree.SetQuery("")
+}
+
+// Read the next value into the node, its label is already set
+func (i *InspectorComponent) decode(dec *json.Decoder, node *TreeNode) error {
+	tok, err := dec.Token()
+	if err != nil {
+		return err
+	}
+	switch tok := tok.(type) {
+	case json.Delim:
+		object := tok == '{'
+		values := []any{}
+		fields := map[string]any{}
+		for dec.More() {
+			child := &TreeNode{}
+			if object {
+				key, err := dec.Token()
+				if err != nil {
+					return err
+				}
+				child.Label = fmt.Sprint(key)
+			} else {
+				child.Label = "[" + strconv.Itoa(len(node.Children)) + "]"
+			}
+			if err := i.decode(dec, child); err != nil {
+				return err
+			}
+			node.Children = append(node.Children, child)
+			values = append(values, child.Value)
+			fields[child.Label] = child.Value
+		}
+		// The closing delimiter
+		if _, err := dec.Token(); err != nil {
+			return err
+		}
+		n := len(node.Children)
+		node.DetailStyle = i.Style.Size
+		if object {
+			node.Detail, node.Value = fmt.Sprintf("{%d}", n), fields
+		} else {
+			node.Detail, node.Value = fmt.Sprintf("[%d]", n), values
+		}
+	case string:
+		node.Detail, node.DetailStyle, node.Value = strconv.Quote(tok), i.Style.String, tok
+	case json.Number:
+		node.Detail, node.DetailStyle, node.Value = tok.String(), i.Style.Number, tok
+	case bool:
+		node.Detail, node.DetailStyle, node.Value = strconv.FormatBool(tok), i.Style.Bool, tok
+	case nil:
+		node.Detail, node.DetailStyle = "null", i.Style.Null
+	}
+	return nil
+}
+
+func (i *InspectorComponent) Params(param InspectorParams) *InspectorComponent {
+	i.Style = osui.SetDefaults(&param.Style).(*InspectorStyle)
+	i.Data.Width = param.Width
+	i.Data.Height = param.Height
+	i.Keymap = InspectorKeymap.Merge(param.Keymap)
+	// The colors are kept in the nodes
+	i.Inspect(i.source)
+	return i
+}
+
+// Browse the value as a tree of its JSON, like the response of an API. Pass json.RawMessage for raw JSON
+func Inspector(v any) *InspectorComponent {
+	i := &InspectorComponent{
+		Tree:   Tree(),
+		Style:  osui.SetDefaults(&InspectorStyle{}).(*InspectorStyle),
+		Keymap: InspectorKeymap,
+	}
+	i.Inspect(v)
+	return i
+}
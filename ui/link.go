@@ -0,0 +1,90 @@
+package ui
+
+import (
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+var LinkKeymap = osui.Keymap{
+	"open": {Keys: []string{"\r", "\n"}, Description: "Open the link"},
+}
+
+type LinkParams struct {
+	Style  LinkStyle
+	OnOpen func(*LinkComponent)
+	Keymap osui.Keymap
+}
+
+type LinkStyle struct {
+	Foreground string `default:"\x1b[34m\x1b[4m" type:"fg"`
+	Active     string `default:"\x1b[1m" type:"fg"`
+}
+
+// Text terminals open on click, see osui.Hyperlink
+type LinkComponent struct {
+	Data  osui.ComponentData
+	Style *LinkStyle
+	Text  string
+	Url   string
+	// Called with the open key or a click, while mouse reporting is on terminals don't open links themselves
+	OnOpen func(*LinkComponent)
+	Keymap osui.Keymap
+}
+
+func (l *LinkComponent) GetComponentData() *osui.ComponentData {
+	return &l.Data
+}
+
+func (l *LinkComponent) GetKeymap() osui.Keymap {
+	return l.Keymap
+}
+
+func (l *LinkComponent) Render() string {
+	style := osui.StateStyle(&l.Data, l.Style)
+	text := l.Text
+	if text == "" {
+		text = l.Url
+	}
+	active := osui.LogicValue(l.Data.IsActive || l.Data.Hovered, style.Active, "")
+	return style.Foreground + active + osui.Hyperlink(l.Url, text) + colors.Reset + l.Data.DefaultColor
+}
+
+func (l *LinkComponent) Update(key string) bool {
+	if l.Data.Disabled {
+		return false
+	}
+	if l.Keymap.Is("open", key) {
+		l.Open()
+	}
+	return false
+}
+
+func (l *LinkComponent) OnClick(m osui.MouseEvent) bool {
+	if m.Button != osui.MouseLeft {
+		return false
+	}
+	l.Open()
+	return true
+}
+
+func (l *LinkComponent) Open() {
+	if l.OnOpen != nil {
+		l.OnOpen(l)
+	}
+}
+
+func (l *LinkComponent) Params(param LinkParams) *LinkComponent {
+	l.Style = osui.SetDefaults(&param.Style).(*LinkStyle)
+	l.OnOpen = param.OnOpen
+	l.Keymap = LinkKeymap.Merge(param.Keymap)
+	return l
+}
+
+func Link(text, url string) *LinkComponent {
+	return &LinkComponent{
+		Text:   text,
+		Url:    url,
+		Style:  osui.SetDefaults(&LinkStyle{}).(*LinkStyle),
+		Keymap: LinkKeymap,
+	}
+}
@@ -0,0 +1,100 @@
+package ui
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"log/slog"
+	"strings"
+	"sync"
+	"time"
+)
+
+// A slog handler that writes records into a log view and optionally a file
+type LogViewHandler struct {
+	view   *LogViewComponent
+	file   io.Writer
+	fileMu *sync.Mutex
+	level  slog.Leveler
+	attrs  string
+	group  string
+}
+
+// Create a handler for the view, file may be nil and opts may be nil to log from info up
+func NewLogViewHandler(view *LogViewComponent, file io.Writer, opts *slog.HandlerOptions) *LogViewHandler {
+	h := &LogViewHandler{view: view, file: file, fileMu: &sync.Mutex{}, level: slog.LevelInfo}
+	if opts != nil && opts.Level != nil {
+		h.level = opts.Level
+	}
+	return h
+}
+
+func (h *LogViewHandler) Enabled(_ context.Context, level slog.Level) bool {
+	return level >= h.level.Level()
+}
+
+func (h *LogViewHandler) Handle(_ context.Context, r slog.Record) error {
+	var text strings.Builder
+	text.WriteString(r.Message + h.attrs)
+	r.Attrs(func(a slog.Attr) bool {
+		text.WriteString(" " + h.group + a.String())
+		return true
+	})
+	h.view.PushLevel(slogLevel(r.Level), text.String())
+	if h.file != nil {
+		h.fileMu.Lock()
+		defer h.fileMu.Unlock()
+		_, err := fmt.Fprintf(h.file, "%s %s %s\n", r.Time.Format(time.RFC3339), r.Level, text.String())
+		return err
+	}
+	return nil
+}
+
+func (h *LogViewHandler) WithAttrs(attrs []slog.Attr) slog.Handler {
+	res := *h
+	for _, a := range attrs {
+		res.attrs += " " + h.group + a.String()
+	}
+	return &res
+}
+
+func (h *LogViewHandler) WithGroup(name string) slog.Handler {
+	res := *h
+	res.group += name + "."
+	return &res
+}
+
+// Send the records of slog and the log package to the view instead of stderr, so they don't corrupt the frame
+func RouteLogs(view *LogViewComponent, file io.Writer) {
+	slog.SetDefault(slog.New(NewLogViewHandler(view, file, nil)))
+}
+
+// Append the written lines, the level is guessed from words like ERROR or WARN in them
+func (l *LogViewComponent) Write(p []byte) (int, error) {
+	for _, line := range strings.Split(strings.TrimRight(string(p), "\n"), "\n") {
+		upper := strings.ToUpper(line)
+		level := LevelInfo
+		switch {
+		case strings.Contains(upper, "ERROR"):
+			level = LevelError
+		case strings.Contains(upper, "WARN"):
+			level = LevelWarn
+		case strings.Contains(upper, "DEBUG"):
+			level = LevelDebug
+		}
+		l.PushLevel(level, line)
+	}
+	return len(p), nil
+}
+
+func slogLevel(level slog.Level) LogLevel {
+	switch {
+	case level >= slog.LevelError:
+		return LevelError
+	case level >= slog.LevelWarn:
+		return LevelWarn
+	case level >= slog.LevelInfo:
+		return LevelInfo
+	}
+	return LevelDebug
+}
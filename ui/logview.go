@@ -0,0 +1,206 @@
+package ui
+
+import (
+	"fmt"
+	"strings"
+	"sync"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+type LogLevel int
+
+const (
+	LevelDebug LogLevel = iota
+	LevelInfo
+	LevelWarn
+	LevelError
+)
+
+type LogLine struct {
+	Level LogLevel
+	Text  string
+}
+
+var LogViewKeymap = osui.Keymap{
+	"up":        {Keys: []string{"\x1b[A"}, Description: "Scroll up"},
+	"down":      {Keys: []string{"\x1b[B"}, Description: "Scroll down"},
+	"page_up":   {Keys: []string{"\x1b[5~"}, Description: "Scroll a page up"},
+	"page_down": {Keys: []string{"\x1b[6~"}, Description: "Scroll a page down"},
+	"follow":    {Keys: []string{"\x1b[F", "\x1b[4~"}, Description: "Follow new lines"},
+}
+
+type LogViewParams struct {
+	Style    LogViewStyle
+	Width    int
+	Height   int
+	Capacity int
+	Keymap   osui.Keymap
+}
+
+type LogViewStyle struct {
+	Debug  string `default:"\x1b[2m" type:"fg"`
+	Info   string `default:"" type:"fg"`
+	Warn   string `default:"\x1b[33m" type:"fg"`
+	Error  string `default:"\x1b[31m" type:"fg"`
+	Paused string `default:"\x1b[7m" type:"fg"`
+}
+
+type LogViewComponent struct {
+	Data   osui.ComponentData
+	Style  *LogViewStyle
+	Keymap osui.Keymap
+	// Lines older than the capacity are dropped
+	Capacity int
+	mu       sync.Mutex
+	lines    []LogLine
+	start    int
+	// Lines scrolled up from the bottom, 0 follows new lines
+	offset int
+}
+
+func (l *LogViewComponent) GetComponentData() *osui.ComponentData {
+	return &l.Data
+}
+
+func (l *LogViewComponent) GetKeymap() osui.Keymap {
+	return l.Keymap
+}
+
+func (l *LogViewComponent) Render() string {
+	osui.UseStyle(l.Style)
+	l.mu.Lock()
+	defer l.mu.Unlock()
+	height := max(l.Data.Height, 1)
+	l.offset = min(l.offset, max(len(l.lines)-height, 0))
+	end := len(l.lines) - l.offset
+	res := []string{}
+	for i := max(end-height, 0); i < end; i++ {
+		line := l.at(i)
+		text := line.Text
+		if l.Data.Width > 0 {
+			text = osui.TruncateVisible(text, l.Data.Width)
+		}
+		res = append(res, l.levelStyle(line.Level)+text+colors.Reset+l.Data.DefaultColor)
+	}
+	if l.offset > 0 && len(res) > 0 {
+		res[len(res)-1] = l.Style.Paused + centerText(fmt.Sprintf("paused, %d more below", l.offset), l.Data.Width) + colors.Reset + l.Data.DefaultColor
+	}
+	return strings.Join(res, "\n")
+}
+
+func (l *LogViewComponent) Update(key string) bool {
+	page := max(l.Data.Height-1, 1)
+	switch {
+	case l.Keymap.Is("up", key):
+		l.Scroll(0, -1)
+	case l.Keymap.Is("down", key):
+		l.Scroll(0, 1)
+	case l.Keymap.Is("page_up", key):
+		l.Scroll(0, -page)
+	case l.Keymap.Is("page_down", key):
+		l.Scroll(0, page)
+	case l.Keymap.Is("follow", key):
+		l.Follow()
+	}
+	return false
+}
+
+func (l *LogViewComponent) Scroll(dx, dy int) bool {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+	old := l.offset
+	l.offset = min(max(l.offset-dy, 0), max(len(l.lines)-max(l.Data.Height, 1), 0))
+	return l.offset != old
+}
+
+// Jump to the bottom and keep showing new lines
+func (l *LogViewComponent) Follow() {
+	l.mu.Lock()
+	l.offset = 0
+	l.mu.Unlock()
+}
+
+func (l *LogViewComponent) Following() bool {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+	return l.offset == 0
+}
+
+// Append an info line, safe to call from any goroutine
+func (l *LogViewComponent) Push(text string) {
+	l.PushLevel(LevelInfo, text)
+}
+
+// Append a line with a level, text with new lines is split into more lines
+func (l *LogViewComponent) PushLevel(level LogLevel, text string) {
+	l.mu.Lock()
+	for _, t := range strings.Split(strings.TrimRight(text, "\n"), "\n") {
+		if l.Capacity > 0 && len(l.lines) >= l.Capacity {
+			l.lines[l.start] = LogLine{Level: level, Text: t}
+			l.start = (l.start + 1) % len(l.lines)
+		} else {
+			l.lines = append(l.lines, LogLine{Level: level, Text: t})
+		}
+		// Keep the same lines in view while paused
+		if l.offset > 0 {
+			l.offset++
+		}
+	}
+	l.mu.Unlock()
+	if l.Data.Screen != nil {
+		l.Data.Screen.RequestRender()
+	}
+}
+
+// Get a copy of the lines from the oldest to the newest
+func (l *LogViewComponent) Lines() []LogLine {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+	res := make([]LogLine, len(l.lines))
+	for i := range res {
+		res[i] = l.at(i)
+	}
+	return res
+}
+
+func (l *LogViewComponent) Clear() {
+	l.mu.Lock()
+	l.lines, l.start, l.offset = nil, 0, 0
+	l.mu.Unlock()
+}
+
+func (l *LogViewComponent) Params(param LogViewParams) *LogViewComponent {
+	l.Style = osui.SetDefaults(&param.Style).(*LogViewStyle)
+	l.Data.Width = osui.LogicValueInt(param.Width == 0, l.Data.Width, param.Width)
+	l.Data.Height = osui.LogicValueInt(param.Height == 0, l.Data.Height, param.Height)
+	l.Capacity = osui.LogicValueInt(param.Capacity == 0, 1000, param.Capacity)
+	l.Keymap = LogViewKeymap.Merge(param.Keymap)
+	return l
+}
+
+// The i-th line from the oldest one in the ring buffer
+func (l *LogViewComponent) at(i int) LogLine {
+	return l.lines[(l.start+i)%len(l.lines)]
+}
+
+func (l *LogViewComponent) levelStyle(level LogLevel) string {
+	switch level {
+	case LevelDebug:
+		return l.Style.Debug
+	case LevelWarn:
+		return l.Style.Warn
+	case LevelError:
+		return l.Style.Error
+	}
+	return l.Style.Info
+}
+
+func LogView() *LogViewComponent {
+	return &LogViewComponent{
+		Style:    osui.SetDefaults(&LogViewStyle{}).(*LogViewStyle),
+		Keymap:   LogViewKeymap,
+		Capacity: 1000,
+	}
+}
@@ -20,6 +20,7 @@ type MenuStyle struct {
 	SelectedBg     string `default:"" type:"bg"`
 	Cursor         string `default:"> " type:"fg"`
 	CursorInactive string `default:"  " type:"fg"`
+	Match          string `default:"\x1b[1;33m" type:"fg"`
 }
 
 type MenuComponent struct {
@@ -28,6 +29,7 @@ type MenuComponent struct {
 	Items        []string
 	SelectedItem int
 	OnSelected   func(*MenuComponent, bool)
+	query        string
 }
 
 func (m *MenuComponent) GetComponentData() *osui.ComponentData {
@@ -45,11 +47,13 @@ func (m *MenuComponent) Render() string {
 	d, _ := osui.CompressString(m.Style.Cursor)
 	empty := strings.Repeat(" ", len(d))
 
-	for i, item := range m.Items {
+	for _, i := range m.visible() {
 		if i == m.SelectedItem {
-			res = append(res, cursor+colors.Combine(m.Style.SelectedFg, m.Style.SelectedBg)+item+colors.Reset+m.Data.DefaultColor)
+			color := colors.Combine(m.Style.SelectedFg, m.Style.SelectedBg)
+			res = append(res, cursor+color+highlightMatches(m.Items[i], m.query, m.Style.Match, colors.Reset+color)+colors.Reset+m.Data.DefaultColor)
 		} else {
-			res = append(res, empty+colors.Combine(m.Style.Fg, m.Style.Bg)+item+colors.Reset+m.Data.DefaultColor)
+			color := colors.Combine(m.Style.Fg, m.Style.Bg)
+			res = append(res, empty+color+highlightMatches(m.Items[i], m.query, m.Style.Match, colors.Reset+color)+colors.Reset+m.Data.DefaultColor)
 		}
 	}
 
@@ -57,28 +61,84 @@ func (m *MenuComponent) Render() string {
 }
 
 func (m *MenuComponent) Update(key string) bool {
-	if isKey.Char(key, "s") {
-		if m.SelectedItem+1 < len(m.Items) {
-			m.SelectedItem++
-		} else {
-			m.SelectedItem = 0
-		}
-	} else if isKey.Char(key, "w") {
-		if m.SelectedItem > 0 {
-			m.SelectedItem--
-		} else {
-			m.SelectedItem = len(m.Items) - 1
-		}
+	visible := m.visible()
+	if isKey.Char(key, "s") || isKey.Down(key) {
+		m.move(visible, 1)
+	} else if isKey.Char(key, "w") || isKey.Up(key) {
+		m.move(visible, -1)
 	} else if isKey.Enter(key) {
-		m.OnSelected(m, true)
+		if len(visible) == 0 {
+			return false
+		}
+		m.selected(true)
 		return true
 	} else if isKey.Char(key, "q") {
-		m.OnSelected(m, false)
+		m.selected(false)
 		return true
 	}
 	return false
 }
 
+// Move the selection by step among the shown items, wrapping around
+func (m *MenuComponent) move(visible []int, step int) {
+	if len(visible) == 0 {
+		return
+	}
+	for k, i := range visible {
+		if i == m.SelectedItem {
+			m.SelectedItem = visible[(k+step+len(visible))%len(visible)]
+			return
+		}
+	}
+	m.SelectedItem = visible[0]
+}
+
+// The indices of the items with the query in them
+func (m *MenuComponent) visible() []int {
+	res := []int{}
+	for i, item := range m.Items {
+		if containsFold(item, m.query) {
+			res = append(res, i)
+		}
+	}
+	return res
+}
+
+// Only show the items with the query in them, the first of them is selected if the selected one isn't
+func (m *MenuComponent) SetQuery(query string) {
+	m.query = query
+	visible := m.visible()
+	for _, i := range visible {
+		if i == m.SelectedItem {
+			return
+		}
+	}
+	if len(visible) > 0 {
+		m.SelectedItem = visible[0]
+	}
+}
+
+func (m *MenuComponent) selected(ok bool) {
+	if m.OnSelected != nil {
+		m.OnSelected(m, ok)
+	}
+}
+
+func (m *MenuComponent) Scroll(dx, dy int) bool {
+	visible := m.visible()
+	if dy == 0 || len(visible) == 0 {
+		return false
+	}
+	k := 0
+	for j, i := range visible {
+		if i == m.SelectedItem {
+			k = j
+		}
+	}
+	m.SelectedItem = visible[min(max(k+dy, 0), len(visible)-1)]
+	return true
+}
+
 func (b *MenuComponent) Params(param MenuParams) *MenuComponent {
 	b.Style = osui.SetDefaults(&param.Style).(*MenuStyle)
 	b.OnSelected = param.OnSelected
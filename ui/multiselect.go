@@ -0,0 +1,300 @@
+package ui
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+	"github.com/orus-dev/osui/isKey"
+)
+
+var MultiSelectKeymap = osui.Keymap{
+	"up":         {Keys: []string{"\x1b[A"}, Description: "Previous item"},
+	"down":       {Keys: []string{"\x1b[B"}, Description: "Next item"},
+	"toggle":     {Keys: []string{" "}, Description: "Check or uncheck the item"},
+	"toggle-all": {Keys: []string{"a"}, Description: "Check or uncheck every shown item"},
+}
+
+type MultiSelectParams struct {
+	Style    MultiSelectStyle
+	Width    int
+	Height   int
+	Keymap   osui.Keymap
+	OnChange func(*MultiSelectComponent, []int)
+}
+
+type MultiSelectStyle struct {
+	Foreground string `default:"" type:"fg"`
+	Checked    string `default:"\x1b[32m" type:"fg"`
+	Unchecked  string `default:"\x1b[2m" type:"fg"`
+	Cursor     string `default:"\x1b[7m" type:"bg"`
+	More       string `default:"\x1b[2m" type:"fg"`
+	Match      string `default:"\x1b[1;33m" type:"fg"`
+}
+
+// A scrollable list of items with a checkbox each, like to choose the components to install
+type MultiSelectComponent struct {
+	Data   osui.ComponentData
+	Style  *MultiSelectStyle
+	Items  []string
+	Cursor int
+	Keymap osui.Keymap
+	// Called with the indices of the checked items whenever they change
+	OnChange func(*MultiSelectComponent, []int)
+	checked  map[int]bool
+	initial  []int
+	offset   int
+	rows     int
+	query    string
+}
+
+func (m *MultiSelectComponent) GetComponentData() *osui.ComponentData {
+	return &m.Data
+}
+
+func (m *MultiSelectComponent) GetKeymap() osui.Keymap {
+	return m.Keymap
+}
+
+// The rows that fit in the height, with the number of hidden items at the bottom when they don't all fit
+func (m *MultiSelectComponent) Render() string {
+	style := osui.StateStyle(&m.Data, m.Style)
+	reset := colors.Reset + m.Data.DefaultColor
+	visible := m.visible()
+	rows := len(visible)
+	if m.Data.Height > 0 && rows > m.Data.Height {
+		rows = max(m.Data.Height-1, 1)
+	}
+	pos := m.position(visible)
+	if len(visible) > 0 {
+		m.Cursor = visible[pos]
+	}
+	if pos < m.offset {
+		m.offset = pos
+	} else if pos >= m.offset+rows {
+		m.offset = pos - rows + 1
+	}
+	m.offset = min(m.offset, max(len(visible)-rows, 0))
+	m.rows = rows
+	res := []string{}
+	for k := m.offset; k < len(visible) && k < m.offset+rows; k++ {
+		i := visible[k]
+		box := osui.LogicValue(m.checked[i], style.Checked+"[x]", style.Unchecked+"[ ]")
+		text := box + reset + " " + style.Foreground + highlightMatches(m.Items[i], m.query, style.Match, reset+style.Foreground) + reset
+		if m.Data.Width > 0 {
+			text = osui.TruncateVisible(text, m.Data.Width)
+			text += strings.Repeat(" ", max(m.Data.Width-osui.VisibleWidth(text), 0))
+		}
+		if i == m.Cursor && m.Data.IsActive {
+			text = osui.WithStyle(text, style.Cursor)
+		}
+		res = append(res, text+reset)
+	}
+	if hidden := len(visible) - m.offset - rows; hidden > 0 {
+		res = append(res, style.More+fmt.Sprintf("  ↓ %d more", hidden)+reset)
+	}
+	return strings.Join(res, "\n")
+}
+
+// Space checks the item under the cursor, a checks every shown item or unchecks them if all are checked, Enter moves on
+func (m *MultiSelectComponent) Update(key string) bool {
+	if m.Data.Disabled {
+		return false
+	}
+	switch {
+	case m.Keymap.Is("up", key):
+		m.Scroll(0, -1)
+	case m.Keymap.Is("down", key):
+		m.Scroll(0, 1)
+	case m.Keymap.Is("toggle", key):
+		if visible := m.visible(); len(visible) > 0 {
+			i := visible[m.position(visible)]
+			m.SetChecked(i, !m.checked[i])
+		}
+	case m.Keymap.Is("toggle-all", key):
+		m.toggleVisible()
+	}
+	return isKey.Enter(key)
+}
+
+func (m *MultiSelectComponent) Scroll(dx, dy int) bool {
+	visible := m.visible()
+	if dy == 0 || len(visible) == 0 {
+		return false
+	}
+	m.Cursor = visible[min(max(m.position(visible)+dy, 0), len(visible)-1)]
+	return true
+}
+
+// Clicking an item moves the cursor to it and checks or unchecks it
+func (m *MultiSelectComponent) OnClick(e osui.MouseEvent) bool {
+	visible := m.visible()
+	if e.Button != osui.MouseLeft || e.Y >= m.rows || m.offset+e.Y >= len(visible) {
+		return false
+	}
+	i := visible[m.offset+e.Y]
+	m.Cursor = i
+	m.SetChecked(i, !m.checked[i])
+	return true
+}
+
+func (m *MultiSelectComponent) IsChecked(i int) bool {
+	return m.checked[i]
+}
+
+func (m *MultiSelectComponent) SetChecked(i int, checked bool) {
+	if i < 0 || i >= len(m.Items) || m.checked[i] == checked {
+		return
+	}
+	if checked {
+		m.checked[i] = true
+	} else {
+		delete(m.checked, i)
+	}
+	m.changed()
+}
+
+// Check or uncheck every item, OnChange is called once
+func (m *MultiSelectComponent) SetAll(checked bool) {
+	if len(m.checked) == osui.LogicValueInt(checked, len(m.Items), 0) {
+		return
+	}
+	m.checked = map[int]bool{}
+	if checked {
+		for i := range m.Items {
+			m.checked[i] = true
+		}
+	}
+	m.changed()
+}
+
+// Check the shown items, or uncheck them if they are all checked
+func (m *MultiSelectComponent) toggleVisible() {
+	visible := m.visible()
+	all := true
+	for _, i := range visible {
+		all = all && m.checked[i]
+	}
+	changed := false
+	for _, i := range visible {
+		if m.checked[i] != !all {
+			changed = true
+		}
+		if all {
+			delete(m.checked, i)
+		} else {
+			m.checked[i] = true
+		}
+	}
+	if changed {
+		m.changed()
+	}
+}
+
+// Only show the items with the query in them, an empty query shows all of them
+func (m *MultiSelectComponent) SetQuery(query string) {
+	m.query = query
+	m.offset = 0
+	if visible := m.visible(); len(visible) > 0 {
+		m.Cursor = visible[m.position(visible)]
+	}
+}
+
+// The indices of the items with the query in them
+func (m *MultiSelectComponent) visible() []int {
+	res := []int{}
+	for i, item := range m.Items {
+		if containsFold(item, m.query) {
+			res = append(res, i)
+		}
+	}
+	return res
+}
+
+// The row of the cursor among the shown items, the first one if the item under it isn't shown
+func (m *MultiSelectComponent) position(visible []int) int {
+	for k, i := range visible {
+		if i == m.Cursor {
+			return k
+		}
+	}
+	return 0
+}
+
+// Check exactly the items at the indices
+func (m *MultiSelectComponent) Select(indices ...int) {
+	m.check(indices)
+	m.changed()
+}
+
+// The indices of the checked items in order
+func (m *MultiSelectComponent) Selected() []int {
+	res := []int{}
+	for i := range m.Items {
+		if m.checked[i] {
+			res = append(res, i)
+		}
+	}
+	return res
+}
+
+// The checked items in order
+func (m *MultiSelectComponent) Values() []string {
+	res := []string{}
+	for _, i := range m.Selected() {
+		res = append(res, m.Items[i])
+	}
+	return res
+}
+
+// The checked items separated by commas, for forms
+func (m *MultiSelectComponent) Value() string {
+	return strings.Join(m.Values(), ",")
+}
+
+// Go back to the items checked when it was created
+func (m *MultiSelectComponent) Reset() {
+	m.Select(m.initial...)
+}
+
+// Check the items at the indices as the initial state Reset goes back to
+func (m *MultiSelectComponent) Checked(indices ...int) *MultiSelectComponent {
+	m.initial = indices
+	m.check(indices)
+	return m
+}
+
+func (m *MultiSelectComponent) check(indices []int) {
+	m.checked = map[int]bool{}
+	for _, i := range indices {
+		if i >= 0 && i < len(m.Items) {
+			m.checked[i] = true
+		}
+	}
+}
+
+func (m *MultiSelectComponent) changed() {
+	if m.OnChange != nil {
+		m.OnChange(m, m.Selected())
+	}
+}
+
+func (m *MultiSelectComponent) Params(param MultiSelectParams) *MultiSelectComponent {
+	m.Style = osui.SetDefaults(&param.Style).(*MultiSelectStyle)
+	m.Data.Width = param.Width
+	m.Data.Height = param.Height
+	m.Keymap = MultiSelectKeymap.Merge(param.Keymap)
+	m.OnChange = param.OnChange
+	return m
+}
+
+// Check any number of the items, none are checked at the start
+func MultiSelect(items ...string) *MultiSelectComponent {
+	return &MultiSelectComponent{
+		Items:   items,
+		checked: map[int]bool{},
+		Style:   osui.SetDefaults(&MultiSelectStyle{}).(*MultiSelectStyle),
+		Keymap:  MultiSelectKeymap,
+	}
+}
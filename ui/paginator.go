@@ -6,13 +6,27 @@ import (
 
 	"github.com/orus-dev/osui"
 	"github.com/orus-dev/osui/colors"
-	"github.com/orus-dev/osui/isKey"
 )
 
+var PaginatorKeymap = osui.Keymap{
+	"next":       {Keys: []string{"\t"}, Description: "Next page"},
+	"previous":   {Keys: []string{"\x1b[Z"}, Description: "Previous page"},
+	"close":      {Keys: []string{"\x18"}, Description: "Close page"},
+	"move_left":  {Keys: []string{"\x1b[1;6D"}, Description: "Move page left"},
+	"move_right": {Keys: []string{"\x1b[1;6C"}, Description: "Move page right"},
+	"page_up":    {Keys: []string{"\x1b[5~"}, Description: "Show the previous page"},
+	"page_down":  {Keys: []string{"\x1b[6~"}, Description: "Show the next page"},
+	"exit":       {Keys: []string{"\x1b"}, Description: "Exit"},
+}
+
 type PaginatorParams struct {
-	Style  DivStyle
-	Width  int
-	Height int
+	Style    PaginatorStyle
+	Width    int
+	Height   int
+	Wrap     bool
+	PageSize int
+	Keymap   osui.Keymap
+	OnClose  func(*PaginatorComponent, int) bool
 }
 
 type PaginatorStyle struct {
@@ -25,26 +39,47 @@ type PaginatorComponent struct {
 	Style           *PaginatorStyle
 	Components      []osui.Component
 	ActiveComponent int
+	Wrap            bool
+	// The number of components shown below each other on a page with a « 2/7 » indicator above them,
+	// 0 shows one component per page with a dot for each
+	PageSize int
+	Keymap   osui.Keymap
+	// Called before a page is closed, returning true keeps the page open
+	OnClose    func(*PaginatorComponent, int) bool
+	headerRows int
 }
 
 func (p *PaginatorComponent) GetComponentData() *osui.ComponentData {
 	return &p.Data
 }
 
+func (p *PaginatorComponent) GetKeymap() osui.Keymap {
+	return p.Keymap
+}
+
+func (p *PaginatorComponent) GetChildren() []osui.Component {
+	return p.Components
+}
+
+func (p *PaginatorComponent) ChildOffset() (int, int) {
+	return 0, p.headerRows
+}
+
 func (p *PaginatorComponent) Render() string {
-	width, _ := osui.GetTerminalSize()
-	pgs := strings.Repeat(" ", (width-len(p.Components))/2)
 	osui.UseStyle(p.Style)
+	if p.PageSize > 0 {
+		return p.renderPage()
+	}
+	labels := make([]string, len(p.Components))
+	for i := range labels {
+		labels[i] = "•"
+	}
+	header := tabStrip(labels, p.ActiveComponent, p.Data.Width, "", p.Wrap, p.Style.Active, colors.Reset+p.Style.Inactive)
+	p.headerRows = len(header)
 	frame := osui.NewFrame(p.Data.Width, p.Data.Height)
 	for i, c := range p.Components {
 		data := c.GetComponentData()
-		if i == p.ActiveComponent {
-			pgs += p.Style.Active + "•" + colors.Reset
-			data.IsActive = true
-		} else {
-			pgs += colors.Reset + p.Style.Inactive + "•"
-			data.IsActive = false
-		}
+		data.IsActive = i == p.ActiveComponent
 		if data.Width == 0 {
 			data.Width = p.Data.Width
 		}
@@ -58,24 +93,62 @@ func (p *PaginatorComponent) Render() string {
 	for i, f := range frame {
 		frame[i] = colors.Reset + p.Data.DefaultColor + f + colors.Reset
 	}
-	return fmt.Sprintf("%s\n%s", pgs, colors.Reset+strings.Join(frame, "\n"))
+	return fmt.Sprintf("%s\n%s", strings.Join(header, "\n"), colors.Reset+strings.Join(frame, "\n"))
+}
+
+// The components of the page below each other under the page indicator
+func (p *PaginatorComponent) renderPage() string {
+	indicator := fmt.Sprintf("« %d/%d »", p.Page()+1, p.PageCount())
+	header := strings.Repeat(" ", max((p.Data.Width-osui.VisibleWidth(indicator))/2, 0)) + p.Style.Active + indicator + colors.Reset
+	p.headerRows = 1
+	frame := osui.NewFrame(p.Data.Width, max(p.Data.Height-1, 0))
+	from, to := p.pageRange(p.Page())
+	y := 0
+	for i := from; i < to; i++ {
+		c := p.Components[i]
+		data := c.GetComponentData()
+		data.IsActive = i == p.ActiveComponent
+		if data.Width == 0 {
+			data.Width = p.Data.Width
+		}
+		data.X, data.Y = 0, y
+		data.DefaultColor = p.Data.DefaultColor
+		data.Screen = p.Data.Screen
+		output := osui.RenderComponent(c)
+		osui.RenderOutputOnFrame(c, output, &frame)
+		y += strings.Count(output, "\n") + 1
+	}
+	for i, f := range frame {
+		frame[i] = colors.Reset + p.Data.DefaultColor + f + colors.Reset
+	}
+	return header + "\n" + colors.Reset + strings.Join(frame, "\n")
 }
 
 func (p *PaginatorComponent) Update(key string) bool {
-	if key == "\x1b[Z" {
+	if p.Keymap.Is("page_up", key) {
+		p.SetPage(max(p.Page()-1, 0))
+	} else if p.Keymap.Is("page_down", key) {
+		p.SetPage(min(p.Page()+1, p.PageCount()-1))
+	} else if p.Keymap.Is("previous", key) {
 		if p.ActiveComponent > 0 {
 			p.updateActive(p.ActiveComponent - 1)
 		} else {
 			p.updateActive(len(p.Components) - 1)
 		}
-	} else if isKey.Tab(key) {
+	} else if p.Keymap.Is("next", key) {
 		if p.ActiveComponent < len(p.Components)-1 {
 			p.updateActive(p.ActiveComponent + 1)
 		} else {
 			p.updateActive(0)
 		}
-	} else if isKey.Escape(key) {
-		fmt.Print("\n\n")
+	} else if p.Keymap.Is("close", key) {
+		p.Close(p.ActiveComponent)
+	} else if p.Keymap.Is("move_left", key) {
+		p.Move(p.ActiveComponent, -1)
+	} else if p.Keymap.Is("move_right", key) {
+		p.Move(p.ActiveComponent, 1)
+	} else if p.Keymap.Is("exit", key) {
+		fmt.Fprint(osui.Terminal, "\n\n")
 		return true
 	} else {
 		if len(p.Components) > 0 {
@@ -92,8 +165,70 @@ func (p *PaginatorComponent) Update(key string) bool {
 	return false
 }
 
+// Close a page, the last remaining page can't be closed
+func (p *PaginatorComponent) Close(i int) bool {
+	if i < 0 || i >= len(p.Components) || len(p.Components) == 1 {
+		return false
+	}
+	if p.OnClose != nil && p.OnClose(p, i) {
+		return false
+	}
+	p.Components, p.ActiveComponent = removeAt(p.Components, i, p.ActiveComponent)
+	return true
+}
+
+// Move a page by delta positions, the active page follows it
+func (p *PaginatorComponent) Move(i, delta int) bool {
+	components, active, ok := moveAt(p.Components, i, delta, p.ActiveComponent)
+	p.Components, p.ActiveComponent = components, active
+	return ok
+}
+
+// The page shown, the one with the active component
+func (p *PaginatorComponent) Page() int {
+	return p.PageOf(p.ActiveComponent)
+}
+
+func (p *PaginatorComponent) PageCount() int {
+	size := max(p.PageSize, 1)
+	return max((len(p.Components)+size-1)/size, 1)
+}
+
+// The page the component at index i is on
+func (p *PaginatorComponent) PageOf(i int) int {
+	return i / max(p.PageSize, 1)
+}
+
+// Show the page, its first component becomes active. Returns false if there is no such page or the
+// active component can't lose the focus
+func (p *PaginatorComponent) SetPage(page int) bool {
+	if page < 0 || page >= p.PageCount() {
+		return false
+	}
+	if page == p.Page() {
+		return true
+	}
+	p.updateActive(page * max(p.PageSize, 1))
+	return p.Page() == page
+}
+
+// Show the page with the component at index i and make it active
+func (p *PaginatorComponent) ShowIndex(i int) bool {
+	p.updateActive(i)
+	return p.ActiveComponent == i
+}
+
+// The indices of the first component on the page and of the one after the last
+func (p *PaginatorComponent) pageRange(page int) (int, int) {
+	size := max(p.PageSize, 1)
+	return min(page*size, len(p.Components)), min((page+1)*size, len(p.Components))
+}
+
 func (p *PaginatorComponent) updateActive(newIndex int) {
 	if newIndex >= 0 && newIndex < len(p.Components) && len(p.Components) > 0 {
+		if newIndex != p.ActiveComponent && !osui.CanBlur(p.Components[p.ActiveComponent]) {
+			return
+		}
 		p.ActiveComponent = newIndex
 	}
 }
@@ -101,12 +236,24 @@ func (p *PaginatorComponent) updateActive(newIndex int) {
 func (b *PaginatorComponent) Params(p interface{}) *PaginatorComponent {
 	param := p.(PaginatorParams)
 	b.Style = osui.SetDefaults(&param.Style).(*PaginatorStyle)
+	b.Wrap = param.Wrap
+	b.PageSize = param.PageSize
+	b.Keymap = PaginatorKeymap.Merge(param.Keymap)
+	b.OnClose = param.OnClose
 	return b
 }
 
+// Split the components into pages of size components each
+func PaginatorOf(size int, components ...osui.Component) *PaginatorComponent {
+	p := Paginator(components...)
+	p.PageSize = size
+	return p
+}
+
 func Paginator(pages ...osui.Component) *PaginatorComponent {
 	return &PaginatorComponent{
 		Components: pages,
 		Style:      osui.SetDefaults(&PaginatorStyle{}).(*PaginatorStyle),
+		Keymap:     PaginatorKeymap,
 	}
 }
@@ -0,0 +1,310 @@
+package ui
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+	"sync"
+	"unicode"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+	"github.com/orus-dev/osui/isKey"
+)
+
+var PaletteKeymap = osui.Keymap{
+	"up":     {Keys: []string{"\x1b[A", "\x10"}, Description: "Previous entry"},
+	"down":   {Keys: []string{"\x1b[B", "\x0e"}, Description: "Next entry"},
+	"select": {Keys: []string{"\r", "\n"}, Description: "Select entry"},
+	"cancel": {Keys: []string{"\x1b"}, Description: "Close the palette"},
+}
+
+type PaletteParams struct {
+	Style    PaletteStyle
+	Width    int
+	Height   int
+	Prompt   string
+	Keymap   osui.Keymap
+	OnSelect func(*PaletteComponent, PaletteItem)
+	OnCancel func(*PaletteComponent)
+}
+
+type PaletteStyle struct {
+	Prompt     string `default:"\x1b[36m" type:"fg"`
+	Foreground string `default:"" type:"fg"`
+	Detail     string `default:"\x1b[2m" type:"fg"`
+	Match      string `default:"\x1b[1;33m" type:"fg"`
+	Selected   string `default:"\x1b[7m" type:"bg"`
+	Count      string `default:"\x1b[2m" type:"fg"`
+}
+
+type PaletteItem struct {
+	Label string
+	// Shown dimmed after the label, not matched
+	Detail string
+	Value  any
+}
+
+type paletteMatch struct {
+	item      int
+	score     int
+	positions []int
+}
+
+type PaletteComponent struct {
+	Data   osui.ComponentData
+	Style  *PaletteStyle
+	Prompt string
+	Query  string
+	Keymap osui.Keymap
+	// Called with the entry chosen with Enter, the palette is done after it
+	OnSelect func(*PaletteComponent, PaletteItem)
+	OnCancel func(*PaletteComponent)
+	Selected int
+
+	mu      sync.Mutex
+	items   []PaletteItem
+	matches []paletteMatch
+	offset  int
+	loading bool
+}
+
+func (p *PaletteComponent) GetComponentData() *osui.ComponentData {
+	return &p.Data
+}
+
+func (p *PaletteComponent) GetKeymap() osui.Keymap {
+	return p.Keymap
+}
+
+// The query on top, the matching entries below it with the matched characters highlighted
+func (p *PaletteComponent) Render() string {
+	style := osui.StateStyle(&p.Data, p.Style)
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	reset := colors.Reset + p.Data.DefaultColor
+	count := fmt.Sprintf(" %d/%d", len(p.matches), len(p.items))
+	if p.loading {
+		count = " …" + count
+	}
+	query := osui.TruncateVisible(p.Query, max(p.Data.Width-osui.VisibleWidth(p.Prompt)-osui.VisibleWidth(count)-1, 0))
+	cursor := osui.LogicValue(p.Data.IsActive, colors.Reverse+" "+reset, " ")
+	line := style.Prompt + p.Prompt + reset + style.Foreground + query + reset + cursor
+	gap := max(p.Data.Width-osui.VisibleWidth(line)-osui.VisibleWidth(count), 0)
+	res := []string{line + strings.Repeat(" ", gap) + style.Count + count + reset}
+
+	rows := max(p.Data.Height-1, 0)
+	if p.Selected < p.offset {
+		p.offset = p.Selected
+	} else if p.Selected >= p.offset+rows {
+		p.offset = p.Selected - rows + 1
+	}
+	for i := p.offset; i < len(p.matches) && i < p.offset+rows; i++ {
+		m := p.matches[i]
+		item := p.items[m.item]
+		text := highlight(item.Label, m.positions, style.Match, colors.Reset+style.Foreground)
+		if item.Detail != "" {
+			text += " " + style.Detail + item.Detail + colors.Reset + style.Foreground
+		}
+		text = osui.TruncateVisible("  "+style.Foreground+text, p.Data.Width)
+		if i == p.Selected && p.Data.IsActive {
+			text = osui.WithStyle(text+strings.Repeat(" ", max(p.Data.Width-osui.VisibleWidth(text), 0)), style.Selected)
+		}
+		res = append(res, text+reset)
+	}
+	return strings.Join(res, "\n")
+}
+
+func (p *PaletteComponent) Update(key string) bool {
+	if p.Keymap.Is("cancel", key) {
+		if p.OnCancel != nil {
+			p.OnCancel(p)
+		}
+		return true
+	} else if p.Keymap.Is("select", key) {
+		item, ok := p.Current()
+		if !ok {
+			return false
+		}
+		if p.OnSelect != nil {
+			p.OnSelect(p, item)
+		}
+		return true
+	} else if p.Keymap.Is("up", key) {
+		p.Scroll(0, -1)
+	} else if p.Keymap.Is("down", key) {
+		p.Scroll(0, 1)
+	} else if isKey.Backspace(key) {
+		p.SetQuery(dropLastGrapheme(p.Query))
+	} else if isKey.Printable(key) {
+		p.SetQuery(p.Query + key)
+	}
+	return false
+}
+
+func (p *PaletteComponent) Scroll(dx, dy int) bool {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	if dy == 0 || len(p.matches) == 0 {
+		return false
+	}
+	p.Selected = min(max(p.Selected+dy, 0), len(p.matches)-1)
+	return true
+}
+
+func (p *PaletteComponent) Paste(text string) {
+	p.SetQuery(p.Query + strings.NewReplacer("\r\n", " ", "\n", " ", "\r", " ").Replace(text))
+}
+
+// Change the query and match every entry against it again
+func (p *PaletteComponent) SetQuery(query string) {
+	p.mu.Lock()
+	p.Query = query
+	p.matches = p.matches[:0]
+	p.match(0)
+	p.Selected, p.offset = 0, 0
+	p.mu.Unlock()
+}
+
+// Get the selected entry, false if nothing matches
+func (p *PaletteComponent) Current() (PaletteItem, bool) {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	if p.Selected >= len(p.matches) {
+		return PaletteItem{}, false
+	}
+	return p.items[p.matches[p.Selected].item], true
+}
+
+// Add entries, safe to call from any goroutine so large sets can be loaded in the background.
+// Only the new entries are matched against the query
+func (p *PaletteComponent) Add(items ...PaletteItem) {
+	p.mu.Lock()
+	from := len(p.items)
+	p.items = append(p.items, items...)
+	p.match(from)
+	p.mu.Unlock()
+	p.requestRender()
+}
+
+// Replace all entries, safe to call from any goroutine
+func (p *PaletteComponent) SetItems(items ...PaletteItem) {
+	p.mu.Lock()
+	p.items = append([]PaletteItem{}, items...)
+	p.matches = p.matches[:0]
+	p.match(0)
+	p.Selected, p.offset = 0, 0
+	p.mu.Unlock()
+	p.requestRender()
+}
+
+// Show that entries are still being added, safe to call from any goroutine
+func (p *PaletteComponent) SetLoading(loading bool) {
+	p.mu.Lock()
+	p.loading = loading
+	p.mu.Unlock()
+	p.requestRender()
+}
+
+// Score the entries from index from on and keep the matches sorted from the best
+func (p *PaletteComponent) match(from int) {
+	selected := -1
+	if p.Selected < len(p.matches) {
+		selected = p.matches[p.Selected].item
+	}
+	for i := from; i < len(p.items); i++ {
+		if score, positions, ok := FuzzyMatch(p.Query, p.items[i].Label); ok {
+			p.matches = append(p.matches, paletteMatch{item: i, score: score, positions: positions})
+		}
+	}
+	sort.SliceStable(p.matches, func(i, j int) bool {
+		return p.matches[i].score > p.matches[j].score
+	})
+	// Entries loaded in the background shouldn't move the selection away from the entry under it
+	for i, m := range p.matches {
+		if m.item == selected {
+			p.Selected = i
+		}
+	}
+}
+
+func (p *PaletteComponent) requestRender() {
+	if p.Data.Screen != nil {
+		p.Data.Screen.RequestRender()
+	}
+}
+
+func (p *PaletteComponent) Params(param PaletteParams) *PaletteComponent {
+	p.Style = osui.SetDefaults(&param.Style).(*PaletteStyle)
+	p.Data.Width = osui.LogicValueInt(param.Width == 0, 40, param.Width)
+	p.Data.Height = osui.LogicValueInt(param.Height == 0, 12, param.Height)
+	p.Prompt = osui.LogicValue(param.Prompt == "", "> ", param.Prompt)
+	p.Keymap = PaletteKeymap.Merge(param.Keymap)
+	p.OnSelect = param.OnSelect
+	p.OnCancel = param.OnCancel
+	return p
+}
+
+// Match pattern as a subsequence of text ignoring case, returns the score and the rune positions of the matched characters.
+// Matches at the start of words and runs of consecutive characters score higher, gaps score lower
+func FuzzyMatch(pattern, text string) (int, []int, bool) {
+	if pattern == "" {
+		return 0, nil, true
+	}
+	p, t := []rune(strings.ToLower(pattern)), []rune(text)
+	positions := make([]int, 0, len(p))
+	score, last := 0, -1
+	for i, j := 0, 0; i < len(t) && j < len(p); i++ {
+		if unicode.ToLower(t[i]) != p[j] {
+			continue
+		}
+		score++
+		if i == 0 || strings.ContainsRune(" _-./\\:", t[i-1]) || (unicode.IsUpper(t[i]) && unicode.IsLower(t[i-1])) {
+			score += 8
+		}
+		if last >= 0 {
+			if i == last+1 {
+				score += 5
+			} else {
+				score -= min(i-last-1, 3)
+			}
+		}
+		positions = append(positions, i)
+		last = i
+		j++
+	}
+	if len(positions) < len(p) {
+		return 0, nil, false
+	}
+	// Prefer shorter entries when the matches are equally good
+	return score*4 - len(t)/8, positions, true
+}
+
+func highlight(text string, positions []int, match, normal string) string {
+	var res strings.Builder
+	next := 0
+	for i, r := range []rune(text) {
+		if next < len(positions) && positions[next] == i {
+			res.WriteString(match + string(r) + normal)
+			next++
+		} else {
+			res.WriteRune(r)
+		}
+	}
+	return res.String()
+}
+
+// A fuzzy finder over the items, type to filter them and Enter to pick one
+func Palette(items ...PaletteItem) *PaletteComponent {
+	p := &PaletteComponent{
+		Prompt: "> ",
+		Style:  osui.SetDefaults(&PaletteStyle{}).(*PaletteStyle),
+		Keymap: PaletteKeymap,
+		Data: osui.ComponentData{
+			Width:  40,
+			Height: 12,
+		},
+	}
+	p.SetItems(items...)
+	return p
+}
@@ -0,0 +1,297 @@
+package ui
+
+import (
+	"math"
+	"strings"
+	"unicode"
+	"unicode/utf8"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+var ParagraphKeymap = osui.Keymap{
+	"up":        {Keys: []string{"\x1b[A"}, Description: "Scroll up"},
+	"down":      {Keys: []string{"\x1b[B"}, Description: "Scroll down"},
+	"page_up":   {Keys: []string{"\x1b[5~"}, Description: "Scroll a page up"},
+	"page_down": {Keys: []string{"\x1b[6~"}, Description: "Scroll a page down"},
+	"top":       {Keys: []string{"\x1b[H", "\x1b[1~"}, Description: "Scroll to the top"},
+	"bottom":    {Keys: []string{"\x1b[F", "\x1b[4~"}, Description: "Scroll to the bottom"},
+}
+
+type ParagraphParams struct {
+	Style    ParagraphStyle
+	Width    int
+	Height   int
+	Markdown bool
+	Keymap   osui.Keymap
+}
+
+type ParagraphStyle struct {
+	Foreground string `default:"" type:"fg"`
+	Background string `default:"" type:"bg"`
+	Heading    string `default:"\x1b[1m" type:"fg"`
+	Code       string `default:"\x1b[36m" type:"fg"`
+	Link       string `default:"\x1b[34m\x1b[4m" type:"fg"`
+}
+
+type ParagraphComponent struct {
+	Data   osui.ComponentData
+	Style  *ParagraphStyle
+	Text   string
+	Keymap osui.Keymap
+	// Render **bold**, *italics*, `code`, [links](url) and # headings
+	Markdown  bool
+	offset    int
+	lineCount int
+}
+
+type span struct {
+	text  string
+	style string
+	link  string
+}
+
+func (p *ParagraphComponent) GetComponentData() *osui.ComponentData {
+	return &p.Data
+}
+
+func (p *ParagraphComponent) GetKeymap() osui.Keymap {
+	return p.Keymap
+}
+
+func (p *ParagraphComponent) Render() string {
+	osui.UseStyle(p.Style)
+	width := max(p.Data.Width, 1)
+	base := colors.Combine(p.Style.Foreground, p.Style.Background)
+	lines := []string{}
+	for _, l := range strings.Split(p.Text, "\n") {
+		for _, spans := range wrapSpans(p.parse(l), width) {
+			var line strings.Builder
+			line.WriteString(base)
+			for _, s := range spans {
+				line.WriteString(s.style + osui.Hyperlink(s.link, s.text) + colors.Reset + base)
+			}
+			lines = append(lines, osui.TruncateVisible(line.String(), width)+colors.Reset+p.Data.DefaultColor)
+		}
+	}
+	p.lineCount = len(lines)
+	if p.Data.Height > 0 {
+		p.offset = min(p.offset, max(len(lines)-p.Data.Height, 0))
+		lines = lines[p.offset:min(p.offset+p.Data.Height, len(lines))]
+	}
+	return strings.Join(lines, "\n")
+}
+
+func (p *ParagraphComponent) Update(key string) bool {
+	page := max(p.Data.Height-1, 1)
+	switch {
+	case p.Keymap.Is("up", key):
+		p.Scroll(0, -1)
+	case p.Keymap.Is("down", key):
+		p.Scroll(0, 1)
+	case p.Keymap.Is("page_up", key):
+		p.Scroll(0, -page)
+	case p.Keymap.Is("page_down", key):
+		p.Scroll(0, page)
+	case p.Keymap.Is("top", key):
+		p.offset = 0
+	case p.Keymap.Is("bottom", key):
+		p.offset = max(p.lineCount-p.Data.Height, 0)
+	}
+	return false
+}
+
+func (p *ParagraphComponent) Scroll(dx, dy int) bool {
+	old := p.offset
+	p.offset = min(max(p.offset+dy, 0), max(p.lineCount-p.Data.Height, 0))
+	return p.offset != old
+}
+
+func (p *ParagraphComponent) SetText(text string) {
+	p.Text = text
+	p.offset = 0
+}
+
+// Add a line at the end, a paragraph scrolled to the end stays there
+func (p *ParagraphComponent) AppendLine(line string) {
+	following := p.Data.Height == 0 || p.offset >= p.lineCount-p.Data.Height
+	if p.Text != "" {
+		p.Text += "\n"
+	}
+	p.Text += line
+	if following {
+		// The render clamps it to the last page
+		p.offset = math.MaxInt
+	}
+	if p.Data.Screen != nil {
+		p.Data.Screen.RequestRender()
+	}
+}
+
+func (p *ParagraphComponent) Params(param ParagraphParams) *ParagraphComponent {
+	p.Style = osui.SetDefaults(&param.Style).(*ParagraphStyle)
+	p.Data.Width = osui.LogicValueInt(param.Width == 0, 40, param.Width)
+	p.Data.Height = param.Height
+	p.Markdown = param.Markdown
+	p.Keymap = ParagraphKeymap.Merge(param.Keymap)
+	return p
+}
+
+// Split a source line into styled spans
+func (p *ParagraphComponent) parse(line string) []span {
+	if !p.Markdown {
+		return []span{{text: line}}
+	}
+	base := ""
+	if trimmed := strings.TrimLeft(line, "#"); trimmed != line && strings.HasPrefix(trimmed, " ") && len(line)-len(trimmed) <= 6 {
+		base = p.Style.Heading
+		line = strings.TrimSpace(trimmed)
+	}
+
+	spans := []span{}
+	bold, italic := false, false
+	var text strings.Builder
+	flush := func() {
+		if text.Len() > 0 {
+			spans = append(spans, span{text: text.String(), style: base + osui.LogicValue(bold, colors.Bold, "") + osui.LogicValue(italic, colors.Italic, "")})
+			text.Reset()
+		}
+	}
+	for i := 0; i < len(line); i++ {
+		switch {
+		case line[i] == '`':
+			if end := strings.IndexByte(line[i+1:], '`'); end >= 0 {
+				flush()
+				spans = append(spans, span{text: line[i+1 : i+1+end], style: base + p.Style.Code})
+				i += end + 1
+				continue
+			}
+		case line[i] == '[':
+			if end := strings.Index(line[i:], "]("); end > 0 {
+				if paren := strings.IndexByte(line[i+end:], ')'); paren >= 0 {
+					flush()
+					link := span{text: line[i+1 : i+end], style: base + p.Style.Link, link: line[i+end+2 : i+end+paren]}
+					if !osui.Settings.Hyperlinks {
+						// Wrapped links would repeat the url after every line of them
+						link.text, link.link = osui.Hyperlink(link.link, link.text), ""
+					}
+					spans = append(spans, link)
+					i += end + paren
+					continue
+				}
+			}
+		case line[i] == '*' || line[i] == '_':
+			// One delimiter is italic, two are bold and three are both
+			n := delimiterRun(line, i)
+			closes := n == 1 && italic || n == 2 && bold || n == 3 && bold && italic
+			open, closing := flanking(line, i, n)
+			if n <= 3 && (closing && closes || open && !closes && closedLater(line, i, n)) {
+				flush()
+				if n != 2 {
+					italic = !italic
+				}
+				if n >= 2 {
+					bold = !bold
+				}
+			} else {
+				text.WriteString(line[i : i+n])
+			}
+			i += n - 1
+			continue
+		}
+		text.WriteByte(line[i])
+	}
+	flush()
+	if len(spans) == 0 {
+		spans = append(spans, span{style: base})
+	}
+	return spans
+}
+
+// The length of the run of the delimiter at i
+func delimiterRun(line string, i int) int {
+	n := 1
+	for i+n < len(line) && line[i+n] == line[i] {
+		n++
+	}
+	return n
+}
+
+// Whether the run of n delimiters at i can open and close emphasis, by the flanking rules of CommonMark. A run
+// opens when it isn't followed by white space, so "2 * 3" stays as it is, and "_" doesn't work inside words
+func flanking(line string, i, n int) (bool, bool) {
+	before, after := ' ', ' '
+	if i > 0 {
+		before, _ = utf8.DecodeLastRuneInString(line[:i])
+	}
+	if i+n < len(line) {
+		after, _ = utf8.DecodeRuneInString(line[i+n:])
+	}
+	punct := func(r rune) bool {
+		return unicode.IsPunct(r) || unicode.IsSymbol(r)
+	}
+	left := !unicode.IsSpace(after) && (!punct(after) || unicode.IsSpace(before) || punct(before))
+	right := !unicode.IsSpace(before) && (!punct(before) || unicode.IsSpace(after) || punct(after))
+	if line[i] == '_' {
+		return left && (!right || punct(before)), right && (!left || punct(after))
+	}
+	return left, right
+}
+
+// Whether a run of as many of the same delimiters closes the one at i later on the line, an opener without
+// one is shown as it is
+func closedLater(line string, i, n int) bool {
+	for j := i + n; j < len(line); j++ {
+		if line[j] != line[i] {
+			continue
+		}
+		m := delimiterRun(line, j)
+		if _, closing := flanking(line, j, m); closing && m == n {
+			return true
+		}
+		j += m - 1
+	}
+	return false
+}
+
+// Wrap the spans at spaces into lines of at most width cells, words longer than a line are split
+func wrapSpans(spans []span, width int) [][]span {
+	lines := [][]span{{}}
+	used := 0
+	newLine := func() {
+		lines = append(lines, []span{})
+		used = 0
+	}
+	for _, s := range spans {
+		for _, word := range strings.SplitAfter(s.text, " ") {
+			if word == "" {
+				continue
+			}
+			if used > 0 && used+osui.VisibleWidth(strings.TrimRight(word, " ")) > width {
+				newLine()
+			}
+			for used == 0 && osui.VisibleWidth(strings.TrimRight(word, " ")) > width {
+				part := osui.TruncateVisible(word, width)
+				if part == "" {
+					part = osui.Graphemes(word)[0]
+				}
+				lines[len(lines)-1] = append(lines[len(lines)-1], span{text: part, style: s.style, link: s.link})
+				newLine()
+				word = word[len(part):]
+			}
+			lines[len(lines)-1] = append(lines[len(lines)-1], span{text: word, style: s.style, link: s.link})
+			used += osui.VisibleWidth(word)
+		}
+	}
+	return lines
+}
+
+func Paragraph(text string) *ParagraphComponent {
+	return &ParagraphComponent{
+		Text:   text,
+		Style:  osui.SetDefaults(&ParagraphStyle{}).(*ParagraphStyle),
+		Keymap: ParagraphKeymap,
+		Data:   osui.ComponentData{Width: 40},
+	}
+}
@@ -0,0 +1,29 @@
+package ui
+
+import (
+	"reflect"
+	"testing"
+
+	"github.com/orus-dev/osui/colors"
+)
+
+func TestParagraphEmphasis(t *testing.T) {
+	p := Paragraph("")
+	p.Markdown = true
+	tests := []struct {
+		line string
+		want []span
+	}{
+		{"2 * 3 = 6", []span{{text: "2 * 3 = 6"}}},
+		{"snake_case_name", []span{{text: "snake_case_name"}}},
+		{"a *lone star", []span{{text: "a *lone star"}}},
+		{"an *italic* word", []span{{text: "an "}, {text: "italic", style: colors.Italic}, {text: " word"}}},
+		{"_under_ and **bold**", []span{{text: "under", style: colors.Italic}, {text: " and "}, {text: "bold", style: colors.Bold}}},
+		{"***both***", []span{{text: "both", style: colors.Bold + colors.Italic}}},
+	}
+	for _, test := range tests {
+		if got := p.parse(test.line); !reflect.DeepEqual(got, test.want) {
+			t.Errorf("%q: got %+v, want %+v", test.line, got, test.want)
+		}
+	}
+}
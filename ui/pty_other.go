@@ -0,0 +1,17 @@
+//go:build !linux
+
+package ui
+
+import (
+	"errors"
+	"os"
+	"os/exec"
+)
+
+func startPty(*exec.Cmd, int, int) (*os.File, error) {
+	return nil, errors.New("terminals are only supported on linux")
+}
+
+func resizePty(*os.File, int, int) error {
+	return nil
+}
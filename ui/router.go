@@ -0,0 +1,286 @@
+package ui
+
+import (
+	"net/url"
+	"strings"
+	"time"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+const (
+	TransitionSlideLeft  = "slide-left"
+	TransitionSlideRight = "slide-right"
+	TransitionFade       = "fade"
+)
+
+var RouterKeymap = osui.Keymap{
+	"back":    {Keys: []string{"\x1b[1;3D", "\x1bb"}, Description: "Go back"},
+	"forward": {Keys: []string{"\x1b[1;3C", "\x1bf"}, Description: "Go forward"},
+}
+
+type RouterParams struct {
+	Transition string
+	Duration   time.Duration
+	Keymap     osui.Keymap
+}
+
+type RouteContext struct {
+	Path   string
+	Params map[string]string
+	Query  url.Values
+	Arg    any
+	// The part of the path matched by a trailing "*", passed on to nested routers
+	Rest string
+}
+
+// Get the navigation argument as a T
+func RouteArg[T any](ctx RouteContext) (T, bool) {
+	arg, ok := ctx.Arg.(T)
+	return arg, ok
+}
+
+// A route path like "/users/:id", a trailing "*" matches the rest of the path
+type Route struct {
+	Path  string
+	Build func(RouteContext) osui.Component
+}
+
+type historyEntry struct {
+	component osui.Component
+	context   RouteContext
+}
+
+type RouterComponent struct {
+	Data       osui.ComponentData
+	Routes     []Route
+	Current    osui.Component
+	Context    RouteContext
+	Transition string
+	Duration   time.Duration
+	Keymap     osui.Keymap
+
+	history      []historyEntry
+	historyIndex int
+
+	lastOutput    string
+	previous      string
+	progress      float64
+	transitioning bool
+	animation     *osui.Animation
+}
+
+func (r *RouterComponent) GetComponentData() *osui.ComponentData {
+	return &r.Data
+}
+
+func (r *RouterComponent) GetKeymap() osui.Keymap {
+	return r.Keymap
+}
+
+func (r *RouterComponent) GetChildren() []osui.Component {
+	if r.Current == nil {
+		return nil
+	}
+	return []osui.Component{r.Current}
+}
+
+func (r *RouterComponent) ChildOffset() (int, int) {
+	return 0, 0
+}
+
+func (r *RouterComponent) Render() string {
+	frame := osui.NewFrame(r.Data.Width, r.Data.Height)
+	if r.Current != nil {
+		data := r.Current.GetComponentData()
+		data.IsActive = r.Data.IsActive
+		if data.Width == 0 {
+			data.Width = r.Data.Width
+		}
+		if data.Height == 0 {
+			data.Height = r.Data.Height
+		}
+		data.DefaultColor = r.Data.DefaultColor
+		data.Screen = r.Data.Screen
+		if r.transitioning {
+			r.renderTransition(&frame)
+		} else {
+			osui.RenderOnFrame(r.Current, &frame)
+		}
+	}
+	r.lastOutput = strings.Join(frame, "\n")
+	return r.lastOutput
+}
+
+func (r *RouterComponent) renderTransition(frame *[]string) {
+	current := osui.RenderComponent(r.Current)
+	offset := int(float64(r.Data.Width) * r.progress)
+	switch r.Transition {
+	case TransitionSlideLeft:
+		osui.DrawOutput(frame, r.previous, -offset, 0)
+		osui.DrawOutput(frame, current, r.Data.Width-offset, 0)
+	case TransitionSlideRight:
+		osui.DrawOutput(frame, r.previous, offset, 0)
+		osui.DrawOutput(frame, current, offset-r.Data.Width, 0)
+	default:
+		lines := strings.Split(osui.LogicValue(r.progress < 0.5, r.previous, current), "\n")
+		for i, line := range lines {
+			lines[i] = osui.WithStyle(line, colors.Dim)
+		}
+		osui.DrawOutput(frame, strings.Join(lines, "\n"), 0, 0)
+	}
+}
+
+// Play the transition from the previous output to the current screen, the screen steps it before every frame
+func (r *RouterComponent) animate(previous string) {
+	if r.animation != nil {
+		r.animation.Stop()
+		r.animation = nil
+		r.transitioning = false
+	}
+	if r.Transition == "" || osui.Settings.ReducedMotion || r.Data.Screen == nil || previous == "" {
+		return
+	}
+	duration := r.Duration
+	if duration == 0 {
+		duration = 200 * time.Millisecond
+	}
+	r.previous = previous
+	r.progress = 0
+	r.transitioning = true
+	a := osui.NewAnimation(0, 1, duration, osui.Linear)
+	a.OnUpdate = func(v float64) {
+		r.progress = v
+	}
+	a.OnComplete = func() {
+		r.animation = nil
+		r.transitioning = false
+		r.previous = ""
+	}
+	r.animation = r.Data.Screen.Animate(a)
+}
+
+func (r *RouterComponent) Update(key string) bool {
+	if r.Keymap.Is("back", key) {
+		r.Back()
+		return false
+	} else if r.Keymap.Is("forward", key) {
+		r.Forward()
+		return false
+	}
+	if r.Current == nil {
+		return false
+	}
+	return r.Current.Update(key)
+}
+
+// Go to the previous screen, it keeps the state it was left with
+func (r *RouterComponent) Back() bool {
+	if r.historyIndex <= 0 {
+		return false
+	}
+	r.historyIndex--
+	r.show(r.history[r.historyIndex])
+	return true
+}
+
+func (r *RouterComponent) Forward() bool {
+	if r.historyIndex >= len(r.history)-1 {
+		return false
+	}
+	r.historyIndex++
+	r.show(r.history[r.historyIndex])
+	return true
+}
+
+func (r *RouterComponent) show(entry historyEntry) {
+	r.Current = entry.component
+	r.Context = entry.context
+	r.animate(r.lastOutput)
+}
+
+// Build the screen of the first route matching the path
+func (r *RouterComponent) Navigate(path string) bool {
+	return r.NavigateWith(path, nil)
+}
+
+// Build the screen of the first route matching the path, the builder gets arg in its context
+func (r *RouterComponent) NavigateWith(path string, arg any) bool {
+	path, rawQuery, _ := strings.Cut(path, "?")
+	query, _ := url.ParseQuery(rawQuery)
+	for _, route := range r.Routes {
+		params, rest, ok := matchRoute(route.Path, path)
+		if !ok {
+			continue
+		}
+		ctx := RouteContext{Path: path, Params: params, Query: query, Arg: arg, Rest: rest}
+		c := route.Build(ctx)
+		if rest != "" {
+			if rawQuery != "" {
+				rest += "?" + rawQuery
+			}
+			osui.Walk(c, func(child osui.Component) bool {
+				if n, isNavigator := child.(osui.Navigator); isNavigator {
+					n.NavigateWith(rest, arg)
+					return false
+				}
+				return true
+			})
+		}
+		if len(r.history) > 0 {
+			r.history = r.history[:r.historyIndex+1]
+		}
+		r.history = append(r.history, historyEntry{component: c, context: ctx})
+		r.historyIndex = len(r.history) - 1
+		r.show(r.history[r.historyIndex])
+		return true
+	}
+	return false
+}
+
+func matchRoute(pattern, path string) (map[string]string, string, bool) {
+	patternSegments := splitPath(pattern)
+	pathSegments := splitPath(path)
+	params := map[string]string{}
+	for i, seg := range patternSegments {
+		if seg == "*" {
+			return params, "/" + strings.Join(pathSegments[i:], "/"), true
+		}
+		if i >= len(pathSegments) {
+			return nil, "", false
+		}
+		if strings.HasPrefix(seg, ":") {
+			params[seg[1:]] = pathSegments[i]
+		} else if seg != pathSegments[i] {
+			return nil, "", false
+		}
+	}
+	if len(pathSegments) != len(patternSegments) {
+		return nil, "", false
+	}
+	return params, "", true
+}
+
+func splitPath(path string) []string {
+	res := []string{}
+	for _, seg := range strings.Split(path, "/") {
+		if seg != "" {
+			res = append(res, seg)
+		}
+	}
+	return res
+}
+
+func (r *RouterComponent) Params(param RouterParams) *RouterComponent {
+	r.Transition = param.Transition
+	r.Duration = param.Duration
+	r.Keymap = RouterKeymap.Merge(param.Keymap)
+	return r
+}
+
+func Router(routes ...Route) *RouterComponent {
+	r := &RouterComponent{Routes: routes, Keymap: RouterKeymap}
+	r.Navigate("/")
+	return r
+}
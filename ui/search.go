@@ -0,0 +1,178 @@
+package ui
+
+import (
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+	"github.com/orus-dev/osui/isKey"
+)
+
+// Lists that can show only the items with the query in them, an empty query shows all of them again
+type Filterable interface {
+	osui.Component
+	SetQuery(query string)
+}
+
+var SearchableListKeymap = osui.Keymap{
+	"clear": {Keys: []string{"\x1b"}, Description: "Clear the filter"},
+}
+
+type SearchableListParams struct {
+	Style       SearchableListStyle
+	Width       int
+	Height      int
+	Placeholder string
+	Keymap      osui.Keymap
+	OnChange    func(*SearchableListComponent, string)
+}
+
+type SearchableListStyle struct {
+	Prompt      string `default:"\x1b[36m" type:"fg"`
+	Foreground  string `default:"" type:"fg"`
+	Placeholder string `default:"\x1b[2m" type:"fg"`
+}
+
+// A filter line above a list, typing narrows the list to the items with the text in them and Esc shows all of
+// them again. Printable keys edit the filter unless the list binds them, like Space in a multi-select, the other
+// keys go to the list
+type SearchableListComponent struct {
+	Data        osui.ComponentData
+	Style       *SearchableListStyle
+	List        Filterable
+	Placeholder string
+	Keymap      osui.Keymap
+	// Called with the filter whenever it changes
+	OnChange func(*SearchableListComponent, string)
+	query    string
+}
+
+func (s *SearchableListComponent) GetComponentData() *osui.ComponentData {
+	return &s.Data
+}
+
+func (s *SearchableListComponent) GetKeymap() osui.Keymap {
+	return s.Keymap
+}
+
+func (s *SearchableListComponent) GetChildren() []osui.Component {
+	return []osui.Component{s.List}
+}
+
+func (s *SearchableListComponent) ChildOffset() (int, int) {
+	return 0, 1
+}
+
+func (s *SearchableListComponent) Render() string {
+	style := osui.StateStyle(&s.Data, s.Style)
+	reset := colors.Reset + s.Data.DefaultColor
+	line := style.Prompt + "/ " + reset
+	if s.query == "" && !s.Data.IsActive {
+		line += style.Placeholder + s.Placeholder + reset
+	} else {
+		line += style.Foreground + s.query + reset + osui.LogicValue(s.Data.IsActive, colors.Reverse+" "+reset, "")
+	}
+	if s.Data.Width > 0 {
+		line = osui.TruncateVisible(line, s.Data.Width)
+	}
+	frame := osui.NewFrame(s.Data.Width, max(s.Data.Height-1, 0))
+	data := s.List.GetComponentData()
+	data.X, data.Y = 0, 0
+	if data.Width == 0 {
+		data.Width = s.Data.Width
+	}
+	if s.Data.Height > 0 {
+		data.Height = len(frame)
+	}
+	data.IsActive = s.Data.IsActive
+	data.DefaultColor = s.Data.DefaultColor
+	data.Screen = s.Data.Screen
+	if s.Data.Height == 0 {
+		return line + reset + "\n" + osui.RenderComponent(s.List)
+	}
+	osui.RenderOnFrame(s.List, &frame)
+	return line + reset + "\n" + strings.Join(frame, "\n")
+}
+
+func (s *SearchableListComponent) Update(key string) bool {
+	bound := false
+	if o, ok := s.List.(osui.KeymapOwner); ok {
+		_, bound = o.GetKeymap().Action(key)
+	}
+	switch {
+	case s.Keymap.Is("clear", key) && s.query != "":
+		s.SetQuery("")
+	case isKey.Backspace(key) && s.query != "":
+		s.SetQuery(dropLastGrapheme(s.query))
+	case isKey.Printable(key) && !bound:
+		s.SetQuery(s.query + key)
+	default:
+		s.List.GetComponentData().IsActive = s.Data.IsActive
+		return s.List.Update(key)
+	}
+	return false
+}
+
+func (s *SearchableListComponent) Paste(text string) {
+	s.SetQuery(s.query + strings.NewReplacer("\r\n", " ", "\n", " ", "\r", " ").Replace(text))
+}
+
+// Change the filter and narrow the list to it
+func (s *SearchableListComponent) SetQuery(query string) {
+	if query == s.query {
+		return
+	}
+	s.query = query
+	s.List.SetQuery(query)
+	if s.OnChange != nil {
+		s.OnChange(s, query)
+	}
+}
+
+func (s *SearchableListComponent) Query() string {
+	return s.query
+}
+
+func (s *SearchableListComponent) Params(param SearchableListParams) *SearchableListComponent {
+	s.Style = osui.SetDefaults(&param.Style).(*SearchableListStyle)
+	s.Data.Width = param.Width
+	s.Data.Height = param.Height
+	s.Placeholder = osui.LogicValue(param.Placeholder == "", "Type to filter", param.Placeholder)
+	s.Keymap = SearchableListKeymap.Merge(param.Keymap)
+	s.OnChange = param.OnChange
+	return s
+}
+
+// Put a filter line above the list, like SearchableList(Menu(items...))
+func SearchableList(list Filterable) *SearchableListComponent {
+	return &SearchableListComponent{
+		List:        list,
+		Placeholder: "Type to filter",
+		Style:       osui.SetDefaults(&SearchableListStyle{}).(*SearchableListStyle),
+		Keymap:      SearchableListKeymap,
+	}
+}
+
+// Whether the text has the query in it, ignoring case
+func containsFold(text, query string) bool {
+	return strings.Contains(strings.ToLower(text), strings.ToLower(query))
+}
+
+// Highlight every place the query is found in the text, ignoring case
+func highlightMatches(text, query, match, normal string) string {
+	if query == "" {
+		return text
+	}
+	var b strings.Builder
+	lower, query := strings.ToLower(text), strings.ToLower(query)
+	for {
+		i := strings.Index(lower, query)
+		// Lower casing can change the length of some characters, those are left as they are
+		if i < 0 || len(lower) != len(text) {
+			b.WriteString(text)
+			return b.String()
+		}
+		b.WriteString(text[:i] + match + text[i:i+len(query)] + normal)
+		text, lower = text[i+len(query):], lower[i+len(query):]
+	}
+}
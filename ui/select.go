@@ -0,0 +1,109 @@
+package ui
+
+import (
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+	"github.com/orus-dev/osui/isKey"
+)
+
+var SelectKeymap = osui.Keymap{
+	"previous": {Keys: []string{"\x1b[D"}, Description: "Previous option"},
+	"next":     {Keys: []string{"\x1b[C", " "}, Description: "Next option"},
+}
+
+type SelectParams struct {
+	Style    SelectStyle
+	Keymap   osui.Keymap
+	OnChange func(*SelectComponent, string)
+}
+
+type SelectStyle struct {
+	Foreground string `default:"" type:"fg"`
+	Active     string `default:"\x1b[34m" type:"fg"`
+	Arrows     string `default:"\x1b[2m" type:"fg"`
+}
+
+type SelectComponent struct {
+	Data     osui.ComponentData
+	Style    *SelectStyle
+	Options  []string
+	Selected int
+	Keymap   osui.Keymap
+	OnChange func(*SelectComponent, string)
+	initial  int
+}
+
+func (s *SelectComponent) GetComponentData() *osui.ComponentData {
+	return &s.Data
+}
+
+func (s *SelectComponent) GetKeymap() osui.Keymap {
+	return s.Keymap
+}
+
+func (s *SelectComponent) Render() string {
+	style := osui.StateStyle(&s.Data, s.Style)
+	color := osui.LogicValue(s.Data.IsActive, style.Active, style.Foreground)
+	return style.Arrows + "‹ " + colors.Reset + color + s.Value() + colors.Reset + style.Arrows + " ›" + colors.Reset + s.Data.DefaultColor
+}
+
+// The arrows cycle through the options, Enter moves on
+func (s *SelectComponent) Update(key string) bool {
+	if s.Data.Disabled {
+		return false
+	}
+	if len(s.Options) > 0 && s.Keymap.Is("previous", key) {
+		s.Select((s.Selected + len(s.Options) - 1) % len(s.Options))
+	} else if len(s.Options) > 0 && s.Keymap.Is("next", key) {
+		s.Select((s.Selected + 1) % len(s.Options))
+	}
+	return isKey.Enter(key)
+}
+
+func (s *SelectComponent) Select(i int) {
+	if i < 0 || i >= len(s.Options) || i == s.Selected {
+		return
+	}
+	s.Selected = i
+	if s.OnChange != nil {
+		s.OnChange(s, s.Options[i])
+	}
+}
+
+// Select the option with the value, returns false if there is none
+func (s *SelectComponent) SelectValue(value string) bool {
+	for i, o := range s.Options {
+		if o == value {
+			s.Select(i)
+			return true
+		}
+	}
+	return false
+}
+
+func (s *SelectComponent) Value() string {
+	if s.Selected >= len(s.Options) {
+		return ""
+	}
+	return s.Options[s.Selected]
+}
+
+func (s *SelectComponent) Reset() {
+	s.Select(s.initial)
+}
+
+func (s *SelectComponent) Params(param SelectParams) *SelectComponent {
+	s.Style = osui.SetDefaults(&param.Style).(*SelectStyle)
+	s.Keymap = SelectKeymap.Merge(param.Keymap)
+	s.OnChange = param.OnChange
+	return s
+}
+
+// Pick one of the options, the first one starts selected
+func Select(options ...string) *SelectComponent {
+	return &SelectComponent{
+		Options: options,
+		Style:   osui.SetDefaults(&SelectStyle{}).(*SelectStyle),
+		Keymap:  SelectKeymap,
+	}
+}
@@ -0,0 +1,124 @@
+package ui
+
+import (
+	"fmt"
+	"reflect"
+	"strconv"
+	"strings"
+
+	"github.com/orus-dev/osui"
+)
+
+// Build a form editing the exported fields of the config struct, onSave gets the edited copy once every field is valid.
+//
+// The setting tag sets the label and the kind of a field like `setting:"Dark mode,toggle"`. The kind is toggle, select,
+// number or text and defaults to the one fitting the type of the field, `setting:"-"` leaves the field out.
+// Selects take their options from the options tag like `options:"light,dark"` and numbers are checked against
+// the min and max tags. The ids of the fields follow Decode, so the form tag renames them
+func SettingsForm[T any](cfg T, onSave func(T)) *FormComponent {
+	val := reflect.ValueOf(cfg)
+	if val.Kind() != reflect.Struct {
+		panic("SettingsForm: expected a struct")
+	}
+	typ := val.Type()
+	fields := []osui.Component{}
+	for i := 0; i < typ.NumField(); i++ {
+		structField := typ.Field(i)
+		tag := structField.Tag.Get("setting")
+		if !structField.IsExported() || tag == "-" {
+			continue
+		}
+		label, kind, _ := strings.Cut(tag, ",")
+		if label == "" {
+			label = structField.Name
+		}
+		c := settingField(structField, val.Field(i), label, kind)
+		if c == nil {
+			continue
+		}
+		id, ok := structField.Tag.Lookup("form")
+		if !ok {
+			id = strings.ToLower(structField.Name)
+		}
+		c.GetComponentData().Id = id
+		if _, isToggle := c.(*ToggleComponent); !isToggle {
+			fields = append(fields, Text(label))
+		}
+		fields = append(fields, c)
+	}
+	f := Form(fields...)
+	f.OnSubmit = func(f *FormComponent, _ map[string]string) {
+		res := reflect.New(typ)
+		res.Elem().Set(val)
+		if err := f.Decode(res.Interface()); err == nil && onSave != nil {
+			onSave(res.Elem().Interface().(T))
+		}
+	}
+	f.Fields = append(f.Fields, Button("Save").Params(ButtonParams{
+		OnClick: func(*ButtonComponent) bool {
+			f.Submit()
+			return false
+		},
+	}))
+	return f
+}
+
+func settingField(structField reflect.StructField, field reflect.Value, label, kind string) osui.Component {
+	value := fmt.Sprint(field.Interface())
+	if kind == "" {
+		switch {
+		case field.Kind() == reflect.Bool:
+			kind = "toggle"
+		case structField.Tag.Get("options") != "":
+			kind = "select"
+		case field.Kind() == reflect.String:
+			kind = "text"
+		case field.CanInt() || field.CanUint() || field.CanFloat():
+			kind = "number"
+		default:
+			return nil
+		}
+	}
+	switch kind {
+	case "toggle":
+		return Toggle(label, value == "true")
+	case "select":
+		s := Select(strings.Split(structField.Tag.Get("options"), ",")...)
+		s.SelectValue(value)
+		s.initial = s.Selected
+		return s
+	case "number":
+		input := InputBox(20).Params(InputBoxParams{Validator: numberValidator(field.Kind(), structField.Tag), ShowError: true})
+		input.InputData = value
+		return input
+	case "text":
+		input := InputBox(30).Params(InputBoxParams{Width: 32})
+		input.InputData = value
+		return input
+	}
+	return nil
+}
+
+// Check that the text parses as the kind of number and lies between the min and max tags
+func numberValidator(kind reflect.Kind, tag reflect.StructTag) func(string) error {
+	return func(text string) error {
+		var err error
+		switch kind {
+		case reflect.Int, reflect.Int8, reflect.Int16, reflect.Int32, reflect.Int64:
+			_, err = strconv.ParseInt(text, 10, 64)
+		case reflect.Uint, reflect.Uint8, reflect.Uint16, reflect.Uint32, reflect.Uint64:
+			_, err = strconv.ParseUint(text, 10, 64)
+		}
+		n, ferr := strconv.ParseFloat(text, 64)
+		if err != nil || ferr != nil {
+			return fmt.Errorf("%q is not a valid number", text)
+		}
+		if low, err := strconv.ParseFloat(tag.Get("min"), 64); err == nil && n < low {
+			return fmt.Errorf("must be at least %s", tag.Get("min"))
+		}
+		if high, err := strconv.ParseFloat(tag.Get("max"), 64); err == nil && n > high {
+			return fmt.Errorf("must be at most %s", tag.Get("max"))
+		}
+		return nil
+	}
+}
@@ -0,0 +1,149 @@
+package ui
+
+import (
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+var ShortcutRecorderKeymap = osui.Keymap{
+	"record": {Keys: []string{"\r", "\n"}, Description: "Record a shortcut"},
+	"cancel": {Keys: []string{"\x1b"}, Description: "Stop recording"},
+	"clear":  {Keys: []string{"\x7f", "\b"}, Description: "Clear the shortcut"},
+}
+
+type ShortcutRecorderParams struct {
+	Style    ShortcutRecorderStyle
+	Width    int
+	Keymap   osui.Keymap
+	Bindings osui.Keymap
+	Action   string
+	OnRecord func(*ShortcutRecorderComponent, string)
+}
+
+type ShortcutRecorderStyle struct {
+	Outline    string `default:"" type:"fg"`
+	Foreground string `default:"" type:"fg"`
+	Recording  string `default:"\x1b[33m" type:"fg"`
+	Empty      string `default:"\x1b[2m" type:"fg"`
+	Conflict   string `default:"\x1b[31m" type:"fg"`
+}
+
+type ShortcutRecorderComponent struct {
+	Data   osui.ComponentData
+	Style  *ShortcutRecorderStyle
+	Key    string
+	Keymap osui.Keymap
+	// The bindings checked for conflicts, a key bound to another action than Action is shown as a conflict
+	Bindings osui.Keymap
+	Action   string
+	// Called with the raw key of the recorded chord, osui.KeyName makes it readable
+	OnRecord  func(*ShortcutRecorderComponent, string)
+	recording bool
+}
+
+func (s *ShortcutRecorderComponent) GetComponentData() *osui.ComponentData {
+	return &s.Data
+}
+
+func (s *ShortcutRecorderComponent) GetKeymap() osui.Keymap {
+	return s.Keymap
+}
+
+// While recording the screen bindings like undo are recorded too
+func (s *ShortcutRecorderComponent) CapturesKeys() bool {
+	return s.recording
+}
+
+func (s *ShortcutRecorderComponent) Render() string {
+	style := osui.StateStyle(&s.Data, s.Style)
+	g := osui.Settings.Glyphs
+	width := max(s.Data.Width-2, 0)
+	text, color := osui.KeyName(s.Key), style.Foreground
+	if s.recording {
+		text, color = "Press a key…", style.Recording
+	} else if s.Key == "" {
+		text, color = "None", style.Empty
+	} else if action, conflict := s.Conflict(); conflict {
+		text, color = text+" ("+action+")", style.Conflict
+	}
+	text = osui.TruncateEllipsis(text, width)
+	left := (width - osui.VisibleWidth(text)) / 2
+	text = strings.Repeat(" ", left) + text + strings.Repeat(" ", width-osui.VisibleWidth(text)-left)
+	return colors.Reset + style.Outline + g.TopBorder(s.Data.Width) + colors.Reset + s.Data.DefaultColor + "\n" +
+		colors.Reset + style.Outline + g.Left + colors.Reset + color + text + colors.Reset + style.Outline + g.Right + colors.Reset + s.Data.DefaultColor + "\n" +
+		colors.Reset + style.Outline + g.BottomBorder(s.Data.Width) + colors.Reset + s.Data.DefaultColor
+}
+
+// Enter starts recording and the next key is taken as the shortcut, Escape stops recording without one
+func (s *ShortcutRecorderComponent) Update(key string) bool {
+	if !s.recording {
+		if s.Keymap.Is("record", key) {
+			s.recording = true
+		} else if s.Keymap.Is("clear", key) {
+			s.Record("")
+		}
+		return false
+	}
+	s.recording = false
+	if !s.Keymap.Is("cancel", key) {
+		s.Record(key)
+	}
+	return false
+}
+
+// Set the shortcut as if it was pressed
+func (s *ShortcutRecorderComponent) Record(key string) {
+	s.Key = key
+	if s.OnRecord != nil {
+		s.OnRecord(s, key)
+	}
+}
+
+func (s *ShortcutRecorderComponent) Recording() bool {
+	return s.recording
+}
+
+// Get the other action the recorded key is already bound to
+func (s *ShortcutRecorderComponent) Conflict() (string, bool) {
+	if s.Key == "" || s.Bindings == nil {
+		return "", false
+	}
+	action, ok := s.Bindings.Action(s.Key)
+	if !ok || action == s.Action {
+		return "", false
+	}
+	return action, true
+}
+
+func (s *ShortcutRecorderComponent) Value() string {
+	return s.Key
+}
+
+func (s *ShortcutRecorderComponent) Reset() {
+	s.Key, s.recording = "", false
+}
+
+func (s *ShortcutRecorderComponent) Params(param ShortcutRecorderParams) *ShortcutRecorderComponent {
+	s.Style = osui.SetDefaults(&param.Style).(*ShortcutRecorderStyle)
+	s.Data.Width = osui.LogicValueInt(param.Width == 0, 20, param.Width)
+	s.Keymap = ShortcutRecorderKeymap.Merge(param.Keymap)
+	s.Bindings = param.Bindings
+	s.Action = param.Action
+	s.OnRecord = param.OnRecord
+	return s
+}
+
+// An input that records the next key chord, starting with key which may be empty
+func ShortcutRecorder(key string) *ShortcutRecorderComponent {
+	return &ShortcutRecorderComponent{
+		Key:    key,
+		Style:  osui.SetDefaults(&ShortcutRecorderStyle{}).(*ShortcutRecorderStyle),
+		Keymap: ShortcutRecorderKeymap,
+		Data: osui.ComponentData{
+			Width:  20,
+			Height: 3,
+		},
+	}
+}
@@ -0,0 +1,125 @@
+package ui
+
+import (
+	"strings"
+
+	"github.com/orus-dev/osui"
+)
+
+const (
+	SlotHeader = "header"
+	SlotBody   = "body"
+	SlotFooter = "footer"
+)
+
+var slotOrder = []string{SlotHeader, SlotBody, SlotFooter}
+
+// Named children of composite components like cards and dialogs
+type Slots map[string]osui.Component
+
+// Get the slots that are set, from the top to the bottom
+func (s Slots) Ordered() []osui.Component {
+	res := []osui.Component{}
+	for _, name := range slotOrder {
+		if c, ok := s[name]; ok && c != nil {
+			res = append(res, c)
+		}
+	}
+	return res
+}
+
+type LayoutComponent struct {
+	Data  osui.ComponentData
+	Slots Slots
+	// The slot that gets the keys
+	ActiveSlot string
+}
+
+func (l *LayoutComponent) GetComponentData() *osui.ComponentData {
+	return &l.Data
+}
+
+func (l *LayoutComponent) GetChildren() []osui.Component {
+	return l.Slots.Ordered()
+}
+
+func (l *LayoutComponent) ChildOffset() (int, int) {
+	return 0, 0
+}
+
+// The header sticks to the top and the footer to the bottom, the body gets the rows between them
+func (l *LayoutComponent) Render() string {
+	frame := osui.NewFrame(l.Data.Width, l.Data.Height)
+	outputs := map[string]string{}
+	heights := map[string]int{}
+	for _, name := range []string{SlotHeader, SlotFooter} {
+		if c := l.Slots[name]; c != nil {
+			l.prepare(name, c)
+			outputs[name] = osui.RenderComponent(c)
+			heights[name] = osui.LogicValueInt(c.GetComponentData().Height == 0, strings.Count(outputs[name], "\n")+1, c.GetComponentData().Height)
+		}
+	}
+	if c := l.Slots[SlotBody]; c != nil {
+		l.prepare(SlotBody, c)
+		data := c.GetComponentData()
+		data.Height = max(l.Data.Height-heights[SlotHeader]-heights[SlotFooter], 0)
+		outputs[SlotBody] = osui.RenderComponent(c)
+	}
+	y := map[string]int{
+		SlotHeader: 0,
+		SlotBody:   heights[SlotHeader],
+		SlotFooter: l.Data.Height - heights[SlotFooter],
+	}
+	for _, name := range slotOrder {
+		if c := l.Slots[name]; c != nil {
+			data := c.GetComponentData()
+			data.X, data.Y = 0, y[name]
+			osui.PushClip(osui.Rect{X: 0, Y: y[name], Width: l.Data.Width, Height: osui.LogicValueInt(name == SlotBody, data.Height, heights[name])})
+			osui.RenderOutputOnFrame(c, outputs[name], &frame)
+			osui.PopClip()
+		}
+	}
+	return strings.Join(frame, "\n")
+}
+
+func (l *LayoutComponent) Update(key string) bool {
+	c := l.Slots[l.ActiveSlot]
+	if c == nil || !c.Update(key) {
+		return false
+	}
+	// Move on to the next slot that is set, the layout is done after the last one
+	for i, name := range slotOrder {
+		if name != l.ActiveSlot {
+			continue
+		}
+		for _, next := range slotOrder[i+1:] {
+			if l.Slots[next] != nil && osui.CanBlur(c) {
+				l.ActiveSlot = next
+				return false
+			}
+		}
+	}
+	return true
+}
+
+func (l *LayoutComponent) prepare(name string, c osui.Component) {
+	data := c.GetComponentData()
+	data.Width = l.Data.Width
+	data.IsActive = l.Data.IsActive && name == l.ActiveSlot
+	data.DefaultColor = l.Data.DefaultColor
+	data.Screen = l.Data.Screen
+}
+
+// Lay out the header, body and footer slots, the body starts focused if it is set
+func Layout(slots Slots) *LayoutComponent {
+	l := &LayoutComponent{Slots: slots, ActiveSlot: SlotBody}
+	if slots[SlotBody] == nil {
+		for _, name := range slotOrder {
+			if slots[name] != nil {
+				l.ActiveSlot = name
+				break
+			}
+		}
+	}
+	return l
+}
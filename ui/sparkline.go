@@ -0,0 +1,85 @@
+package ui
+
+import (
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+type SparklineParams struct {
+	Style SparklineStyle
+	Max   float64
+	Bind  *osui.State[[]float64]
+}
+
+type SparklineStyle struct {
+	Foreground string `default:"" type:"fg"`
+	Background string `default:"" type:"bg"`
+}
+
+type SparklineComponent struct {
+	Data   osui.ComponentData
+	Style  *SparklineStyle
+	Values []float64
+	// The value drawn as a full column, 0 scales to the largest value
+	Max float64
+}
+
+func (s *SparklineComponent) GetComponentData() *osui.ComponentData {
+	return &s.Data
+}
+
+func (s *SparklineComponent) Render() string {
+	osui.UseStyle(s.Style)
+	height := max(s.Data.Height, 1)
+	values := s.Values
+	if s.Data.Width > 0 && len(values) > s.Data.Width {
+		values = values[len(values)-s.Data.Width:]
+	}
+	top := s.Max
+	if top == 0 {
+		top = maxValue(values)
+	}
+	rows := make([][]rune, height)
+	for _, v := range values {
+		for i, r := range barCells(v, top, height) {
+			rows[i] = append(rows[i], r)
+		}
+	}
+	lines := make([]string, height)
+	for i, row := range rows {
+		lines[i] = colors.Combine(s.Style.Foreground, s.Style.Background) + string(row) + colors.Reset + s.Data.DefaultColor
+	}
+	return strings.Join(lines, "\n")
+}
+
+func (s *SparklineComponent) Update(key string) bool {
+	return false
+}
+
+// Show the values of the state, updated whenever it changes
+func (s *SparklineComponent) Bind(state *osui.State[[]float64]) *SparklineComponent {
+	s.Values = state.Get()
+	state.SubscribeFor(s, func(v []float64) {
+		s.Values = v
+	})
+	return s
+}
+
+func (s *SparklineComponent) Params(param SparklineParams) *SparklineComponent {
+	s.Style = osui.SetDefaults(&param.Style).(*SparklineStyle)
+	s.Max = param.Max
+	if param.Bind != nil {
+		s.Bind(param.Bind)
+	}
+	return s
+}
+
+func Sparkline(values ...float64) *SparklineComponent {
+	return &SparklineComponent{
+		Values: values,
+		Style:  osui.SetDefaults(&SparklineStyle{}).(*SparklineStyle),
+		Data:   osui.ComponentData{Height: 1},
+	}
+}
@@ -0,0 +1,166 @@
+package ui
+
+import (
+	"math"
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+var SplitKeymap = osui.Keymap{
+	"grow":   {Keys: []string{"\x1b[1;4C", "\x1b[1;4B"}, Description: "Grow the first pane"},
+	"shrink": {Keys: []string{"\x1b[1;4D", "\x1b[1;4A"}, Description: "Shrink the first pane"},
+	"switch": {Keys: []string{"\x1b[17~"}, Description: "Focus the other pane"},
+}
+
+type SplitParams struct {
+	Style    SplitStyle
+	Width    int
+	Height   int
+	Vertical bool
+	Ratio    float64
+	MinSize  int
+	Step     float64
+	Keymap   osui.Keymap
+}
+
+type SplitStyle struct {
+	Divider       string `default:"\x1b[2m" type:"fg"`
+	ActiveDivider string `default:"" type:"fg"`
+}
+
+type SplitComponent struct {
+	Data   osui.ComponentData
+	Style  *SplitStyle
+	First  osui.Component
+	Second osui.Component
+	// Stack the panes from top to bottom instead of side by side
+	Vertical bool
+	// The share of the space the first pane gets
+	Ratio float64
+	// The smallest size of a pane in cells, in the direction of the split
+	MinSize int
+	// How much the keys move the divider
+	Step   float64
+	Keymap osui.Keymap
+	// 0 while the first pane has the focus, 1 for the second
+	Active int
+}
+
+func (s *SplitComponent) GetComponentData() *osui.ComponentData {
+	return &s.Data
+}
+
+func (s *SplitComponent) GetKeymap() osui.Keymap {
+	return s.Keymap
+}
+
+func (s *SplitComponent) GetChildren() []osui.Component {
+	return []osui.Component{s.First, s.Second}
+}
+
+func (s *SplitComponent) ChildOffset() (int, int) {
+	return 0, 0
+}
+
+// Get the size of the first pane, the divider takes the cell after it
+func (s *SplitComponent) FirstSize() int {
+	total := osui.LogicValueInt(s.Vertical, s.Data.Height, s.Data.Width) - 1
+	size := int(math.Round(float64(total) * s.Ratio))
+	return max(min(size, total-s.MinSize), min(s.MinSize, total/2), 0)
+}
+
+func (s *SplitComponent) Render() string {
+	style := osui.StateStyle(&s.Data, s.Style)
+	g := osui.Settings.Glyphs
+	frame := osui.NewFrame(s.Data.Width, s.Data.Height)
+	first := s.FirstSize()
+	for i, c := range s.GetChildren() {
+		data := c.GetComponentData()
+		data.IsActive = s.Data.IsActive && i == s.Active
+		data.DefaultColor = s.Data.DefaultColor
+		data.Screen = s.Data.Screen
+		if s.Vertical {
+			data.X, data.Y = 0, osui.LogicValueInt(i == 0, 0, first+1)
+			data.Width, data.Height = s.Data.Width, osui.LogicValueInt(i == 0, first, s.Data.Height-first-1)
+		} else {
+			data.X, data.Y = osui.LogicValueInt(i == 0, 0, first+1), 0
+			data.Width, data.Height = osui.LogicValueInt(i == 0, first, s.Data.Width-first-1), s.Data.Height
+		}
+		osui.PushClip(osui.Rect{X: data.X, Y: data.Y, Width: data.Width, Height: data.Height})
+		osui.RenderOnFrame(c, &frame)
+		osui.PopClip()
+	}
+	color := osui.LogicValue(s.Data.IsActive && style.ActiveDivider != "", style.ActiveDivider, style.Divider)
+	if s.Vertical {
+		osui.DrawOutput(&frame, color+strings.Repeat(g.Horizontal, s.Data.Width)+colors.Reset+s.Data.DefaultColor, 0, first)
+	} else {
+		osui.DrawOutput(&frame, strings.Repeat(color+g.Vertical+colors.Reset+s.Data.DefaultColor+"\n", max(s.Data.Height-1, 0))+color+g.Vertical+colors.Reset, first, 0)
+	}
+	return strings.Join(frame, "\n")
+}
+
+func (s *SplitComponent) Update(key string) bool {
+	pane := s.GetChildren()[s.Active]
+	pane.GetComponentData().IsActive = s.Data.IsActive
+	// A pane capturing the keys, like a running terminal, gets the bindings of the split too
+	capturing := osui.Captures(pane)
+	if s.Keymap.Is("grow", key) && !capturing {
+		s.SetRatio(s.Ratio + s.Step)
+	} else if s.Keymap.Is("shrink", key) && !capturing {
+		s.SetRatio(s.Ratio - s.Step)
+	} else if s.Keymap.Is("switch", key) && !capturing {
+		s.Focus(1 - s.Active)
+	} else {
+		if pane.Update(key) {
+			if s.Active == 1 {
+				return true
+			}
+			s.Focus(1)
+		}
+	}
+	return false
+}
+
+// Move the divider, the ratio is kept between 0 and 1 and the panes keep their minimum size when drawn
+func (s *SplitComponent) SetRatio(ratio float64) {
+	s.Ratio = min(max(ratio, 0), 1)
+}
+
+// Focus the first pane with 0 or the second one with 1, unless the focused pane keeps the focus
+func (s *SplitComponent) Focus(pane int) {
+	if pane < 0 || pane > 1 || pane == s.Active || !osui.Focusable(s.GetChildren()[pane]) || !osui.CanBlur(s.GetChildren()[s.Active]) {
+		return
+	}
+	s.Active = pane
+}
+
+func (s *SplitComponent) Params(param SplitParams) *SplitComponent {
+	s.Style = osui.SetDefaults(&param.Style).(*SplitStyle)
+	s.Data.Width = param.Width
+	s.Data.Height = param.Height
+	s.Vertical = param.Vertical
+	s.Ratio, s.Step = 0.5, 0.05
+	if param.Ratio != 0 {
+		s.SetRatio(param.Ratio)
+	}
+	if param.Step != 0 {
+		s.Step = param.Step
+	}
+	s.MinSize = param.MinSize
+	s.Keymap = SplitKeymap.Merge(param.Keymap)
+	return s
+}
+
+// Lay out two panes side by side with a divider between them, like a sidebar and the main view
+func Split(first, second osui.Component) *SplitComponent {
+	return &SplitComponent{
+		First:  first,
+		Second: second,
+		Ratio:  0.5,
+		Step:   0.05,
+		Style:  osui.SetDefaults(&SplitStyle{}).(*SplitStyle),
+		Keymap: SplitKeymap,
+	}
+}
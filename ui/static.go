@@ -0,0 +1,64 @@
+package ui
+
+import (
+	"strings"
+
+	"github.com/orus-dev/osui"
+)
+
+// Draws its child once and reuses the output on the next frames, until Refresh is called or the size, states
+// or styles of the child or its descendants change. Wrap big parts of a ui that rarely change in it
+type StaticComponent struct {
+	Data    osui.ComponentData
+	Child   osui.Component
+	version uint64
+}
+
+func (s *StaticComponent) GetComponentData() *osui.ComponentData {
+	return &s.Data
+}
+
+func (s *StaticComponent) GetChildren() []osui.Component {
+	return []osui.Component{s.Child}
+}
+
+func (s *StaticComponent) ChildOffset() (int, int) {
+	return 0, 0
+}
+
+func (s *StaticComponent) Version() uint64 {
+	return s.version
+}
+
+func (s *StaticComponent) Render() string {
+	data := s.Child.GetComponentData()
+	data.IsActive = s.Data.IsActive
+	if data.Width == 0 {
+		data.Width = s.Data.Width
+	}
+	if data.Height == 0 {
+		data.Height = s.Data.Height
+	}
+	data.DefaultColor = s.Data.DefaultColor
+	data.Screen = s.Data.Screen
+	frame := osui.NewFrame(s.Data.Width, s.Data.Height)
+	osui.RenderOnFrame(s.Child, &frame)
+	return strings.Join(frame, "\n")
+}
+
+func (s *StaticComponent) Update(key string) bool {
+	return s.Child.Update(key)
+}
+
+// Render the child again on the next frame, call it after changing it
+func (s *StaticComponent) Refresh() {
+	s.version++
+	if s.Data.Screen != nil {
+		s.Data.Screen.RequestRender()
+	}
+}
+
+// Reuse the output of the child while it doesn't change, like Static(Div(...)) for a sidebar
+func Static(child osui.Component) *StaticComponent {
+	return &StaticComponent{Child: child}
+}
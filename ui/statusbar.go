@@ -0,0 +1,111 @@
+package ui
+
+import (
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+type StatusBarParams struct {
+	Style StatusBarStyle
+	Top   bool
+	Gap   string
+}
+
+type StatusBarStyle struct {
+	Background string `default:"\x1b[47m" type:"bg"`
+	Foreground string `default:"\x1b[30m" type:"fg"`
+}
+
+type StatusBarComponent struct {
+	Data   osui.ComponentData
+	Style  *StatusBarStyle
+	Left   []osui.Component
+	Center []osui.Component
+	Right  []osui.Component
+	// Pin the bar to the top row of the terminal instead of the bottom one
+	Top bool
+	// Written between the components of a section, the separator glyph between spaces when empty
+	Gap string
+}
+
+func (s *StatusBarComponent) GetComponentData() *osui.ComponentData {
+	return &s.Data
+}
+
+func (s *StatusBarComponent) Render() string {
+	osui.UseStyle(s.Style)
+	width, height := osui.GetTerminalSize()
+	if s.Data.Screen != nil {
+		width, height = s.Data.Screen.Size()
+	}
+	s.Data.X, s.Data.Y = 0, osui.LogicValueInt(s.Top, 0, height-1)
+	s.Data.Width, s.Data.Height = width, 1
+
+	// The left section keeps its space first, then the right one and the center gets what is left
+	style := colors.Combine(s.Style.Foreground, s.Style.Background)
+	left := osui.TruncateEllipsis(s.section(s.Left, style), width)
+	right := osui.TruncateEllipsis(s.section(s.Right, style), max(width-osui.VisibleWidth(left)-1, 0))
+	lw, rw := osui.VisibleWidth(left), osui.VisibleWidth(right)
+	center := osui.TruncateEllipsis(s.section(s.Center, style), max(width-lw-rw-2, 0))
+	cw := osui.VisibleWidth(center)
+	cx := min(max((width-cw)/2, lw+1), width-rw-1-cw)
+	if cw == 0 {
+		cx = lw
+	}
+
+	return style + left +
+		strings.Repeat(" ", max(cx-lw, 0)) + center +
+		strings.Repeat(" ", max(width-cx-cw-rw, 0)) + right +
+		colors.Reset + s.Data.DefaultColor
+}
+
+func (s *StatusBarComponent) Update(key string) bool {
+	return false
+}
+
+func (s *StatusBarComponent) SetLeft(c ...osui.Component) *StatusBarComponent {
+	s.Left = c
+	return s
+}
+
+func (s *StatusBarComponent) SetCenter(c ...osui.Component) *StatusBarComponent {
+	s.Center = c
+	return s
+}
+
+func (s *StatusBarComponent) SetRight(c ...osui.Component) *StatusBarComponent {
+	s.Right = c
+	return s
+}
+
+func (s *StatusBarComponent) Params(param StatusBarParams) *StatusBarComponent {
+	s.Style = osui.SetDefaults(&param.Style).(*StatusBarStyle)
+	s.Top = param.Top
+	s.Gap = param.Gap
+	return s
+}
+
+// Render the first line of every component of a section, separated by the gap
+func (s *StatusBarComponent) section(components []osui.Component, style string) string {
+	parts := []string{}
+	for _, c := range components {
+		data := c.GetComponentData()
+		data.DefaultColor = style
+		data.Screen = s.Data.Screen
+		data.IsActive = false
+		if output := strings.SplitN(osui.RenderComponent(c), "\n", 2)[0]; output != "" {
+			parts = append(parts, output)
+		}
+	}
+	return strings.Join(parts, osui.LogicValue(s.Gap == "", " "+osui.Settings.Glyphs.Separator+" ", s.Gap))
+}
+
+// A bar on the bottom row of the terminal, add it to a screen layer so it is drawn over the content
+func StatusBar() *StatusBarComponent {
+	return &StatusBarComponent{
+		Style: osui.SetDefaults(&StatusBarStyle{}).(*StatusBarStyle),
+		Data:  osui.ComponentData{Height: 1},
+	}
+}
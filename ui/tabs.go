@@ -0,0 +1,174 @@
+package ui
+
+import (
+	"strings"
+
+	"github.com/orus-dev/osui"
+)
+
+// The keys bound by the active tab, or by the focused component in it, go to the tab
+var TabsKeymap = osui.Keymap{
+	"next":       {Keys: []string{"\t", "\x1b[C"}, Description: "Next tab"},
+	"previous":   {Keys: []string{"\x1b[Z", "\x1b[D"}, Description: "Previous tab"},
+	"close":      {Keys: []string{"\x18"}, Description: "Close tab"},
+	"move_left":  {Keys: []string{"\x1b[1;6D"}, Description: "Move tab left"},
+	"move_right": {Keys: []string{"\x1b[1;6C"}, Description: "Move tab right"},
+}
+
+type TabsParams struct {
+	Style       TabsStyle
+	Wrap        bool
+	Keymap      osui.Keymap
+	OnTabChange func(*TabsComponent, int)
+	OnClose     func(*TabsComponent, int) bool
+}
+
+type TabsStyle struct {
+	Active    string `default:"\x1b[34m" type:"fg"`
+	Inactive  string `default:"" type:"fg"`
+	Separator string `default:""`
+}
+
+type TabItem struct {
+	Title     string
+	Component osui.Component
+}
+
+type TabsComponent struct {
+	Data        osui.ComponentData
+	Style       *TabsStyle
+	Tabs        []TabItem
+	ActiveTab   int
+	Wrap        bool
+	Keymap      osui.Keymap
+	OnTabChange func(*TabsComponent, int)
+	// Called before a tab is closed, returning true keeps the tab open
+	OnClose    func(*TabsComponent, int) bool
+	headerRows int
+}
+
+func (t *TabsComponent) GetComponentData() *osui.ComponentData {
+	return &t.Data
+}
+
+func (t *TabsComponent) GetKeymap() osui.Keymap {
+	return t.Keymap
+}
+
+func (t *TabsComponent) GetChildren() []osui.Component {
+	children := make([]osui.Component, len(t.Tabs))
+	for i, tab := range t.Tabs {
+		children[i] = tab.Component
+	}
+	return children
+}
+
+func (t *TabsComponent) ChildOffset() (int, int) {
+	return 0, t.headerRows
+}
+
+func (t *TabsComponent) Render() string {
+	osui.UseStyle(t.Style)
+	titles := make([]string, len(t.Tabs))
+	for i, tab := range t.Tabs {
+		titles[i] = " " + tab.Title + " "
+	}
+	separator := osui.LogicValue(t.Style.Separator == "", osui.Settings.Glyphs.Separator, t.Style.Separator)
+	header := tabStrip(titles, t.ActiveTab, t.Data.Width, separator, t.Wrap, t.Style.Active, t.Style.Inactive)
+	t.headerRows = len(header)
+	frame := osui.NewFrame(t.Data.Width, max(t.Data.Height-t.headerRows, 0))
+	for i, tab := range t.Tabs {
+		data := tab.Component.GetComponentData()
+		data.IsActive = i == t.ActiveTab && t.Data.IsActive
+		if data.Width == 0 {
+			data.Width = t.Data.Width
+		}
+		if data.Height == 0 {
+			data.Height = len(frame)
+		}
+		data.DefaultColor = t.Data.DefaultColor
+		data.Screen = t.Data.Screen
+	}
+	if t.ActiveTab < len(t.Tabs) {
+		osui.RenderOnFrame(t.Tabs[t.ActiveTab].Component, &frame)
+	}
+	return strings.Join(append(header, frame...), "\n")
+}
+
+func (t *TabsComponent) Update(key string) bool {
+	if len(t.Tabs) == 0 {
+		return false
+	}
+	active := t.Tabs[t.ActiveTab].Component
+	active.GetComponentData().IsActive = t.Data.IsActive
+	action, bound := t.Keymap.Action(key)
+	if !bound || osui.Captures(active) || osui.Binds(active, key) {
+		return active.Update(key)
+	}
+	switch action {
+	case "next":
+		t.SetActive((t.ActiveTab + 1) % len(t.Tabs))
+	case "previous":
+		t.SetActive((t.ActiveTab + len(t.Tabs) - 1) % len(t.Tabs))
+	case "close":
+		t.Close(t.ActiveTab)
+	case "move_left":
+		t.Move(t.ActiveTab, -1)
+	case "move_right":
+		t.Move(t.ActiveTab, 1)
+	}
+	return false
+}
+
+// Close a tab, the last remaining tab can't be closed
+func (t *TabsComponent) Close(i int) bool {
+	if i < 0 || i >= len(t.Tabs) || len(t.Tabs) == 1 {
+		return false
+	}
+	if t.OnClose != nil && t.OnClose(t, i) {
+		return false
+	}
+	t.Tabs, t.ActiveTab = removeAt(t.Tabs, i, t.ActiveTab)
+	return true
+}
+
+// Move a tab by delta positions, the active tab follows it
+func (t *TabsComponent) Move(i, delta int) bool {
+	tabs, active, ok := moveAt(t.Tabs, i, delta, t.ActiveTab)
+	t.Tabs, t.ActiveTab = tabs, active
+	return ok
+}
+
+func (t *TabsComponent) SetActive(i int) {
+	if i < 0 || i >= len(t.Tabs) || i == t.ActiveTab {
+		return
+	}
+	if t.ActiveTab < len(t.Tabs) && !osui.CanBlur(t.Tabs[t.ActiveTab].Component) {
+		return
+	}
+	t.ActiveTab = i
+	if t.OnTabChange != nil {
+		t.OnTabChange(t, i)
+	}
+}
+
+func (t *TabsComponent) Params(param TabsParams) *TabsComponent {
+	t.Style = osui.SetDefaults(&param.Style).(*TabsStyle)
+	t.Wrap = param.Wrap
+	t.Keymap = TabsKeymap.Merge(param.Keymap)
+	t.OnTabChange = param.OnTabChange
+	t.OnClose = param.OnClose
+	return t
+}
+
+func Tab(title string, c osui.Component) TabItem {
+	return TabItem{Title: title, Component: c}
+}
+
+func Tabs(tabs ...TabItem) *TabsComponent {
+	return &TabsComponent{
+		Tabs:   tabs,
+		Style:  osui.SetDefaults(&TabsStyle{}).(*TabsStyle),
+		Keymap: TabsKeymap,
+	}
+}
@@ -0,0 +1,189 @@
+package ui
+
+import (
+	"os"
+	"os/exec"
+	"strings"
+	"sync"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+var TerminalKeymap = osui.Keymap{
+	"leave": {Keys: []string{"\x1d"}, Description: "Move the focus out of the terminal"},
+}
+
+type TerminalParams struct {
+	Style  TerminalStyle
+	Width  int
+	Height int
+	OnExit func(*TerminalComponent, int)
+	Keymap osui.Keymap
+}
+
+type TerminalStyle struct {
+	Foreground string `default:"" type:"fg"`
+	Background string `default:"" type:"bg"`
+	Error      string `default:"\x1b[31m" type:"fg"`
+}
+
+// A program running in a pseudo terminal, like a shell. Every key but the leave key goes to the program while it
+// is focused. The program starts with the first render, at the size of the component
+type TerminalComponent struct {
+	Data   osui.ComponentData
+	Style  *TerminalStyle
+	Cmd    *exec.Cmd
+	Keymap osui.Keymap
+	// Called on the ui loop with the exit code once the program is done
+	OnExit func(*TerminalComponent, int)
+	mu     sync.Mutex
+	vt     *vtScreen
+	pty    *os.File
+	err    error
+	exited bool
+}
+
+func (t *TerminalComponent) GetComponentData() *osui.ComponentData {
+	return &t.Data
+}
+
+func (t *TerminalComponent) GetKeymap() osui.Keymap {
+	return t.Keymap
+}
+
+// While the program runs and the component is focused the screen bindings like undo go to the program too
+func (t *TerminalComponent) CapturesKeys() bool {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+	return t.Data.IsActive && t.pty != nil && !t.exited
+}
+
+func (t *TerminalComponent) Render() string {
+	style := osui.StateStyle(&t.Data, t.Style)
+	width, height := max(t.Data.Width, 1), max(t.Data.Height, 1)
+	t.mu.Lock()
+	defer t.mu.Unlock()
+	if t.vt == nil {
+		t.start(width, height)
+	} else if t.vt.width != width || t.vt.height != height {
+		t.vt.resize(width, height)
+		if t.pty != nil {
+			resizePty(t.pty, width, height)
+		}
+	}
+	if t.err != nil {
+		return style.Error + osui.TruncateEllipsis(t.err.Error(), width) + colors.Reset + t.Data.DefaultColor
+	}
+	lines := t.vt.lines(t.Data.IsActive && !t.exited, colors.Combine(style.Foreground, style.Background))
+	for i := range lines {
+		lines[i] += t.Data.DefaultColor
+	}
+	return strings.Join(lines, "\n")
+}
+
+// Send the key to the program, the leave key moves on instead
+func (t *TerminalComponent) Update(key string) bool {
+	if t.Keymap.Is("leave", key) {
+		return true
+	}
+	t.Write(key)
+	return false
+}
+
+func (t *TerminalComponent) Paste(text string) {
+	t.Write(text)
+}
+
+// Send input to the program as if it was typed
+func (t *TerminalComponent) Write(s string) {
+	t.mu.Lock()
+	pty := t.pty
+	t.mu.Unlock()
+	if pty != nil {
+		pty.WriteString(s)
+	}
+}
+
+// Stop the program, OnExit is still called
+func (t *TerminalComponent) Kill() {
+	if t.Cmd.Process != nil {
+		t.Cmd.Process.Kill()
+	}
+}
+
+func (t *TerminalComponent) Exited() bool {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+	return t.exited
+}
+
+func (t *TerminalComponent) start(width, height int) {
+	t.vt = newVtScreen(width, height)
+	env := t.Cmd.Env
+	if env == nil {
+		env = os.Environ()
+	}
+	// The later value wins, programs get told what the screen understands
+	t.Cmd.Env = append(env, "TERM=xterm-256color")
+	pty, err := startPty(t.Cmd, width, height)
+	if err != nil {
+		t.err = err
+		return
+	}
+	t.pty = pty
+	go t.read(pty)
+}
+
+// Feed the output to the screen until the program closes the terminal
+func (t *TerminalComponent) read(pty *os.File) {
+	buf := make([]byte, 4096)
+	for {
+		n, err := pty.Read(buf)
+		if n > 0 {
+			t.mu.Lock()
+			t.vt.write(buf[:n])
+			t.mu.Unlock()
+			if t.Data.Screen != nil {
+				t.Data.Screen.RequestRender()
+			}
+		}
+		if err != nil {
+			break
+		}
+	}
+	t.Cmd.Wait()
+	t.mu.Lock()
+	t.exited = true
+	pty.Close()
+	t.pty = nil
+	t.mu.Unlock()
+	code := t.Cmd.ProcessState.ExitCode()
+	if t.Data.Screen == nil {
+		return
+	}
+	t.Data.Screen.Handle().Do(func() {
+		if t.OnExit != nil {
+			t.OnExit(t, code)
+		}
+	})
+}
+
+func (t *TerminalComponent) Params(param TerminalParams) *TerminalComponent {
+	t.Style = osui.SetDefaults(&param.Style).(*TerminalStyle)
+	t.Data.Width = osui.LogicValueInt(param.Width == 0, 80, param.Width)
+	t.Data.Height = osui.LogicValueInt(param.Height == 0, 24, param.Height)
+	t.OnExit = param.OnExit
+	t.Keymap = TerminalKeymap.Merge(param.Keymap)
+	return t
+}
+
+// Run the command in a terminal inside the layout, like Terminal(exec.Command(os.Getenv("SHELL")))
+func Terminal(cmd *exec.Cmd) *TerminalComponent {
+	return &TerminalComponent{
+		Cmd:    cmd,
+		Style:  osui.SetDefaults(&TerminalStyle{}).(*TerminalStyle),
+		Keymap: TerminalKeymap,
+		Data:   osui.ComponentData{Width: 80, Height: 24},
+	}
+}
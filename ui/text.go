@@ -18,7 +18,14 @@ func (t *TextComponent) GetComponentData() *osui.ComponentData {
 }
 
 func (t TextComponent) Render() string {
-	return t.Text
+	if !osui.BidiEnabled(&t.Data) {
+		return t.Text
+	}
+	text := osui.Bidi(t.Text, t.Data.Direction)
+	if t.Data.Direction == osui.Rtl {
+		return osui.AlignRight(text, t.Data.Width)
+	}
+	return text
 }
 
 func Text(text string) *TextComponent {
@@ -0,0 +1,99 @@
+package ui
+
+import (
+	"strconv"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+	"github.com/orus-dev/osui/isKey"
+)
+
+var ToggleKeymap = osui.Keymap{
+	"toggle": {Keys: []string{" "}, Description: "Switch on or off"},
+}
+
+type ToggleParams struct {
+	Style    ToggleStyle
+	Keymap   osui.Keymap
+	OnChange func(*ToggleComponent, bool)
+}
+
+type ToggleStyle struct {
+	On         string `default:"\x1b[32m" type:"fg"`
+	Off        string `default:"\x1b[2m" type:"fg"`
+	Foreground string `default:"" type:"fg"`
+	Active     string `default:"\x1b[34m" type:"fg"`
+}
+
+type ToggleComponent struct {
+	Data     osui.ComponentData
+	Style    *ToggleStyle
+	Label    string
+	On       bool
+	Keymap   osui.Keymap
+	OnChange func(*ToggleComponent, bool)
+	initial  bool
+}
+
+func (t *ToggleComponent) GetComponentData() *osui.ComponentData {
+	return &t.Data
+}
+
+func (t *ToggleComponent) GetKeymap() osui.Keymap {
+	return t.Keymap
+}
+
+func (t *ToggleComponent) Render() string {
+	style := osui.StateStyle(&t.Data, t.Style)
+	box := osui.LogicValue(t.On, style.On+"[x]", style.Off+"[ ]")
+	label := osui.LogicValue(t.Data.IsActive, style.Active, style.Foreground) + t.Label
+	return box + colors.Reset + t.Data.DefaultColor + " " + label + colors.Reset + t.Data.DefaultColor
+}
+
+// Space switches the toggle, Enter moves on
+func (t *ToggleComponent) Update(key string) bool {
+	if t.Data.Disabled {
+		return false
+	}
+	if t.Keymap.Is("toggle", key) {
+		t.Set(!t.On)
+		return false
+	}
+	return isKey.Enter(key)
+}
+
+func (t *ToggleComponent) Set(on bool) {
+	if t.On == on {
+		return
+	}
+	t.On = on
+	if t.OnChange != nil {
+		t.OnChange(t, on)
+	}
+}
+
+func (t *ToggleComponent) Value() string {
+	return strconv.FormatBool(t.On)
+}
+
+// Go back to the value the toggle was created with
+func (t *ToggleComponent) Reset() {
+	t.Set(t.initial)
+}
+
+func (t *ToggleComponent) Params(param ToggleParams) *ToggleComponent {
+	t.Style = osui.SetDefaults(&param.Style).(*ToggleStyle)
+	t.Keymap = ToggleKeymap.Merge(param.Keymap)
+	t.OnChange = param.OnChange
+	return t
+}
+
+func Toggle(label string, on bool) *ToggleComponent {
+	return &ToggleComponent{
+		Label:   label,
+		On:      on,
+		initial: on,
+		Style:   osui.SetDefaults(&ToggleStyle{}).(*ToggleStyle),
+		Keymap:  ToggleKeymap,
+	}
+}
@@ -0,0 +1,271 @@
+package ui
+
+import (
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+	"github.com/orus-dev/osui/isKey"
+)
+
+var TreeKeymap = osui.Keymap{
+	"up":        {Keys: []string{"\x1b[A"}, Description: "Previous node"},
+	"down":      {Keys: []string{"\x1b[B"}, Description: "Next node"},
+	"expand":    {Keys: []string{"\x1b[C"}, Description: "Expand the node or go to its first child"},
+	"collapse":  {Keys: []string{"\x1b[D"}, Description: "Collapse the node or go to its parent"},
+	"toggle":    {Keys: []string{" "}, Description: "Expand or collapse the node"},
+	"page_up":   {Keys: []string{"\x1b[5~"}, Description: "A page up"},
+	"page_down": {Keys: []string{"\x1b[6~"}, Description: "A page down"},
+}
+
+type TreeParams struct {
+	Style    TreeStyle
+	Width    int
+	Height   int
+	Keymap   osui.Keymap
+	OnSelect func(*TreeComponent, *TreeNode)
+}
+
+type TreeStyle struct {
+	Foreground string `default:"" type:"fg"`
+	Background string `default:"" type:"bg"`
+	Branch     string `default:"\x1b[2m" type:"fg"`
+	Detail     string `default:"\x1b[2m" type:"fg"`
+	Match      string `default:"\x1b[1;33m" type:"fg"`
+	Cursor     string `default:"\x1b[7m" type:"bg"`
+}
+
+type TreeNode struct {
+	Label string
+	// Shown after the label, like the value of a key
+	Detail string
+	// Used for the detail instead of the Detail style of the tree
+	DetailStyle string
+	Children    []*TreeNode
+	Expanded    bool
+	Value       any
+}
+
+// A shown node with its depth and the row of its parent, -1 for roots
+type treeRow struct {
+	node   *TreeNode
+	depth  int
+	parent int
+}
+
+// Nested nodes, the arrows move between them and expand or collapse them. With a query only the nodes that match it
+// and their ancestors are shown
+type TreeComponent struct {
+	Data   osui.ComponentData
+	Style  *TreeStyle
+	Roots  []*TreeNode
+	Cursor int
+	Keymap osui.Keymap
+	// Called with the node Enter was pressed on
+	OnSelect func(*TreeComponent, *TreeNode)
+	query    string
+	rows     []treeRow
+	offset   int
+}
+
+func (t *TreeComponent) GetComponentData() *osui.ComponentData {
+	return &t.Data
+}
+
+func (t *TreeComponent) GetKeymap() osui.Keymap {
+	return t.Keymap
+}
+
+func (t *TreeComponent) Render() string {
+	style := osui.StateStyle(&t.Data, t.Style)
+	t.rows = t.visibleRows()
+	reset := colors.Reset + t.Data.DefaultColor
+	body := colors.Combine(style.Foreground, style.Background)
+	height := t.Data.Height
+	if height == 0 {
+		height = len(t.rows)
+	}
+	t.Cursor = min(max(t.Cursor, 0), max(len(t.rows)-1, 0))
+	if t.Cursor < t.offset {
+		t.offset = t.Cursor
+	} else if t.Cursor >= t.offset+height {
+		t.offset = t.Cursor - height + 1
+	}
+	res := []string{}
+	for i := t.offset; i < len(t.rows) && i < t.offset+height; i++ {
+		row := t.rows[i]
+		branch := "  "
+		if len(row.node.Children) > 0 {
+			branch = osui.LogicValue(t.expanded(row.node), osui.Settings.Glyphs.Expanded, osui.Settings.Glyphs.Collapsed) + " "
+		}
+		text := body + strings.Repeat("  ", row.depth) + style.Branch + branch + reset + body + highlightMatches(row.node.Label, t.query, style.Match, reset+body)
+		if row.node.Detail != "" {
+			detail := osui.LogicValue(row.node.DetailStyle == "", style.Detail, row.node.DetailStyle)
+			text += " " + detail + highlightMatches(row.node.Detail, t.query, style.Match, reset+body+detail) + reset + body
+		}
+		if t.Data.Width > 0 {
+			text = osui.TruncateVisible(text, t.Data.Width)
+			text += strings.Repeat(" ", max(t.Data.Width-osui.VisibleWidth(text), 0))
+		}
+		if i == t.Cursor && t.Data.IsActive {
+			text = osui.WithStyle(text, style.Cursor)
+		}
+		res = append(res, text+reset)
+	}
+	return strings.Join(res, "\n")
+}
+
+// The rows of the expanded nodes, or of the matches and their ancestors while there is a query
+func (t *TreeComponent) visibleRows() []treeRow {
+	rows := []treeRow{}
+	var walk func(nodes []*TreeNode, depth, parent int)
+	walk = func(nodes []*TreeNode, depth, parent int) {
+		for _, n := range nodes {
+			if t.query != "" && !t.matches(n) {
+				continue
+			}
+			rows = append(rows, treeRow{node: n, depth: depth, parent: parent})
+			if t.expanded(n) {
+				walk(n.Children, depth+1, len(rows)-1)
+			}
+		}
+	}
+	walk(t.Roots, 0, -1)
+	return rows
+}
+
+// Whether the node or one of its descendants has the query in its label or detail
+func (t *TreeComponent) matches(n *TreeNode) bool {
+	if containsFold(n.Label, t.query) || containsFold(n.Detail, t.query) {
+		return true
+	}
+	for _, c := range n.Children {
+		if t.matches(c) {
+			return true
+		}
+	}
+	return false
+}
+
+// Nodes are shown expanded while searching so the matches in them can be seen
+func (t *TreeComponent) expanded(n *TreeNode) bool {
+	return n.Expanded || (t.query != "" && len(n.Children) > 0)
+}
+
+func (t *TreeComponent) Update(key string) bool {
+	if t.Data.Disabled {
+		return false
+	}
+	page := max(t.Data.Height-1, 1)
+	// Keys since the last render may have expanded or collapsed nodes
+	t.rows = t.visibleRows()
+	node := t.Selected()
+	switch {
+	case t.Keymap.Is("up", key):
+		t.Scroll(0, -1)
+	case t.Keymap.Is("down", key):
+		t.Scroll(0, 1)
+	case t.Keymap.Is("page_up", key):
+		t.Scroll(0, -page)
+	case t.Keymap.Is("page_down", key):
+		t.Scroll(0, page)
+	case node == nil:
+	case t.Keymap.Is("expand", key):
+		if len(node.Children) == 0 {
+			break
+		}
+		if t.expanded(node) {
+			t.Scroll(0, 1)
+		} else {
+			node.Expanded = true
+		}
+	case t.Keymap.Is("collapse", key):
+		if t.expanded(node) && t.query == "" {
+			node.Expanded = false
+		} else if parent := t.rows[t.Cursor].parent; parent >= 0 {
+			t.Cursor = parent
+		}
+	case t.Keymap.Is("toggle", key):
+		node.Expanded = !node.Expanded
+	case isKey.Enter(key):
+		if t.OnSelect != nil {
+			t.OnSelect(t, node)
+		}
+	}
+	return false
+}
+
+func (t *TreeComponent) Scroll(dx, dy int) bool {
+	if dy == 0 || len(t.rows) == 0 {
+		return false
+	}
+	cursor := t.Cursor
+	t.Cursor = min(max(t.Cursor+dy, 0), len(t.rows)-1)
+	return t.Cursor != cursor
+}
+
+// Clicking a node moves the cursor to it, clicking it again expands or collapses it
+func (t *TreeComponent) OnClick(e osui.MouseEvent) bool {
+	i := t.offset + e.Y
+	if e.Button != osui.MouseLeft || i >= len(t.rows) {
+		return false
+	}
+	if i == t.Cursor {
+		t.rows[i].node.Expanded = !t.rows[i].node.Expanded
+	}
+	t.Cursor = i
+	return true
+}
+
+// The node under the cursor as of the last render, nil if the tree is empty
+func (t *TreeComponent) Selected() *TreeNode {
+	if t.Cursor < 0 || t.Cursor >= len(t.rows) {
+		return nil
+	}
+	return t.rows[t.Cursor].node
+}
+
+// Only show the nodes with the query in their label or detail and their ancestors, an empty query shows all of them
+func (t *TreeComponent) SetQuery(query string) {
+	t.query = query
+	t.Cursor, t.offset = 0, 0
+	t.rows = t.visibleRows()
+}
+
+func (t *TreeComponent) Query() string {
+	return t.query
+}
+
+// Expand or collapse every node
+func (t *TreeComponent) ExpandAll(expanded bool) {
+	var walk func(nodes []*TreeNode)
+	walk = func(nodes []*TreeNode) {
+		for _, n := range nodes {
+			n.Expanded = expanded && len(n.Children) > 0
+			walk(n.Children)
+		}
+	}
+	walk(t.Roots)
+}
+
+func (t *TreeComponent) Params(param TreeParams) *TreeComponent {
+	t.Style = osui.SetDefaults(&param.Style).(*TreeStyle)
+	t.Data.Width = param.Width
+	t.Data.Height = param.Height
+	t.Keymap = TreeKeymap.Merge(param.Keymap)
+	t.OnSelect = param.OnSelect
+	return t
+}
+
+func Tree(roots ...*TreeNode) *TreeComponent {
+	return &TreeComponent{
+		Roots:  roots,
+		Style:  osui.SetDefaults(&TreeStyle{}).(*TreeStyle),
+		Keymap: TreeKeymap,
+	}
+}
+
+// A collapsed node with the children
+func TreeItem(label string, children ...*TreeNode) *TreeNode {
+	return &TreeNode{Label: label, Children: children}
+}
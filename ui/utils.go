@@ -6,6 +6,7 @@ import (
 	"strings"
 
 	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
 )
 
 func WithPosition(x, y int, c osui.Component) osui.Component {
@@ -22,6 +23,25 @@ func WithSize(width, height int, c osui.Component) osui.Component {
 	return c
 }
 
+func WithId(id string, c osui.Component) osui.Component {
+	c.GetComponentData().Id = id
+	return c
+}
+
+func WithClass(class string, c osui.Component) osui.Component {
+	c.GetComponentData().AddClass(class)
+	return c
+}
+
+// Draw the component above its siblings at x, y of its parent, z orders it among the other floating components
+func Floating(x, y, z int, c osui.Component) osui.Component {
+	data := c.GetComponentData()
+	data.X, data.Y = x, y
+	data.Floating = true
+	data.ZIndex = z
+	return c
+}
+
 func centerText(text string, width int) string {
 	if len(text) > width {
 		return text[:width]
@@ -33,6 +53,120 @@ func centerText(text string, width int) string {
 	return fmt.Sprintf("%s%s%s", leftPadding, text, rightPadding)
 }
 
+// Lay out tab labels in the given width, scrolling to keep the active one visible or wrapping into more rows
+func tabStrip(labels []string, active, width int, sep string, wrap bool, activeStyle, inactiveStyle string) []string {
+	sepWidth := len([]rune(sep))
+	widths := make([]int, len(labels))
+	total := 0
+	for i, l := range labels {
+		widths[i] = len([]rune(l))
+		total += widths[i] + osui.LogicValueInt(i > 0, sepWidth, 0)
+	}
+	if total <= width {
+		return []string{strings.Repeat(" ", (width-total)/2) + renderTabs(labels, 0, len(labels), active, sep, activeStyle, inactiveStyle)}
+	}
+
+	if wrap {
+		rows := []string{}
+		from, w := 0, 0
+		for i := range labels {
+			if i > from && w+sepWidth+widths[i] > width {
+				rows = append(rows, renderTabs(labels, from, i, active, sep, activeStyle, inactiveStyle))
+				from, w = i, widths[i]
+			} else {
+				w += widths[i] + osui.LogicValueInt(i > from, sepWidth, 0)
+			}
+		}
+		return append(rows, renderTabs(labels, from, len(labels), active, sep, activeStyle, inactiveStyle))
+	}
+
+	avail := width - 4
+	from, to := active, active+1
+	w := widths[active]
+	for grown := true; grown; {
+		grown = false
+		if to < len(labels) && w+sepWidth+widths[to] <= avail {
+			w += sepWidth + widths[to]
+			to++
+			grown = true
+		}
+		if from > 0 && w+sepWidth+widths[from-1] <= avail {
+			from--
+			w += sepWidth + widths[from]
+			grown = true
+		}
+	}
+	return []string{
+		osui.LogicValue(from > 0, "‹ ", "  ") +
+			renderTabs(labels, from, to, active, sep, activeStyle, inactiveStyle) +
+			osui.LogicValue(to < len(labels), " ›", ""),
+	}
+}
+
+// Remove the item at i, the active index keeps pointing at the same item or at the one before the removed one
+func removeAt[T any](items []T, i, active int) ([]T, int) {
+	items = append(items[:i], items[i+1:]...)
+	if i < active || active == len(items) {
+		active--
+	}
+	return items, active
+}
+
+// Move the item at i by delta positions, the active index follows the item it points at. False if either
+// position is out of the items
+func moveAt[T any](items []T, i, delta, active int) ([]T, int, bool) {
+	j := i + delta
+	if i < 0 || i >= len(items) || j < 0 || j >= len(items) {
+		return items, active, false
+	}
+	item := items[i]
+	items = append(items[:i], items[i+1:]...)
+	items = append(items[:j], append([]T{item}, items[j:]...)...)
+	if active == i {
+		active = j
+	} else if i < active && j >= active {
+		active--
+	} else if i > active && j <= active {
+		active++
+	}
+	return items, active, true
+}
+
+func renderTabs(labels []string, from, to, active int, sep, activeStyle, inactiveStyle string) string {
+	var b strings.Builder
+	for i := from; i < to; i++ {
+		if i > from {
+			b.WriteString(sep)
+		}
+		b.WriteString(osui.LogicValue(i == active, activeStyle, inactiveStyle) + labels[i] + colors.Reset)
+	}
+	return b.String()
+}
+
+var blocks = []rune(" ▁▂▃▄▅▆▇█")
+
+// The cells of a vertical bar from the top row to the bottom, value is scaled by top to the height in eighths
+func barCells(value, top float64, height int) []rune {
+	cells := make([]rune, height)
+	eighths := 0
+	if top > 0 && value > 0 {
+		eighths = int(math.Round(math.Min(value/top, 1) * float64(height*8)))
+	}
+	for i := range cells {
+		fill := min(max(eighths-(height-1-i)*8, 0), 8)
+		cells[i] = blocks[fill]
+	}
+	return cells
+}
+
+func maxValue(values []float64) float64 {
+	m := 0.0
+	for _, v := range values {
+		m = math.Max(m, v)
+	}
+	return m
+}
+
 func findClosestComponent(a []osui.Component, i int, d string) int {
 	if len(a) == 0 || i < 0 || i >= len(a) {
 		return -1 // Invalid input
@@ -0,0 +1,392 @@
+package ui
+
+import (
+	"strconv"
+	"strings"
+	"unicode/utf8"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+type vtCell struct {
+	// 0 is an empty cell, -1 the second cell of a wide character
+	r     rune
+	style string
+}
+
+type vtState int
+
+const (
+	// The longest parameters of a CSI sequence kept, longer ones are dropped
+	vtMaxSeq = 64
+	// The biggest value of a CSI parameter, bigger ones are cut to it so counts can't overflow
+	vtMaxArg = 1 << 16
+)
+
+const (
+	vtGround vtState = iota
+	vtEscape
+	vtCsi
+	// A CSI sequence too long to be one the screen knows, dropped up to its final byte
+	vtCsiIgnore
+	vtOsc
+	vtCharset
+)
+
+// The screen of a VT100 like terminal, enough of it for shells and most full screen programs
+type vtScreen struct {
+	width  int
+	height int
+	cells  [][]vtCell
+	x      int
+	y      int
+	style  string
+	// The scroll region, bottom is the last row in it
+	top    int
+	bottom int
+	saved  [2]int
+	hidden bool
+	// The last column was written, the next character goes on the next line
+	wrapNext bool
+	state    vtState
+	seq      []byte
+	pending  []byte
+}
+
+func newVtScreen(width, height int) *vtScreen {
+	v := &vtScreen{}
+	v.resize(width, height)
+	return v
+}
+
+// Change the size keeping the top left of the content
+func (v *vtScreen) resize(width, height int) {
+	width, height = max(width, 1), max(height, 1)
+	cells := make([][]vtCell, height)
+	for y := range cells {
+		cells[y] = make([]vtCell, width)
+		if y < len(v.cells) {
+			copy(cells[y], v.cells[y])
+		}
+	}
+	v.cells, v.width, v.height = cells, width, height
+	v.top, v.bottom = 0, height-1
+	v.x, v.y = min(v.x, width-1), min(v.y, height-1)
+	v.wrapNext = false
+}
+
+// Feed output of the program, UTF-8 characters and escape sequences may be split between writes
+func (v *vtScreen) write(data []byte) {
+	data = append(v.pending, data...)
+	v.pending = nil
+	for len(data) > 0 {
+		if !utf8.FullRune(data) {
+			v.pending = append(v.pending, data...)
+			return
+		}
+		r, size := utf8.DecodeRune(data)
+		data = data[size:]
+		v.feed(r)
+	}
+}
+
+func (v *vtScreen) feed(r rune) {
+	switch v.state {
+	case vtEscape:
+		v.escape(r)
+		return
+	case vtCsi:
+		if r >= 0x40 && r <= 0x7e {
+			v.state = vtGround
+			v.csi(string(v.seq), r)
+			return
+		}
+		if len(v.seq) >= vtMaxSeq {
+			v.state = vtCsiIgnore
+			return
+		}
+		v.seq = append(v.seq, byte(r))
+		return
+	case vtCsiIgnore:
+		if r >= 0x40 && r <= 0x7e {
+			v.state = vtGround
+		}
+		return
+	case vtOsc:
+		// Titles and the like are dropped, they end with BEL or ESC \
+		if r == 0x07 {
+			v.state = vtGround
+		} else if r == 0x1b {
+			v.state = vtEscape
+		}
+		return
+	case vtCharset:
+		v.state = vtGround
+		return
+	}
+	switch r {
+	case 0x1b:
+		v.state = vtEscape
+	case '\r':
+		v.x, v.wrapNext = 0, false
+	case '\n', 0x0b, 0x0c:
+		v.lineFeed()
+	case '\b':
+		v.x, v.wrapNext = max(v.x-1, 0), false
+	case '\t':
+		v.x = min((v.x/8+1)*8, v.width-1)
+	default:
+		if r >= 32 && r != 0x7f {
+			v.put(r)
+		}
+	}
+}
+
+func (v *vtScreen) escape(r rune) {
+	v.state = vtGround
+	switch r {
+	case '[':
+		v.state, v.seq = vtCsi, v.seq[:0]
+	case ']':
+		v.state = vtOsc
+	case '(', ')':
+		v.state = vtCharset
+	case '7':
+		v.saved = [2]int{v.x, v.y}
+	case '8':
+		v.x, v.y = min(v.saved[0], v.width-1), min(v.saved[1], v.height-1)
+	case 'D':
+		v.lineFeed()
+	case 'E':
+		v.x = 0
+		v.lineFeed()
+	case 'M':
+		if v.y == v.top {
+			v.scrollDown(1)
+		} else {
+			v.y = max(v.y-1, 0)
+		}
+	case 'c':
+		*v = *newVtScreen(v.width, v.height)
+	}
+}
+
+func (v *vtScreen) put(r rune) {
+	w := osui.RuneWidth(r)
+	if w == 0 {
+		return
+	}
+	if v.wrapNext || v.x+w > v.width {
+		v.x = 0
+		v.lineFeed()
+	}
+	v.cells[v.y][v.x] = vtCell{r: r, style: v.style}
+	if w == 2 && v.x+1 < v.width {
+		v.cells[v.y][v.x+1] = vtCell{r: -1, style: v.style}
+	}
+	v.x += w
+	if v.x >= v.width {
+		v.x, v.wrapNext = v.width-1, true
+	}
+}
+
+func (v *vtScreen) lineFeed() {
+	v.wrapNext = false
+	if v.y == v.bottom {
+		v.scrollUp(1)
+	} else {
+		v.y = min(v.y+1, v.height-1)
+	}
+}
+
+// Move the lines of the scroll region up by n, the new lines at the bottom are empty
+func (v *vtScreen) scrollUp(n int) {
+	for ; n > 0; n-- {
+		copy(v.cells[v.top:v.bottom], v.cells[v.top+1:v.bottom+1])
+		v.cells[v.bottom] = make([]vtCell, v.width)
+	}
+}
+
+func (v *vtScreen) scrollDown(n int) {
+	for ; n > 0; n-- {
+		copy(v.cells[v.top+1:v.bottom+1], v.cells[v.top:v.bottom])
+		v.cells[v.top] = make([]vtCell, v.width)
+	}
+}
+
+func (v *vtScreen) csi(params string, final rune) {
+	private := strings.HasPrefix(params, "?")
+	args := []int{}
+	for _, p := range strings.Split(strings.TrimLeft(params, "?>="), ";") {
+		n, _ := strconv.Atoi(p)
+		args = append(args, min(max(n, 0), vtMaxArg))
+	}
+	// The first argument, 0 and missing ones count as 1 for movements
+	n := max(args[0], 1)
+	arg := func(i, def int) int {
+		if i < len(args) && args[i] > 0 {
+			return args[i]
+		}
+		return def
+	}
+	v.wrapNext = false
+	switch final {
+	case 'A':
+		v.y = max(v.y-n, 0)
+	case 'B', 'e':
+		v.y = min(v.y+n, v.height-1)
+	case 'C', 'a':
+		v.x = min(v.x+n, v.width-1)
+	case 'D':
+		v.x = max(v.x-n, 0)
+	case 'E':
+		v.x, v.y = 0, min(v.y+n, v.height-1)
+	case 'F':
+		v.x, v.y = 0, max(v.y-n, 0)
+	case 'G', '`':
+		v.x = min(n-1, v.width-1)
+	case 'd':
+		v.y = min(n-1, v.height-1)
+	case 'H', 'f':
+		v.y, v.x = min(arg(0, 1)-1, v.height-1), min(arg(1, 1)-1, v.width-1)
+	case 'J':
+		v.erase(args[0], true)
+	case 'K':
+		v.erase(args[0], false)
+	case 'L', 'M':
+		if v.y < v.top || v.y > v.bottom {
+			return
+		}
+		top := v.top
+		v.top = v.y
+		if final == 'L' {
+			v.scrollDown(min(n, v.bottom-v.y+1))
+		} else {
+			v.scrollUp(min(n, v.bottom-v.y+1))
+		}
+		v.top = top
+	case 'P':
+		row := v.cells[v.y]
+		n = min(n, v.width-v.x)
+		copy(row[v.x:], row[v.x+n:])
+		for i := v.width - n; i < v.width; i++ {
+			row[i] = vtCell{}
+		}
+	case '@':
+		row := v.cells[v.y]
+		n = min(n, v.width-v.x)
+		copy(row[v.x+n:], row[v.x:v.width-n])
+		for i := v.x; i < v.x+n; i++ {
+			row[i] = vtCell{}
+		}
+	case 'X':
+		for i := v.x; i < min(v.x+n, v.width); i++ {
+			v.cells[v.y][i] = vtCell{}
+		}
+	case 'S':
+		v.scrollUp(min(n, v.bottom-v.top+1))
+	case 'T':
+		v.scrollDown(min(n, v.bottom-v.top+1))
+	case 'r':
+		top, bottom := arg(0, 1)-1, arg(1, v.height)-1
+		if top < bottom && bottom < v.height {
+			v.top, v.bottom = top, bottom
+			v.x, v.y = 0, 0
+		}
+	case 's':
+		v.saved = [2]int{v.x, v.y}
+	case 'u':
+		v.x, v.y = min(v.saved[0], v.width-1), min(v.saved[1], v.height-1)
+	case 'm':
+		v.sgr(params)
+	case 'h', 'l':
+		if !private {
+			return
+		}
+		for _, mode := range args {
+			switch mode {
+			case 25:
+				v.hidden = final == 'l'
+			case 47, 1047, 1049:
+				// There is no second buffer, the program redraws the screen anyway
+				v.erase(2, true)
+			}
+		}
+	}
+}
+
+// Erase mode 0 from the cursor to the end, 1 from the start to the cursor and 2 all of the screen or the line
+func (v *vtScreen) erase(mode int, screen bool) {
+	blank := func(y, from, to int) {
+		for x := max(from, 0); x < min(to, v.width); x++ {
+			v.cells[y][x] = vtCell{style: v.style}
+		}
+	}
+	switch mode {
+	case 0:
+		blank(v.y, v.x, v.width)
+		if screen {
+			for y := v.y + 1; y < v.height; y++ {
+				blank(y, 0, v.width)
+			}
+		}
+	case 1:
+		blank(v.y, 0, v.x+1)
+		if screen {
+			for y := 0; y < v.y; y++ {
+				blank(y, 0, v.width)
+			}
+		}
+	default:
+		if !screen {
+			blank(v.y, 0, v.width)
+			return
+		}
+		for y := 0; y < v.height; y++ {
+			blank(y, 0, v.width)
+		}
+	}
+}
+
+// Keep the text style as the escape sequences that set it, a reset starts over
+func (v *vtScreen) sgr(params string) {
+	switch {
+	case params == "" || params == "0":
+		v.style = ""
+	case strings.HasPrefix(params, "0;"):
+		v.style = "\x1b[" + params + "m"
+	case len(v.style) > 128:
+		// Programs that never reset would grow it forever
+		v.style = "\x1b[" + params + "m"
+	default:
+		v.style += "\x1b[" + params + "m"
+	}
+}
+
+// The lines of the screen, the cursor is drawn reversed
+func (v *vtScreen) lines(cursor bool, base string) []string {
+	res := make([]string, v.height)
+	for y, row := range v.cells {
+		var b strings.Builder
+		b.WriteString(base)
+		style := ""
+		for x, c := range row {
+			if c.r == -1 {
+				continue
+			}
+			if c.style != style {
+				b.WriteString(colors.Reset + base + c.style)
+				style = c.style
+			}
+			char := osui.LogicValue(c.r == 0, " ", string(c.r))
+			if cursor && !v.hidden && x == v.x && y == v.y {
+				b.WriteString(colors.Reverse + char + colors.Reset + base + style)
+			} else {
+				b.WriteString(char)
+			}
+		}
+		res[y] = b.String() + colors.Reset
+	}
+	return res
+}
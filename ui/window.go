@@ -0,0 +1,359 @@
+package ui
+
+import (
+	"strings"
+
+	"github.com/orus-dev/osui"
+	"github.com/orus-dev/osui/colors"
+)
+
+var WindowManagerKeymap = osui.Keymap{
+	"next":          {Keys: []string{"\x1b[17~"}, Description: "Focus the next window"},
+	"move_up":       {Keys: []string{"\x1b[1;4A"}, Description: "Move the window up"},
+	"move_down":     {Keys: []string{"\x1b[1;4B"}, Description: "Move the window down"},
+	"move_right":    {Keys: []string{"\x1b[1;4C"}, Description: "Move the window right"},
+	"move_left":     {Keys: []string{"\x1b[1;4D"}, Description: "Move the window left"},
+	"shrink_height": {Keys: []string{"\x1b[1;7A"}, Description: "Make the window shorter"},
+	"grow_height":   {Keys: []string{"\x1b[1;7B"}, Description: "Make the window taller"},
+	"grow_width":    {Keys: []string{"\x1b[1;7C"}, Description: "Make the window wider"},
+	"shrink_width":  {Keys: []string{"\x1b[1;7D"}, Description: "Make the window narrower"},
+	"minimize":      {Keys: []string{"\x1bm"}, Description: "Minimize the window"},
+	"close":         {Keys: []string{"\x1bx"}, Description: "Close the window"},
+}
+
+// The smallest size a window can be resized to
+const (
+	windowMinWidth  = 12
+	windowMinHeight = 3
+)
+
+type WindowParams struct {
+	Style   WindowStyle
+	X       int
+	Y       int
+	Width   int
+	Height  int
+	OnClose func(*WindowComponent) bool
+}
+
+type WindowStyle struct {
+	Outline       string `default:"\x1b[2m" type:"fg"`
+	ActiveOutline string `default:"\x1b[34m" type:"fg"`
+	Title         string `default:"\x1b[1m" type:"fg"`
+	Background    string `default:"" type:"bg"`
+	Foreground    string `default:"" type:"fg"`
+	Buttons       string `default:"" type:"fg"`
+}
+
+// A bordered container with a title bar, moved, resized, minimized and closed by the window manager it is open in
+type WindowComponent struct {
+	Data    osui.ComponentData
+	Style   *WindowStyle
+	Title   string
+	Content osui.Component
+	// Minimized windows are only listed in the task bar of the window manager
+	Minimized bool
+	// Called before the window is closed, returning true keeps it open
+	OnClose func(*WindowComponent) bool
+	manager *WindowManagerComponent
+}
+
+func (w *WindowComponent) GetComponentData() *osui.ComponentData {
+	return &w.Data
+}
+
+func (w *WindowComponent) GetChildren() []osui.Component {
+	return []osui.Component{w.Content}
+}
+
+func (w *WindowComponent) ChildOffset() (int, int) {
+	return 1, 1
+}
+
+// The title bar has the minimize and close buttons on the right, the outline shows which window is focused
+func (w *WindowComponent) Render() string {
+	style := osui.StateStyle(&w.Data, w.Style)
+	g := osui.Settings.Glyphs
+	outline := osui.LogicValue(w.Data.IsActive, style.ActiveOutline, style.Outline)
+	body := colors.Combine(style.Foreground, style.Background)
+	width := max(w.Data.Width-2, 0)
+	frame := osui.NewFrame(width, max(w.Data.Height-2, 0))
+	data := w.Content.GetComponentData()
+	data.X, data.Y = 0, 0
+	data.Width, data.Height = width, len(frame)
+	data.IsActive = w.Data.IsActive
+	data.DefaultColor = body
+	data.Screen = w.Data.Screen
+	osui.RenderOnFrame(w.Content, &frame)
+
+	buttons := " _ × "
+	title := osui.TruncateEllipsis(" "+w.Title+" ", max(w.Data.Width-4-len([]rune(buttons)), 0))
+	rest := max(w.Data.Width-4-osui.VisibleWidth(title)-len([]rune(buttons)), 0)
+	lines := []string{colors.Reset + outline + g.TopLeft + g.Top + colors.Reset + style.Title + title + colors.Reset +
+		outline + strings.Repeat(g.Top, rest) + colors.Reset + style.Buttons + buttons + colors.Reset + outline + g.Top + g.TopRight + colors.Reset}
+	for _, f := range frame {
+		lines = append(lines, outline+g.Left+colors.Reset+osui.WithStyle(f, body)+outline+g.Right+colors.Reset)
+	}
+	lines = append(lines, outline+g.BottomBorder(w.Data.Width)+colors.Reset+w.Data.DefaultColor)
+	return strings.Join(lines, "\n")
+}
+
+func (w *WindowComponent) Update(key string) bool {
+	w.Content.GetComponentData().IsActive = w.Data.IsActive
+	return w.Content.Update(key)
+}
+
+// Clicking the window raises it, the buttons of the title bar minimize or close it
+func (w *WindowComponent) OnClick(e osui.MouseEvent) bool {
+	if w.manager == nil || e.Button != osui.MouseLeft {
+		return false
+	}
+	// The title bar ends with " _ × " and the corner
+	if e.Y == 0 && e.X == w.Data.Width-4 {
+		w.manager.Close(w)
+	} else if e.Y == 0 && e.X == w.Data.Width-6 {
+		w.manager.Minimize(w)
+	} else {
+		w.manager.Raise(w)
+	}
+	return true
+}
+
+// Keep the window inside of the area, shrinking it if it is larger
+func (w *WindowComponent) clamp(width, height int) {
+	w.Data.Width = min(max(w.Data.Width, windowMinWidth), max(width, 1))
+	w.Data.Height = min(max(w.Data.Height, windowMinHeight), max(height, 1))
+	w.Data.X = min(max(w.Data.X, 0), max(width-w.Data.Width, 0))
+	w.Data.Y = min(max(w.Data.Y, 0), max(height-w.Data.Height, 0))
+}
+
+func (w *WindowComponent) Params(param WindowParams) *WindowComponent {
+	w.Style = osui.SetDefaults(&param.Style).(*WindowStyle)
+	w.Data.X, w.Data.Y = param.X, param.Y
+	w.Data.Width = osui.LogicValueInt(param.Width == 0, 40, param.Width)
+	w.Data.Height = osui.LogicValueInt(param.Height == 0, 12, param.Height)
+	w.OnClose = param.OnClose
+	return w
+}
+
+func Window(title string, content osui.Component) *WindowComponent {
+	return &WindowComponent{
+		Title:   title,
+		Content: content,
+		Style:   osui.SetDefaults(&WindowStyle{}).(*WindowStyle),
+		Data:    osui.ComponentData{Width: 40, Height: 12},
+	}
+}
+
+type WindowManagerParams struct {
+	Style  WindowManagerStyle
+	Keymap osui.Keymap
+}
+
+type WindowManagerStyle struct {
+	TaskBar   string `default:"\x1b[7m" type:"bg"`
+	Minimized string `default:"" type:"fg"`
+}
+
+// Windows over a desktop component. The last window in the stacking order is drawn on top and gets the keys,
+// the desktop gets them while no window is shown. Minimized windows are listed in a task bar on the last row
+type WindowManagerComponent struct {
+	Data    osui.ComponentData
+	Style   *WindowManagerStyle
+	Desktop osui.Component
+	Keymap  osui.Keymap
+	windows []*WindowComponent
+}
+
+func (m *WindowManagerComponent) GetComponentData() *osui.ComponentData {
+	return &m.Data
+}
+
+func (m *WindowManagerComponent) GetKeymap() osui.Keymap {
+	return m.Keymap
+}
+
+// The desktop first and the shown windows from the bottom of the stack up, so clicks reach the top one first
+func (m *WindowManagerComponent) GetChildren() []osui.Component {
+	res := []osui.Component{m.Desktop}
+	for _, w := range m.windows {
+		if !w.Minimized {
+			res = append(res, w)
+		}
+	}
+	return res
+}
+
+func (m *WindowManagerComponent) ChildOffset() (int, int) {
+	return 0, 0
+}
+
+func (m *WindowManagerComponent) Render() string {
+	style := osui.StateStyle(&m.Data, m.Style)
+	frame := osui.NewFrame(m.Data.Width, m.Data.Height)
+	height := m.Data.Height
+	minimized := []string{}
+	for _, w := range m.windows {
+		if w.Minimized {
+			minimized = append(minimized, w.Title)
+		}
+	}
+	if len(minimized) > 0 {
+		height--
+	}
+	data := m.Desktop.GetComponentData()
+	if data.Width == 0 {
+		data.Width = m.Data.Width
+	}
+	if data.Height == 0 {
+		data.Height = height
+	}
+	data.IsActive = m.Data.IsActive && m.Focused() == nil
+	data.DefaultColor = m.Data.DefaultColor
+	data.Screen = m.Data.Screen
+	osui.RenderOnFrame(m.Desktop, &frame)
+	top := m.Focused()
+	for _, w := range m.windows {
+		if w.Minimized {
+			continue
+		}
+		w.clamp(m.Data.Width, height)
+		w.Data.IsActive = m.Data.IsActive && w == top
+		w.Data.DefaultColor = m.Data.DefaultColor
+		w.Data.Screen = m.Data.Screen
+		osui.RenderOnFrame(w, &frame)
+	}
+	if len(minimized) > 0 && len(frame) > 0 {
+		bar := ""
+		for _, title := range minimized {
+			bar += style.Minimized + " " + title + " " + colors.Reset + style.TaskBar
+		}
+		bar = osui.TruncateVisible(bar, m.Data.Width)
+		frame[len(frame)-1] = osui.WithStyle(bar+strings.Repeat(" ", max(m.Data.Width-osui.VisibleWidth(bar), 0)), style.TaskBar) + m.Data.DefaultColor
+	}
+	return strings.Join(frame, "\n")
+}
+
+func (m *WindowManagerComponent) Update(key string) bool {
+	w := m.Focused()
+	if w != nil {
+		w.Data.IsActive = m.Data.IsActive
+		w.Content.GetComponentData().IsActive = w.Data.IsActive
+	}
+	switch {
+	case w != nil && osui.Captures(w):
+		// A running terminal and the like in the window gets the bindings of the manager too
+		w.Update(key)
+	case m.Keymap.Is("next", key):
+		m.FocusNext()
+	case w == nil:
+		m.Desktop.GetComponentData().IsActive = m.Data.IsActive
+		return m.Desktop.Update(key)
+	case m.Keymap.Is("move_up", key):
+		w.Data.Y--
+	case m.Keymap.Is("move_down", key):
+		w.Data.Y++
+	case m.Keymap.Is("move_left", key):
+		w.Data.X--
+	case m.Keymap.Is("move_right", key):
+		w.Data.X++
+	case m.Keymap.Is("shrink_height", key):
+		w.Data.Height = max(w.Data.Height-1, windowMinHeight)
+	case m.Keymap.Is("grow_height", key):
+		w.Data.Height++
+	case m.Keymap.Is("shrink_width", key):
+		w.Data.Width = max(w.Data.Width-1, windowMinWidth)
+	case m.Keymap.Is("grow_width", key):
+		w.Data.Width++
+	case m.Keymap.Is("minimize", key):
+		m.Minimize(w)
+	case m.Keymap.Is("close", key):
+		m.Close(w)
+	default:
+		w.Update(key)
+	}
+	return false
+}
+
+// Add the window on top of the others and focus it
+func (m *WindowManagerComponent) Open(w *WindowComponent) {
+	w.manager = m
+	w.Minimized = false
+	m.remove(w)
+	m.windows = append(m.windows, w)
+}
+
+// Close the window unless its OnClose keeps it open, the window below it gets the focus
+func (m *WindowManagerComponent) Close(w *WindowComponent) bool {
+	if w.OnClose != nil && w.OnClose(w) {
+		return false
+	}
+	if !m.remove(w) {
+		return false
+	}
+	w.manager = nil
+	return true
+}
+
+// Move the window to the top of the stack and focus it, a minimized window is shown again
+func (m *WindowManagerComponent) Raise(w *WindowComponent) {
+	if w.manager != m {
+		return
+	}
+	m.Open(w)
+}
+
+// Hide the window in the task bar, the window below it gets the focus
+func (m *WindowManagerComponent) Minimize(w *WindowComponent) {
+	if w.manager != m || !m.remove(w) {
+		return
+	}
+	w.Minimized = true
+	// Minimized windows go to the bottom so cycling reaches the shown ones first
+	m.windows = append([]*WindowComponent{w}, m.windows...)
+}
+
+// Raise the window at the bottom of the stack, cycling through all of them including the minimized ones
+func (m *WindowManagerComponent) FocusNext() {
+	if len(m.windows) > 1 || (len(m.windows) == 1 && m.windows[0].Minimized) {
+		m.Raise(m.windows[0])
+	}
+}
+
+// The window that gets the keys, nil if every window is closed or minimized
+func (m *WindowManagerComponent) Focused() *WindowComponent {
+	if len(m.windows) == 0 || m.windows[len(m.windows)-1].Minimized {
+		return nil
+	}
+	return m.windows[len(m.windows)-1]
+}
+
+// The open windows in stacking order, from the bottom up
+func (m *WindowManagerComponent) Windows() []*WindowComponent {
+	return m.windows
+}
+
+func (m *WindowManagerComponent) remove(w *WindowComponent) bool {
+	for i, v := range m.windows {
+		if v == w {
+			m.windows = append(m.windows[:i], m.windows[i+1:]...)
+			return true
+		}
+	}
+	return false
+}
+
+func (m *WindowManagerComponent) Params(param WindowManagerParams) *WindowManagerComponent {
+	m.Style = osui.SetDefaults(&param.Style).(*WindowManagerStyle)
+	m.Keymap = WindowManagerKeymap.Merge(param.Keymap)
+	return m
+}
+
+// Manage windows over the desktop, open them with Open
+func WindowManager(desktop osui.Component) *WindowManagerComponent {
+	return &WindowManagerComponent{
+		Desktop: desktop,
+		Style:   osui.SetDefaults(&WindowManagerStyle{}).(*WindowManagerStyle),
+		Keymap:  WindowManagerKeymap,
+	}
+}
@@ -0,0 +1,69 @@
+package osui
+
+// A change that can be undone, Apply does it again after it was reverted
+type UndoableAction struct {
+	Name   string
+	Apply  func()
+	Revert func()
+}
+
+// Apply the action and add it to the undo history
+func (s *Screen) Do(a UndoableAction) {
+	a.Apply()
+	s.Record(a)
+}
+
+// Add an action that was already applied to the undo history, this clears the redo history
+func (s *Screen) Record(a UndoableAction) {
+	s.undo = append(s.undo, a)
+	if depth := Settings.UndoDepth; depth > 0 && len(s.undo) > depth {
+		s.undo = s.undo[len(s.undo)-depth:]
+	}
+	s.redo = nil
+}
+
+// Revert the last action, returns false if there is nothing to undo
+func (s *Screen) Undo() bool {
+	if len(s.undo) == 0 {
+		return false
+	}
+	a := s.undo[len(s.undo)-1]
+	s.undo = s.undo[:len(s.undo)-1]
+	a.Revert()
+	s.redo = append(s.redo, a)
+	s.RequestRender()
+	return true
+}
+
+// Apply the last undone action again, returns false if there is nothing to redo
+func (s *Screen) Redo() bool {
+	if len(s.redo) == 0 {
+		return false
+	}
+	a := s.redo[len(s.redo)-1]
+	s.redo = s.redo[:len(s.redo)-1]
+	a.Apply()
+	s.undo = append(s.undo, a)
+	s.RequestRender()
+	return true
+}
+
+// Get the name of the action Undo would revert, ok is false if there is none
+func (s *Screen) UndoName() (string, bool) {
+	if len(s.undo) == 0 {
+		return "", false
+	}
+	return s.undo[len(s.undo)-1].Name, true
+}
+
+func (s *Screen) RedoName() (string, bool) {
+	if len(s.redo) == 0 {
+		return "", false
+	}
+	return s.redo[len(s.redo)-1].Name, true
+}
+
+func (s *Screen) ClearHistory() {
+	s.undo = nil
+	s.redo = nil
+}
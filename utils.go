@@ -1,76 +1,246 @@
 package osui
 
 import (
+	"errors"
 	"fmt"
+	"math"
 	"os"
 	"os/exec"
 	"reflect"
 	"regexp"
 	"runtime"
 	"strings"
+	"sync"
+	"time"
+	"unicode/utf8"
 
 	"github.com/nathan-fiscaletti/consolesize-go"
 	"github.com/orus-dev/osui/colors"
 )
 
-var re = regexp.MustCompile(`(\x1b\[([0-9;]*)[a-zA-Z])+`)
+// Escape sequences take no cells, OSC ones like hyperlinks end with BEL or ST
+var re = regexp.MustCompile(`(\x1b\[([0-9;]*)[a-zA-Z]|\x1b\][^\x07\x1b]*(\x07|\x1b\\))+`)
 
+// Draw the compressed line over the compressed frame line starting at x, fm and lm hold their escape sequences.
+// The styles of the frame under the line are applied again after it, so the rest of the frame keeps its colors
 func RenderLine(frame_, line_ string, x int, fm, lm map[int]string) string {
 	var res strings.Builder
 	frame := []rune(frame_)
 	line := []rune(line_)
 
 	flen, llen := len(frame), len(line)
+	covered := ""
 	for i := 0; i < flen; i++ {
 		if i >= x && i-x < llen {
-			if v, ok := lm[i-x]; ok {
-				res.WriteString(v)
-			}
+			covered += fm[i]
+			res.WriteString(lm[i-x])
 			res.WriteRune(line[i-x])
-		} else {
-			if v, ok := fm[i]; ok {
-				res.WriteString(v)
-			}
-			res.WriteRune(frame[i])
+			continue
+		}
+		if i == x+llen && llen > 0 {
+			res.WriteString(lm[llen] + covered)
+			covered = ""
 		}
+		res.WriteString(fm[i])
+		res.WriteRune(frame[i])
 	}
+	if x < flen && x+llen >= flen {
+		res.WriteString(lm[flen-x])
+	}
+	res.WriteString(covered + fm[flen])
 	return res.String()
 }
 
+// Split the escape sequences off the text, they are kept by the index of the character after them
 func CompressString(input string) (string, map[int]string) {
 	matchesMap := make(map[int]string)
-	res := []rune{}
+	var res strings.Builder
+	res.Grow(len(input))
+	n := 0
+	for i := 0; i < len(input); {
+		if l := escapeLength(input[i:]); l > 0 {
+			matchesMap[n] = input[i : i+l]
+			i += l
+			continue
+		}
+		r, size := utf8.DecodeRuneInString(input[i:])
+		res.WriteRune(r)
+		n++
+		i += size
+	}
+	return res.String(), matchesMap
+}
 
-	i := 0
-	for i < len([]rune(input)) {
-		loc := re.FindStringIndex(string([]rune(input)[i:]))
+func RenderOnFrame(c Component, frame *[]string) {
+	data := c.GetComponentData()
+	data.frame = data.Screen.frameNumber()
+	// The component draws on its own frame, so the clips of this one don't apply to it
+	saved := clips
+	clips = nil
+	output := RenderComponent(c)
+	clips = saved
+	RenderOutputOnFrame(c, output, frame)
+}
 
-		if loc != nil && loc[0] == 0 {
-			ansiSeq := re.FindString(string([]rune(input)[i:]))
-			matchesMap[len(res)] = ansiSeq
-			i += len([]rune(ansiSeq))
-		} else {
-			res = append(res, []rune(input)[i])
-			i++
+// Render the component and remember how long it took and how big its output was, containers that
+// draw the output of their children themselves should render them with this for the stats to see them
+func RenderComponent(c Component) string {
+	data := c.GetComponentData()
+	prev := data.Screen.watchComponent(c)
+	defer data.Screen.watchComponent(prev)
+	start := time.Now()
+	_, versioned := c.(Versioned)
+	if versioned {
+		if output, ok := cachedOutput(c); ok {
+			data.renderTime = time.Since(start)
+			return output
 		}
+		caching++
 	}
+	output := c.Render()
+	if versioned {
+		caching--
+	}
+	data.storeKey(c, output, versioned)
+	data.renderTime, data.outputSize = time.Since(start), len(output)
+	return output
+}
 
-	return string(res), matchesMap
+// Draw the already rendered output of a component on the frame
+func RenderOutputOnFrame(c Component, output string, frame *[]string) {
+	componentData := c.GetComponentData()
+	componentData.frame = componentData.Screen.frameNumber()
+	// Floating components are drawn by the screen once the whole tree is drawn
+	if componentData.Floating {
+		componentData.floatOutput = output
+		return
+	}
+	drawComponent(c, output, componentData.X, componentData.Y, frame)
 }
 
-func RenderOnFrame(c Component, frame *[]string) {
+func drawComponent(c Component, output string, x, y int, frame *[]string) {
 	componentData := c.GetComponentData()
-	for i, line := range strings.Split(c.Render(), "\n") {
-		if int(componentData.Y)+i < len(*frame) {
-			fo, fm := CompressString((*frame)[int(componentData.Y)+i])
-			lo, lm := CompressString(line)
-			(*frame)[int(componentData.Y)+i] = RenderLine(fo, lo, componentData.X, fm, lm)
+	_, isContainer := c.(Container)
+	focused := componentData.IsActive && !isContainer
+	lines := strings.Split(output, "\n")
+	componentData.drawnWidth, componentData.drawnHeight = 0, len(lines)
+	for i, line := range lines {
+		componentData.drawnWidth = max(componentData.drawnWidth, VisibleWidth(line))
+		if focused && Settings.Focus.Style != "" {
+			line = WithStyle(line, Settings.Focus.Style)
+		}
+		drawLine(frame, line, x, y+i)
+	}
+	if focused && Settings.Focus.Marker != "" {
+		m, _ := CompressString(Settings.Focus.Marker)
+		if mx := x - len([]rune(m)); mx >= 0 {
+			saved := clips
+			clips = nil
+			drawLine(frame, Settings.Focus.Marker, mx, y)
+			clips = saved
 		}
 	}
 }
 
+// Draw multi line output on the frame, x and y may be negative
+func DrawOutput(frame *[]string, output string, x, y int) {
+	for i, line := range strings.Split(output, "\n") {
+		drawLine(frame, line, x, y+i)
+	}
+}
+
+func drawLine(frame *[]string, line string, x, y int) {
+	if y < 0 || y >= len(*frame) {
+		return
+	}
+	cellsMu.Lock()
+	defer cellsMu.Unlock()
+	row := rowOf(frame, y)
+	// Nothing is drawn outside of the frame, so a child can't reach into the rest of its parent
+	clip := Rect{X: 0, Y: 0, Width: len(row.cells), Height: len(*frame)}
+	if len(clips) > 0 {
+		clip = clip.Intersect(clips[len(clips)-1])
+	}
+	if y < clip.Y || y >= clip.Y+clip.Height {
+		return
+	}
+	cells, tail := appendCells(scratch[:0], colors.ExpandGradients(line))
+	scratch = cells
+	cells, tail, x = clipCells(cells, tail, x, clip.X, clip.X+clip.Width)
+	row.draw(cells, tail, x)
+	(*frame)[y] = row.line
+}
+
+// A rectangle of cells on a frame
+type Rect struct {
+	X      int
+	Y      int
+	Width  int
+	Height int
+}
+
+func (r Rect) Contains(x, y int) bool {
+	return x >= r.X && y >= r.Y && x < r.X+r.Width && y < r.Y+r.Height
+}
+
+// The part covered by both rects, empty if they don't overlap
+func (r Rect) Intersect(o Rect) Rect {
+	x, y := max(r.X, o.X), max(r.Y, o.Y)
+	return Rect{
+		X:      x,
+		Y:      y,
+		Width:  max(min(r.X+r.Width, o.X+o.Width)-x, 0),
+		Height: max(min(r.Y+r.Height, o.Y+o.Height)-y, 0),
+	}
+}
+
+var clips []Rect
+
+// Only draw inside the rect on the current frame until PopClip, nested clips draw where all of them overlap
+func PushClip(r Rect) {
+	if len(clips) > 0 {
+		r = r.Intersect(clips[len(clips)-1])
+	}
+	clips = append(clips, r)
+}
+
+func PopClip() {
+	if len(clips) > 0 {
+		clips = clips[:len(clips)-1]
+	}
+}
+
+// Apply a style to the whole line, also after the resets inside of it
+func WithStyle(line, style string) string {
+	return style + strings.ReplaceAll(line, colors.Reset, colors.Reset+style) + colors.Reset
+}
+
+var (
+	// Keys a stopped reader read but didn't hand out, the next read returns them first
+	unread   []string
+	unreadMu sync.Mutex
+
+	errReadStopped = errors.New("osui: reading the input was stopped")
+)
+
 func ReadKey() (string, error) {
-	var b [3]byte
+	return readKeyUntil(nil)
+}
+
+// Read like ReadKey, gives up once done is closed
+func readKeyUntil(done <-chan struct{}) (string, error) {
+	unreadMu.Lock()
+	if len(unread) > 0 {
+		k := strings.Join(unread, "")
+		unread = nil
+		unreadMu.Unlock()
+		return k, nil
+	}
+	unreadMu.Unlock()
+	if !waitForInput(done) {
+		return "", errReadStopped
+	}
+	var b [256]byte
 	n, err := os.Stdin.Read(b[:])
 	if err != nil {
 		return "", err
@@ -78,10 +248,57 @@ func ReadKey() (string, error) {
 	return string(b[:n]), nil
 }
 
+// Split raw terminal input into single keys and escape sequences
+func SplitKeys(s string) []string {
+	keys := []string{}
+	for len(s) > 0 {
+		n := keyLength(s)
+		keys = append(keys, s[:n])
+		s = s[n:]
+	}
+	return keys
+}
+
+func keyLength(s string) int {
+	if s[0] != 0x1b || len(s) == 1 || s[1] == 0x1b {
+		_, n := utf8.DecodeRuneInString(s)
+		return n
+	}
+	switch s[1] {
+	case '[':
+		for i := 2; i < len(s); i++ {
+			if s[i] >= 0x40 && s[i] <= 0x7e {
+				return i + 1
+			}
+		}
+		return len(s)
+	case 'O':
+		return min(3, len(s))
+	}
+	_, n := utf8.DecodeRuneInString(s[1:])
+	return 1 + n
+}
+
 func NewFrame(width, height int) []string {
 	frame := make([]string, height)
-	for i := 0; i < height; i++ {
-		frame[i] = strings.Repeat(" ", width)
+	// Strings can't change, so the lines share one
+	blank := strings.Repeat(" ", max(width, 0))
+	for i := range frame {
+		frame[i] = blank
+	}
+	return frame
+}
+
+// A frame with every cell drawn with fill, for the background of a container. An empty fill gives spaces
+func NewFilledFrame(width, height int, fill string) []string {
+	if fill == "" {
+		return NewFrame(width, height)
+	}
+	fill = string([]rune(fill)[:1])
+	frame := make([]string, height)
+	line := strings.Repeat(fill, max(width, 0))
+	for i := range frame {
+		frame[i] = line
 	}
 	return frame
 }
@@ -90,11 +307,11 @@ func Clear() {
 	switch runtime.GOOS {
 	case "windows":
 		cmd := exec.Command("cmd", "/c", "cls")
-		cmd.Stdout = os.Stdout
+		cmd.Stdout = Terminal
 		cmd.Run()
 	default:
 		cmd := exec.Command("clear")
-		cmd.Stdout = os.Stdout
+		cmd.Stdout = Terminal
 		cmd.Run()
 	}
 }
@@ -149,11 +366,27 @@ func UseStyle(p interface{}) {
 }
 
 func ShowCursor() {
-	fmt.Print("\033[?25h")
+	fmt.Fprint(Terminal, "\033[?25h")
 }
 
 func HideCursor() {
-	fmt.Print("\033[?25l")
+	fmt.Fprint(Terminal, "\033[?25l")
+}
+
+func EnableMouse() {
+	fmt.Fprint(Terminal, "\033[?1000h\033[?1003h\033[?1006h")
+}
+
+func DisableMouse() {
+	fmt.Fprint(Terminal, "\033[?1000l\033[?1003l\033[?1006l")
+}
+
+func EnablePaste() {
+	fmt.Fprint(Terminal, "\033[?2004h")
+}
+
+func DisablePaste() {
+	fmt.Fprint(Terminal, "\033[?2004l")
 }
 
 func LogicValue(b bool, _if, _else string) string {
@@ -174,3 +407,156 @@ func LogicValueInt(b bool, _if, _else int) int {
 func GetTerminalSize() (int, int) {
 	return consolesize.GetConsoleSize()
 }
+
+type Point struct {
+	X float64
+	Y float64
+}
+
+var brailleDots = [4][2]rune{{0x01, 0x08}, {0x02, 0x10}, {0x04, 0x20}, {0x40, 0x80}}
+
+// A grid of braille cells, each cell holds 2x4 dots
+type Braille struct {
+	Width  int
+	Height int
+	cells  [][]rune
+}
+
+func NewBraille(width, height int) *Braille {
+	cells := make([][]rune, height)
+	for i := range cells {
+		cells[i] = make([]rune, width)
+	}
+	return &Braille{Width: width, Height: height, cells: cells}
+}
+
+// Set a dot, x is in [0, Width*2) and y is in [0, Height*4)
+func (b *Braille) Set(x, y int) {
+	if x < 0 || y < 0 || x >= b.Width*2 || y >= b.Height*4 {
+		return
+	}
+	b.cells[y/4][x/2] |= brailleDots[y%4][x%2]
+}
+
+func (b *Braille) Line(x0, y0, x1, y1 int) {
+	Bresenham(x0, y0, x1, y1, b.Set)
+}
+
+// Call plot for every point of the line between two points
+func Bresenham(x0, y0, x1, y1 int, plot func(x, y int)) {
+	dx, dy := absInt(x1-x0), -absInt(y1-y0)
+	sx, sy := LogicValueInt(x0 < x1, 1, -1), LogicValueInt(y0 < y1, 1, -1)
+	e := dx + dy
+	for {
+		plot(x0, y0)
+		if x0 == x1 && y0 == y1 {
+			return
+		}
+		e2 := 2 * e
+		if e2 >= dy {
+			e += dy
+			x0 += sx
+		}
+		if e2 <= dx {
+			e += dx
+			y0 += sy
+		}
+	}
+}
+
+// Plot points connected by lines, mapping the bounds onto the whole grid
+func (b *Braille) Plot(points []Point, minX, maxX, minY, maxY float64) {
+	px, py := 0, 0
+	first := true
+	for _, p := range points {
+		// NaN and infinite samples have no place on the grid, the line breaks at them
+		if !p.finite() {
+			first = true
+			continue
+		}
+		x := scaleToGrid(p.X, minX, maxX, b.Width*2-1)
+		y := b.Height*4 - 1 - scaleToGrid(p.Y, minY, maxY, b.Height*4-1)
+		if first {
+			b.Set(x, y)
+		} else {
+			b.Line(px, py, x, y)
+		}
+		px, py, first = x, y, false
+	}
+}
+
+// Get the braille rune of a cell, 0 if the cell has no dots
+func (b *Braille) Cell(x, y int) rune {
+	if x < 0 || y < 0 || x >= b.Width || y >= b.Height || b.cells[y][x] == 0 {
+		return 0
+	}
+	return 0x2800 + b.cells[y][x]
+}
+
+func (b *Braille) Lines(style string) []string {
+	res := make([]string, b.Height)
+	for y := range b.cells {
+		var line strings.Builder
+		line.WriteString(style)
+		for x := range b.cells[y] {
+			if r := b.Cell(x, y); r != 0 {
+				line.WriteRune(r)
+			} else {
+				line.WriteRune(' ')
+			}
+		}
+		if style != "" {
+			line.WriteString(colors.Reset)
+		}
+		res[y] = line.String()
+	}
+	return res
+}
+
+// The bounds of the finite points, 0 for all of them if there are none
+func PointBounds(points []Point) (minX, maxX, minY, maxY float64) {
+	found := false
+	for _, p := range points {
+		if !p.finite() {
+			continue
+		}
+		if !found {
+			minX, maxX, minY, maxY, found = p.X, p.X, p.Y, p.Y, true
+			continue
+		}
+		minX, maxX = math.Min(minX, p.X), math.Max(maxX, p.X)
+		minY, maxY = math.Min(minY, p.Y), math.Max(maxY, p.Y)
+	}
+	return minX, maxX, minY, maxY
+}
+
+func (p Point) finite() bool {
+	return !math.IsNaN(p.X) && !math.IsInf(p.X, 0) && !math.IsNaN(p.Y) && !math.IsInf(p.Y, 0)
+}
+
+// Plot points as a braille line of width x height cells
+func BraillePlot(points []Point, width, height int, style string) string {
+	b := NewBraille(width, height)
+	minX, maxX, minY, maxY := PointBounds(points)
+	b.Plot(points, minX, maxX, minY, maxY)
+	return strings.Join(b.Lines(style), "\n")
+}
+
+// Map v from [min, max] onto [0, size], values outside of the bounds are clamped to the grid
+func scaleToGrid(v, min, max float64, size int) int {
+	if max == min || math.IsNaN(v) || math.IsNaN(min) || math.IsNaN(max) || math.IsInf(min, 0) || math.IsInf(max, 0) {
+		return 0
+	}
+	scaled := math.Round((v - min) / (max - min) * float64(size))
+	if math.IsNaN(scaled) {
+		return 0
+	}
+	return int(math.Min(math.Max(scaled, 0), float64(size)))
+}
+
+func absInt(a int) int {
+	if a < 0 {
+		return -a
+	}
+	return a
+}
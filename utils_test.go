@@ -0,0 +1,27 @@
+package osui
+
+import (
+	"math"
+	"strings"
+	"testing"
+)
+
+func TestBraillePlotSkipsNonFinitePoints(t *testing.T) {
+	out := BraillePlot([]Point{{0, 0}, {1, math.NaN()}, {2, math.Inf(1)}}, 4, 2, "")
+	lines := strings.Split(out, "\n")
+	if len(lines) != 2 {
+		t.Fatalf("got %d lines, want 2", len(lines))
+	}
+	for _, line := range lines {
+		if n := len([]rune(line)); n != 4 {
+			t.Fatalf("line %q has %d cells, want 4", line, n)
+		}
+	}
+	if strings.TrimSpace(out) == "" {
+		t.Fatal("the finite point was not drawn")
+	}
+	minX, maxX, minY, maxY := PointBounds([]Point{{math.NaN(), 1}, {2, 3}, {math.Inf(-1), 0}})
+	if minX != 2 || maxX != 2 || minY != 3 || maxY != 3 {
+		t.Fatalf("bounds = %v %v %v %v, want the finite point only", minX, maxX, minY, maxY)
+	}
+}
@@ -0,0 +1,40 @@
+package osui
+
+// Components whose value can be invalid
+type Validatable interface {
+	Validate() error
+}
+
+type FieldError struct {
+	Component Component
+	Err       error
+}
+
+// Validate every validatable component in the tree, returns the failures in tree order
+func ValidateAll(root Component) []FieldError {
+	res := []FieldError{}
+	Walk(root, func(c Component) bool {
+		if v, ok := c.(Validatable); ok {
+			if err := v.Validate(); err != nil {
+				res = append(res, FieldError{Component: c, Err: err})
+			}
+		}
+		return true
+	})
+	return res
+}
+
+// Check that every validatable component in the tree is valid
+func Valid(root Component) bool {
+	return len(ValidateAll(root)) == 0
+}
+
+// Components that hold a value a form collects
+type Valuer interface {
+	Value() string
+}
+
+// Components that can go back to their initial value
+type Resetter interface {
+	Reset()
+}
@@ -0,0 +1,100 @@
+package osui
+
+import (
+	"fmt"
+	"log"
+	"runtime"
+	"sync"
+	"time"
+)
+
+// A report of the ui loop being blocked for longer than Settings.Watchdog
+type Stall struct {
+	// What the loop was doing, like "render", "key" or "event save"
+	Phase     string
+	Component Component
+	Id        string
+	Duration  time.Duration
+	// The stacks of all goroutines when the stall was detected
+	Stack string
+}
+
+func (s Stall) String() string {
+	where := ""
+	if s.Component != nil {
+		where = " in " + TypeName(s.Component)
+		if s.Id != "" {
+			where += fmt.Sprintf(" %q", s.Id)
+		}
+	}
+	return fmt.Sprintf("the ui loop is blocked for %s by a %s%s, avoid blocking calls like time.Sleep in handlers", s.Duration.Round(time.Millisecond), s.Phase, where)
+}
+
+type watchdog struct {
+	mu        sync.Mutex
+	phase     string
+	component Component
+	start     time.Time
+	reported  bool
+}
+
+// Mark the ui loop as busy with the phase until idle is called
+func (s *Screen) busy(phase string, c Component) {
+	if s.watch == nil {
+		return
+	}
+	s.watch.mu.Lock()
+	s.watch.phase, s.watch.component, s.watch.start, s.watch.reported = phase, c, time.Now(), false
+	s.watch.mu.Unlock()
+}
+
+func (s *Screen) idle() {
+	s.busy("", nil)
+}
+
+// Point the watchdog at the component that is working now, returns the one it pointed at before
+func (s *Screen) watchComponent(c Component) Component {
+	if s == nil || s.watch == nil {
+		return nil
+	}
+	s.watch.mu.Lock()
+	defer s.watch.mu.Unlock()
+	prev := s.watch.component
+	s.watch.component = c
+	return prev
+}
+
+// Check the ui loop until done is closed, reporting each stall once
+func (s *Screen) runWatchdog(threshold time.Duration, done chan struct{}) {
+	ticker := time.NewTicker(max(threshold/4, time.Millisecond))
+	defer ticker.Stop()
+	for {
+		select {
+		case <-done:
+			return
+		case <-ticker.C:
+		}
+		w := s.watch
+		w.mu.Lock()
+		blocked := time.Since(w.start)
+		stalled := w.phase != "" && !w.reported && blocked >= threshold
+		stall := Stall{Phase: w.phase, Component: w.component, Duration: blocked}
+		if stalled {
+			w.reported = true
+		}
+		w.mu.Unlock()
+		if !stalled {
+			continue
+		}
+		if stall.Component != nil {
+			stall.Id = stall.Component.GetComponentData().Id
+		}
+		buf := make([]byte, 1<<16)
+		stall.Stack = string(buf[:runtime.Stack(buf, true)])
+		if Settings.OnStall != nil {
+			Settings.OnStall(stall)
+		} else {
+			log.Printf("osui: %s", stall)
+		}
+	}
+}